@@ -0,0 +1,62 @@
+/*
+ * C interface to the float_duration crate, enabled with its `ffi` feature.
+ *
+ * A `FloatDuration` has the same layout and ABI as a `double` holding a number of
+ * seconds, so it is declared here as `float_duration_t`.
+ */
+#ifndef FLOAT_DURATION_H
+#define FLOAT_DURATION_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* A time duration, stored as a floating-point number of seconds. */
+typedef double float_duration_t;
+
+/* Create a duration from a number of units. */
+float_duration_t float_duration_from_nanoseconds(double value);
+float_duration_t float_duration_from_microseconds(double value);
+float_duration_t float_duration_from_milliseconds(double value);
+float_duration_t float_duration_from_seconds(double value);
+float_duration_t float_duration_from_minutes(double value);
+float_duration_t float_duration_from_hours(double value);
+float_duration_t float_duration_from_days(double value);
+
+/* Return a duration as a number of units. */
+double float_duration_as_nanoseconds(float_duration_t duration);
+double float_duration_as_microseconds(float_duration_t duration);
+double float_duration_as_milliseconds(float_duration_t duration);
+double float_duration_as_seconds(float_duration_t duration);
+double float_duration_as_minutes(float_duration_t duration);
+double float_duration_as_hours(float_duration_t duration);
+double float_duration_as_days(float_duration_t duration);
+
+/* Arithmetic on durations. */
+float_duration_t float_duration_add(float_duration_t a, float_duration_t b);
+float_duration_t float_duration_sub(float_duration_t a, float_duration_t b);
+float_duration_t float_duration_mul(float_duration_t duration, double factor);
+float_duration_t float_duration_div(float_duration_t duration, double divisor);
+float_duration_t float_duration_abs(float_duration_t duration);
+
+/*
+ * Write a duration such as "1.5 seconds" to `buffer`, like `snprintf`: at most
+ * `len` bytes are written, the text is NUL-terminated if `len` is not zero, and the
+ * length of the full text is returned.
+ */
+size_t float_duration_format(float_duration_t duration, char *buffer, size_t len);
+
+/*
+ * Parse a NUL-terminated duration string such as "1h 30m" into `out`. Returns
+ * false, leaving `out` untouched, if the string is not a valid duration.
+ */
+bool float_duration_parse(const char *text, float_duration_t *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FLOAT_DURATION_H */
@@ -0,0 +1,379 @@
+//! Exponential backoff delays for retry loops.
+use num_traits::Float;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use duration::FloatDuration;
+
+/// A builder for exponentially increasing retry delays.
+///
+/// The first delay is `initial`, and every following delay is the previous one
+/// multiplied by the multiplier, optionally capped at a maximum delay. The
+/// sequence of delays is produced by [`iter`](#method.iter), and ends after the
+/// maximum number of attempts if one is set.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::backoff::Backoff;
+///
+/// let backoff = Backoff::new(FloatDuration::milliseconds(100.0))
+///     .with_multiplier(2.0)
+///     .with_max_delay(FloatDuration::milliseconds(500.0))
+///     .with_max_attempts(5);
+///
+/// let delays: Vec<FloatDuration> = backoff.iter().collect();
+/// assert_eq!(delays, vec![FloatDuration::milliseconds(100.0),
+///                         FloatDuration::milliseconds(200.0),
+///                         FloatDuration::milliseconds(400.0),
+///                         FloatDuration::milliseconds(500.0),
+///                         FloatDuration::milliseconds(500.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    initial: FloatDuration,
+    multiplier: f64,
+    max_delay: Option<FloatDuration>,
+    max_attempts: Option<usize>,
+}
+
+impl Backoff {
+    /// Create a new `Backoff` starting at `initial` and doubling each attempt, with
+    /// no maximum delay and no limit on the number of attempts.
+    ///
+    /// # Panics
+    /// This function panics if `initial` is negative or not finite.
+    pub fn new(initial: FloatDuration) -> Backoff {
+        assert!(initial >= FloatDuration::zero() && initial.as_seconds().is_finite(),
+                "the initial backoff delay must be non-negative and finite");
+        Backoff {
+            initial,
+            multiplier: 2.0,
+            max_delay: None,
+            max_attempts: None,
+        }
+    }
+    /// Multiply the delay by `multiplier` after each attempt.
+    ///
+    /// # Panics
+    /// This function panics if `multiplier` is less than 1 or not finite.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Backoff {
+        assert!(multiplier >= 1.0 && multiplier.is_finite(),
+                "the backoff multiplier must be at least 1 and finite");
+        self.multiplier = multiplier;
+        self
+    }
+    /// Cap each delay at `max_delay`.
+    pub fn with_max_delay(mut self, max_delay: FloatDuration) -> Backoff {
+        self.max_delay = Some(max_delay);
+        self
+    }
+    /// Stop producing delays after `max_attempts` delays.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Backoff {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// The delay before the first retry.
+    pub fn initial(&self) -> FloatDuration {
+        self.initial
+    }
+    /// The factor each delay is multiplied by.
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+    /// The maximum delay, if any.
+    pub fn max_delay(&self) -> Option<FloatDuration> {
+        self.max_delay
+    }
+    /// The maximum number of delays, if any.
+    pub fn max_attempts(&self) -> Option<usize> {
+        self.max_attempts
+    }
+
+    /// Return the delay before the retry numbered `attempt`, counting from zero,
+    /// or `None` if the maximum number of attempts has been reached.
+    pub fn delay(&self, attempt: usize) -> Option<FloatDuration> {
+        if let Some(max_attempts) = self.max_attempts {
+            if attempt >= max_attempts {
+                return None;
+            }
+        }
+        if self.initial.is_zero() {
+            return Some(self.initial);
+        }
+        let exponent = if attempt > i32::MAX as usize {
+            i32::MAX
+        } else {
+            attempt as i32
+        };
+        Some(self.clamp(self.initial * Float::powi(self.multiplier, exponent)))
+    }
+    /// Return an iterator over the successive delays.
+    pub fn iter(&self) -> Delays {
+        Delays {
+            backoff: self.clone(),
+            attempt: 0,
+            current: self.initial,
+        }
+    }
+
+    fn clamp(&self, delay: FloatDuration) -> FloatDuration {
+        match self.max_delay {
+            Some(max_delay) => delay.min(max_delay),
+            None => delay,
+        }
+    }
+}
+
+impl IntoIterator for Backoff {
+    type Item = FloatDuration;
+    type IntoIter = Delays;
+
+    fn into_iter(self) -> Delays {
+        Delays {
+            current: self.initial,
+            attempt: 0,
+            backoff: self,
+        }
+    }
+}
+
+impl IntoIterator for &Backoff {
+    type Item = FloatDuration;
+    type IntoIter = Delays;
+
+    fn into_iter(self) -> Delays {
+        self.iter()
+    }
+}
+
+/// An iterator over the delays of a `Backoff`.
+///
+/// This type is returned by `Backoff::iter`.
+#[derive(Debug, Clone)]
+pub struct Delays {
+    backoff: Backoff,
+    attempt: usize,
+    current: FloatDuration,
+}
+
+impl Delays {
+    /// The number of delays produced so far.
+    pub fn attempts(&self) -> usize {
+        self.attempt
+    }
+    /// Restart the sequence from the initial delay.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+        self.current = self.backoff.initial;
+    }
+}
+
+impl Iterator for Delays {
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        if let Some(max_attempts) = self.backoff.max_attempts {
+            if self.attempt >= max_attempts {
+                return None;
+            }
+        }
+        let delay = self.backoff.clamp(self.current);
+        self.attempt += 1;
+        // Once the cap is reached there is no point growing the delay further,
+        // which also keeps it from overflowing to infinity.
+        self.current = self.backoff.clamp(self.current * self.backoff.multiplier);
+        Some(delay)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.backoff.max_attempts {
+            Some(max_attempts) => {
+                let remaining = max_attempts.saturating_sub(self.attempt);
+                (remaining, Some(remaining))
+            }
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+/// Random jitter, to spread out the retries of many clients that failed at the
+/// same time.
+///
+/// These compose with the delays of a `Backoff`:
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # extern crate rand;
+/// use float_duration::FloatDuration;
+/// use float_duration::backoff::Backoff;
+/// use rand::rngs::SmallRng;
+/// use rand::SeedableRng;
+///
+/// # fn main() {
+/// let mut rng = SmallRng::seed_from_u64(1);
+/// let backoff = Backoff::new(FloatDuration::milliseconds(100.0)).with_max_attempts(4);
+/// for (delay, jittered) in backoff.iter().zip(backoff.iter().map(|d| d.jitter_full(&mut rng))) {
+///     assert!(jittered >= FloatDuration::zero() && jittered <= delay);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+impl FloatDuration {
+    /// Return a random duration between zero and `self`.
+    pub fn jitter_full<R: Rng + ?Sized>(&self, rng: &mut R) -> FloatDuration {
+        *self * rng.random::<f64>()
+    }
+    /// Return a random duration between half of `self` and `self`.
+    ///
+    /// This keeps at least half of the delay, unlike `jitter_full`.
+    pub fn jitter_equal<R: Rng + ?Sized>(&self, rng: &mut R) -> FloatDuration {
+        let half = *self / 2.0;
+        half + half * rng.random::<f64>()
+    }
+}
+
+/// Decorrelated jitter: each delay is random between a base delay and three times
+/// the previous delay, capped at a maximum.
+///
+/// The delays grow on average like an exponential backoff, but each one depends on
+/// the previous random delay rather than on the attempt number.
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # extern crate rand;
+/// use float_duration::FloatDuration;
+/// use float_duration::backoff::DecorrelatedJitter;
+/// use rand::rngs::SmallRng;
+/// use rand::SeedableRng;
+///
+/// # fn main() {
+/// let mut rng = SmallRng::seed_from_u64(1);
+/// let mut jitter = DecorrelatedJitter::new(FloatDuration::milliseconds(100.0),
+///                                          FloatDuration::seconds(10.0));
+/// for _ in 0..20 {
+///     let delay = jitter.next_delay(&mut rng);
+///     assert!(delay >= FloatDuration::milliseconds(100.0));
+///     assert!(delay <= FloatDuration::seconds(10.0));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecorrelatedJitter {
+    base: FloatDuration,
+    max_delay: FloatDuration,
+    previous: FloatDuration,
+}
+
+#[cfg(feature = "rand")]
+impl DecorrelatedJitter {
+    /// Create a new `DecorrelatedJitter` with delays between `base` and `max_delay`.
+    ///
+    /// # Panics
+    /// This function panics if `base` is not positive and finite, or if `max_delay`
+    /// is less than `base`.
+    pub fn new(base: FloatDuration, max_delay: FloatDuration) -> DecorrelatedJitter {
+        assert!(base > FloatDuration::zero() && base.as_seconds().is_finite(),
+                "the base jitter delay must be positive and finite");
+        assert!(max_delay >= base, "the maximum jitter delay must be at least the base delay");
+        DecorrelatedJitter {
+            base,
+            max_delay,
+            previous: base,
+        }
+    }
+
+    /// Return the next random delay.
+    pub fn next_delay<R: Rng + ?Sized>(&mut self, rng: &mut R) -> FloatDuration {
+        let upper = self.previous * 3.0;
+        let delay = self.base + (upper - self.base) * rng.random::<f64>();
+        self.previous = delay.min(self.max_delay);
+        self.previous
+    }
+    /// Restart from the base delay, such as after a successful attempt.
+    pub fn reset(&mut self) {
+        self.previous = self.base;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::new(FloatDuration::seconds(1.0)).with_multiplier(3.0);
+        let delays: Vec<_> = backoff.iter().take(4).collect();
+        assert_eq!(delays,
+                   vec![FloatDuration::seconds(1.0),
+                        FloatDuration::seconds(3.0),
+                        FloatDuration::seconds(9.0),
+                        FloatDuration::seconds(27.0)]);
+        assert_eq!(backoff.delay(3), Some(FloatDuration::seconds(27.0)));
+        assert_eq!(backoff.iter().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_limits() {
+        let backoff = Backoff::new(FloatDuration::seconds(1.0))
+            .with_max_delay(FloatDuration::seconds(5.0))
+            .with_max_attempts(4);
+        let delays: Vec<_> = (&backoff).into_iter().collect();
+        assert_eq!(delays,
+                   vec![FloatDuration::seconds(1.0),
+                        FloatDuration::seconds(2.0),
+                        FloatDuration::seconds(4.0),
+                        FloatDuration::seconds(5.0)]);
+        assert_eq!(backoff.delay(3), Some(FloatDuration::seconds(5.0)));
+        assert_eq!(backoff.delay(4), None);
+        assert_eq!(backoff.iter().size_hint(), (4, Some(4)));
+
+        let mut iter = backoff.into_iter();
+        assert_eq!(iter.by_ref().count(), 4);
+        assert_eq!(iter.attempts(), 4);
+        iter.reset();
+        assert_eq!(iter.next(), Some(FloatDuration::seconds(1.0)));
+    }
+
+    #[test]
+    fn test_max_delay_never_overflows() {
+        let backoff = Backoff::new(FloatDuration::seconds(1.0))
+            .with_multiplier(1e10)
+            .with_max_delay(FloatDuration::minutes(1.0));
+        assert_eq!(backoff.iter().nth(1000), Some(FloatDuration::minutes(1.0)));
+        assert_eq!(backoff.delay(1000), Some(FloatDuration::minutes(1.0)));
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_invalid_multiplier_panic() {
+        Backoff::new(FloatDuration::seconds(1.0)).with_multiplier(0.5);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_jitter() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(9);
+        let delay = FloatDuration::seconds(2.0);
+        for _ in 0..1000 {
+            let full = delay.jitter_full(&mut rng);
+            assert!(full >= FloatDuration::zero() && full < delay);
+            let equal = delay.jitter_equal(&mut rng);
+            assert!(equal >= FloatDuration::seconds(1.0) && equal < delay);
+        }
+
+        let base = FloatDuration::milliseconds(10.0);
+        let max_delay = FloatDuration::seconds(1.0);
+        let mut jitter = DecorrelatedJitter::new(base, max_delay);
+        let delays: Vec<FloatDuration> = (0..100).map(|_| jitter.next_delay(&mut rng)).collect();
+        assert!(delays.iter().all(|&d| d >= base && d <= max_delay));
+        assert!(delays.windows(2).all(|pair| pair[1] <= pair[0] * 3.0));
+        assert!(delays.contains(&max_delay));
+        jitter.reset();
+        assert!(jitter.next_delay(&mut rng) <= base * 3.0);
+    }
+}
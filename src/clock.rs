@@ -0,0 +1,345 @@
+//! Clocks, for injecting the source of time into code that measures it.
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use duration::{instant_elapsed, FloatDuration};
+use instant::FloatInstant;
+use scale::TimeScale;
+
+/// A source of time.
+///
+/// Code which depends on the passage of time can be made generic over a `Clock`, so
+/// that it runs on `SystemClock` in production and on a `MockClock` in tests.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::clock::{Clock, MockClock};
+///
+/// fn is_expired<C: Clock>(clock: &C, deadline: float_duration::FloatInstant) -> bool {
+///     clock.now() >= deadline
+/// }
+///
+/// let clock = MockClock::new();
+/// let deadline = clock.now() + FloatDuration::seconds(30.0);
+/// assert!(!is_expired(&clock, deadline));
+///
+/// clock.advance(FloatDuration::seconds(30.0));
+/// assert!(is_expired(&clock, deadline));
+/// ```
+pub trait Clock {
+    /// Return the current time.
+    fn now(&self) -> FloatInstant;
+    /// Return the time passed since the previous call to `delta`, or since the
+    /// clock was created for the first call.
+    fn delta(&mut self) -> FloatDuration;
+}
+
+/// A `Clock` reading the monotonic system clock through `FloatInstant::now`.
+#[derive(Debug, Clone)]
+pub struct SystemClock {
+    last: FloatInstant,
+}
+
+impl SystemClock {
+    /// Create a new `SystemClock`.
+    pub fn new() -> SystemClock {
+        SystemClock { last: FloatInstant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> FloatInstant {
+        FloatInstant::now()
+    }
+    fn delta(&mut self) -> FloatDuration {
+        let now = FloatInstant::now();
+        let delta = now - self.last;
+        self.last = now;
+        delta
+    }
+}
+
+/// A `Clock` whose time only changes when it is explicitly advanced.
+///
+/// Clones of a `MockClock` share the same time, so a test can keep a clone to
+/// control the time seen by the code under test. Each clone keeps track of its own
+/// `delta` separately.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    time: Arc<Mutex<FloatInstant>>,
+    last: FloatInstant,
+}
+
+impl MockClock {
+    /// Create a new `MockClock` at the epoch.
+    pub fn new() -> MockClock {
+        MockClock::starting_at(FloatInstant::EPOCH)
+    }
+    /// Create a new `MockClock` at `time`.
+    pub fn starting_at(time: FloatInstant) -> MockClock {
+        MockClock {
+            time: Arc::new(Mutex::new(time)),
+            last: time,
+        }
+    }
+    /// Move the time of this clock and all of its clones forward by `duration`.
+    pub fn advance(&self, duration: FloatDuration) {
+        *self.lock() += duration;
+    }
+    /// Set the time of this clock and all of its clones.
+    pub fn set(&self, time: FloatInstant) {
+        *self.lock() = time;
+    }
+
+    fn lock(&self) -> ::std::sync::MutexGuard<'_, FloatInstant> {
+        // The lock is never held while panicking, so it cannot be poisoned.
+        self.time.lock().unwrap()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> MockClock {
+        MockClock::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> FloatInstant {
+        *self.lock()
+    }
+    fn delta(&mut self) -> FloatDuration {
+        let now = self.now();
+        let delta = now - self.last;
+        self.last = now;
+        delta
+    }
+}
+
+/// A clock running simulated time at a scaled rate of real time.
+///
+/// Each call to [`tick`](#method.tick) advances the simulated time by the real time
+/// elapsed since the previous tick, multiplied by the time scale. While the clock is
+/// paused, ticks do not advance the simulated time. Slow motion, fast forward and
+/// pausing are all expressed through this single clock.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FloatInstant, TimeScale};
+/// use float_duration::clock::SimulationClock;
+///
+/// let mut clock = SimulationClock::new();
+/// clock.set_scale(TimeScale::new(0.5));
+///
+/// // `advance` feeds the clock a real time step directly, while `tick`
+/// // measures it from the system clock.
+/// assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::seconds(0.5));
+/// clock.pause();
+/// assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::zero());
+///
+/// assert_eq!(clock.now(), FloatInstant::from_epoch(FloatDuration::seconds(0.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SimulationClock {
+    last_tick: Instant,
+    time: FloatInstant,
+    delta: FloatDuration,
+    scale: TimeScale,
+    paused: bool,
+}
+
+impl SimulationClock {
+    /// Create a running clock at the epoch with a time scale of 1.
+    pub fn new() -> SimulationClock {
+        SimulationClock {
+            last_tick: Instant::now(),
+            time: FloatInstant::EPOCH,
+            delta: FloatDuration::zero(),
+            scale: TimeScale::IDENTITY,
+            paused: false,
+        }
+    }
+    /// Create a running clock starting at `time`, with a time scale of 1.
+    pub fn starting_at(time: FloatInstant) -> SimulationClock {
+        SimulationClock { time, ..SimulationClock::new() }
+    }
+
+    /// Advance the clock by the real time elapsed since the last tick, and return
+    /// the amount of simulated time that passed.
+    pub fn tick(&mut self) -> FloatDuration {
+        let real_elapsed = instant_elapsed(self.last_tick);
+        self.last_tick += real_elapsed;
+        self.advance(real_elapsed)
+    }
+    /// Advance the clock by `real_elapsed` of real time, and return the amount of
+    /// simulated time that passed.
+    ///
+    /// This does not affect the real time measured by the next `tick`.
+    pub fn advance(&mut self, real_elapsed: FloatDuration) -> FloatDuration {
+        self.delta = if self.paused {
+            FloatDuration::zero()
+        } else {
+            real_elapsed * self.scale
+        };
+        self.time += self.delta;
+        self.delta
+    }
+
+    /// The current simulated time, as of the last tick.
+    pub fn now(&self) -> FloatInstant {
+        self.time
+    }
+    /// The simulated time that passed during the last tick.
+    pub fn delta(&self) -> FloatDuration {
+        self.delta
+    }
+    /// Set the simulated time without affecting the time scale or pause state.
+    pub fn set_time(&mut self, time: FloatInstant) {
+        self.time = time;
+    }
+
+    /// The number of simulated seconds per real second.
+    pub fn scale(&self) -> TimeScale {
+        self.scale
+    }
+    /// Set the number of simulated seconds per real second.
+    ///
+    /// The new scale applies to the real time elapsed since the last tick. A bare
+    /// `f64` factor is accepted too.
+    ///
+    /// # Panics
+    /// This function panics if `scale` is negative or not finite.
+    pub fn set_scale<S: Into<TimeScale>>(&mut self, scale: S) {
+        let scale = scale.into();
+        assert!(scale.factor() >= 0.0 && scale.factor().is_finite(),
+                "the time scale must be non-negative and finite");
+        self.scale = scale;
+    }
+
+    /// Stop the simulated time from advancing.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+    /// Let the simulated time advance again.
+    ///
+    /// Real time that elapsed while paused is discarded, so the next tick only
+    /// measures time from the moment the clock was resumed.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.paused = false;
+            self.last_tick = Instant::now();
+        }
+    }
+    /// Returns true if the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+impl Default for SimulationClock {
+    fn default() -> SimulationClock {
+        SimulationClock::new()
+    }
+}
+
+impl Clock for SimulationClock {
+    /// The current simulated time, as of the last tick.
+    fn now(&self) -> FloatInstant {
+        self.time
+    }
+    /// Advance the clock with `tick`.
+    fn delta(&mut self) -> FloatDuration {
+        self.tick()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_advance() {
+        let mut clock = SimulationClock::starting_at(FloatInstant::EPOCH +
+                                                     FloatDuration::seconds(10.0));
+        assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::seconds(1.0));
+        clock.set_scale(TimeScale::new(2.0));
+        assert_eq!(clock.scale(), TimeScale::new(2.0));
+        assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::seconds(2.0));
+        assert_eq!(clock.delta(), FloatDuration::seconds(2.0));
+        assert_eq!(clock.now().since_epoch(), FloatDuration::seconds(13.0));
+
+        clock.pause();
+        assert!(clock.is_paused());
+        assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::zero());
+        assert_eq!(clock.now().since_epoch(), FloatDuration::seconds(13.0));
+        clock.resume();
+        clock.set_scale(TimeScale::PAUSED);
+        assert_eq!(clock.advance(FloatDuration::seconds(1.0)), FloatDuration::zero());
+
+        clock.set_time(FloatInstant::EPOCH);
+        assert_eq!(clock.now(), FloatInstant::EPOCH);
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut clock = SimulationClock::new();
+        clock.set_scale(10.0);
+        thread::sleep(Duration::from_millis(2));
+        assert!(clock.tick() >= FloatDuration::milliseconds(20.0));
+
+        clock.pause();
+        thread::sleep(Duration::from_millis(2));
+        assert_eq!(clock.tick(), FloatDuration::zero());
+        clock.resume();
+        // The time spent paused is not counted after resuming.
+        assert!(clock.tick() < FloatDuration::milliseconds(20.0));
+    }
+
+    #[test]
+    fn test_system_clock() {
+        let mut clock = SystemClock::new();
+        let start = clock.now();
+        thread::sleep(Duration::from_millis(2));
+        assert!(clock.now() - start >= FloatDuration::milliseconds(2.0));
+        assert!(clock.delta() >= FloatDuration::milliseconds(2.0));
+    }
+
+    #[test]
+    fn test_mock_clock() {
+        let mut clock = MockClock::starting_at(FloatInstant::EPOCH + FloatDuration::seconds(5.0));
+        let mut shared = clock.clone();
+        assert_eq!(clock.delta(), FloatDuration::zero());
+
+        shared.advance(FloatDuration::seconds(1.5));
+        assert_eq!(clock.now().since_epoch(), FloatDuration::seconds(6.5));
+        assert_eq!(clock.delta(), FloatDuration::seconds(1.5));
+        assert_eq!(clock.delta(), FloatDuration::zero());
+
+        clock.set(FloatInstant::EPOCH);
+        assert_eq!(shared.now(), FloatInstant::EPOCH);
+        assert_eq!(shared.delta(), FloatDuration::seconds(-5.0));
+    }
+
+    #[test]
+    fn test_simulation_clock_trait() {
+        fn elapsed<C: Clock>(clock: &mut C) -> FloatDuration {
+            clock.delta()
+        }
+        let mut clock = SimulationClock::new();
+        clock.pause();
+        assert_eq!(elapsed(&mut clock), FloatDuration::zero());
+        assert_eq!(Clock::now(&clock), FloatInstant::EPOCH);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_negative_scale_panic() {
+        SimulationClock::new().set_scale(-1.0);
+    }
+}
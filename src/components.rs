@@ -0,0 +1,229 @@
+//! Converting durations to and from calendar-style components.
+use num_traits::Float;
+
+use duration::{self, FloatDuration};
+
+/// One more than the largest number of days `DurationComponents::days` can hold.
+const MAX_DAYS: f64 = 18_446_744_073_709_551_616.0;
+
+/// A duration broken into whole days, hours, minutes, and so on.
+///
+/// Every field is normalized, so that hours are below 24, minutes and seconds are
+/// below 60, and the sub-second fields are below 1000. The sign is stored once in
+/// `negative` and all other fields hold the magnitude.
+///
+/// This type is returned by `FloatDuration::decompose`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DurationComponents {
+    /// Whether the duration is negative.
+    pub negative: bool,
+    /// Whole days.
+    pub days: u64,
+    /// Whole hours, below 24.
+    pub hours: u32,
+    /// Whole minutes, below 60.
+    pub minutes: u32,
+    /// Whole seconds, below 60.
+    pub seconds: u32,
+    /// Whole milliseconds, below 1000.
+    pub milliseconds: u32,
+    /// Whole microseconds, below 1000.
+    pub microseconds: u32,
+    /// Whole nanoseconds, below 1000.
+    pub nanoseconds: u32,
+    /// The part of the duration that did not fit in whole nanoseconds, in
+    /// nanoseconds.
+    ///
+    /// Since the other fields are rounded to the nearest nanosecond, this lies in
+    /// `[-0.5, 0.5]`.
+    pub subnanos: f64,
+}
+
+impl FloatDuration {
+    /// Create a new `FloatDuration` by adding up the fields of `components`.
+    ///
+    /// This is the inverse of [`decompose`](#method.decompose). The fields do not
+    /// need to be normalized, so 90 minutes is as valid as 1 hour and 30 minutes.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use float_duration::components::DurationComponents;
+    ///
+    /// let d = FloatDuration::from_components(DurationComponents {
+    ///     hours: 2,
+    ///     minutes: 30,
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(d, FloatDuration::minutes(150.0));
+    /// assert_eq!(FloatDuration::from_components(d.decompose()), d);
+    /// ```
+    pub fn from_components(components: DurationComponents) -> FloatDuration {
+        let whole = components.days as f64 * duration::SECS_PER_DAY +
+                    f64::from(components.hours) * duration::SECS_PER_HOUR +
+                    f64::from(components.minutes) * duration::SECS_PER_MINUTE +
+                    f64::from(components.seconds);
+        let nanos = f64::from(components.milliseconds) * 1.0e6 +
+                    f64::from(components.microseconds) * 1.0e3 +
+                    f64::from(components.nanoseconds) + components.subnanos;
+        let secs = whole + nanos / duration::NANOS_PER_SEC;
+        if components.negative {
+            FloatDuration::seconds(-secs)
+        } else {
+            FloatDuration::seconds(secs)
+        }
+    }
+    /// Create a new `FloatDuration` from a number of hours, minutes, and seconds.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_hms(1.0, 30.0, 15.5),
+    ///            FloatDuration::seconds(5415.5));
+    /// ```
+    pub fn from_hms(hours: f64, minutes: f64, seconds: f64) -> FloatDuration {
+        FloatDuration::seconds(hours * duration::SECS_PER_HOUR +
+                               minutes * duration::SECS_PER_MINUTE + seconds)
+    }
+
+    /// Break the duration into whole days, hours, minutes, seconds, milliseconds,
+    /// microseconds, and nanoseconds.
+    ///
+    /// The duration is rounded to the nearest nanosecond first, so that values such
+    /// as `1.123` seconds come out as 123 milliseconds rather than 122 milliseconds
+    /// and 999999 nanoseconds. What is left over is kept in `subnanos`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let d = FloatDuration::days(2.0) + FloatDuration::hours(3.0) +
+    ///         FloatDuration::minutes(15.0) + FloatDuration::seconds(7.25);
+    /// let c = (-d).decompose();
+    /// assert!(c.negative);
+    /// assert_eq!((c.days, c.hours, c.minutes, c.seconds, c.milliseconds), (2, 3, 15, 7, 250));
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if the duration is not finite, or if it spans
+    /// `2^64` days or more (about 1.6e24 seconds), which `days` cannot hold.
+    pub fn decompose(&self) -> DurationComponents {
+        let secs = self.as_seconds();
+        assert!(secs.is_finite(), "only a finite duration can be decomposed");
+
+        let magnitude = Float::abs(secs);
+        let mut whole = Float::trunc(magnitude);
+        let exact_nanos = (magnitude - whole) * duration::NANOS_PER_SEC;
+        let rounded = Float::round(exact_nanos);
+        let mut nanos = rounded;
+        if nanos >= duration::NANOS_PER_SEC {
+            nanos -= duration::NANOS_PER_SEC;
+            whole += 1.0;
+        }
+        let nanos = nanos as u32;
+
+        let in_day = whole % duration::SECS_PER_DAY;
+        let days = (whole - in_day) / duration::SECS_PER_DAY;
+        assert!(days < MAX_DAYS, "the duration has too many days to be decomposed");
+        let days = days as u64;
+        let in_day = in_day as u32;
+
+        DurationComponents {
+            negative: secs.is_sign_negative(),
+            days,
+            hours: in_day / 3600,
+            minutes: in_day / 60 % 60,
+            seconds: in_day % 60,
+            milliseconds: nanos / 1_000_000,
+            microseconds: nanos / 1000 % 1000,
+            nanoseconds: nanos % 1000,
+            subnanos: exact_nanos - rounded,
+        }
+    }
+}
+
+impl From<DurationComponents> for FloatDuration {
+    fn from(components: DurationComponents) -> FloatDuration {
+        FloatDuration::from_components(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose() {
+        let c = FloatDuration::seconds(90061.001002003).decompose();
+        assert_eq!(c,
+                   DurationComponents {
+                       negative: false,
+                       days: 1,
+                       hours: 1,
+                       minutes: 1,
+                       seconds: 1,
+                       milliseconds: 1,
+                       microseconds: 2,
+                       nanoseconds: 3,
+                       subnanos: c.subnanos,
+                   });
+        assert!(c.subnanos.abs() <= 0.5);
+
+        let c = FloatDuration::seconds(1.123).decompose();
+        assert_eq!((c.seconds, c.milliseconds, c.microseconds, c.nanoseconds), (1, 123, 0, 0));
+
+        let c = FloatDuration::seconds(59.9999999999).decompose();
+        assert_eq!((c.minutes, c.seconds, c.nanoseconds), (1, 0, 0));
+
+        assert_eq!(FloatDuration::zero().decompose(), DurationComponents::default());
+    }
+
+    #[test]
+    fn test_decompose_negative() {
+        let c = FloatDuration::minutes(-61.5).decompose();
+        assert!(c.negative);
+        assert_eq!((c.days, c.hours, c.minutes, c.seconds), (0, 1, 1, 30));
+        assert_eq!(FloatDuration::days(14.0).decompose().days, 14);
+    }
+
+    #[test]
+    fn test_from_components() {
+        let c = DurationComponents {
+            negative: true,
+            days: 1,
+            hours: 25,
+            milliseconds: 1500,
+            ..Default::default()
+        };
+        assert_eq!(FloatDuration::from_components(c),
+                   -(FloatDuration::hours(49.0) + FloatDuration::seconds(1.5)));
+        assert_eq!(FloatDuration::from(DurationComponents::default()), FloatDuration::zero());
+        assert_eq!(FloatDuration::from_hms(0.0, -1.0, 30.0), FloatDuration::seconds(-30.0));
+
+        for &secs in &[0.0, 1.123, -59.75, 90061.001002003, 1.0e10 + 0.5, -3.0e-9] {
+            let d = FloatDuration::seconds(secs);
+            assert_eq!(FloatDuration::from_components(d.decompose()), d);
+        }
+    }
+
+    #[test]
+    fn test_decompose_max_days() {
+        let d = FloatDuration::days(MAX_DAYS / 2.0);
+        assert_eq!(d.decompose().days, 1 << 63);
+        assert_eq!(FloatDuration::from_components(d.decompose()), d);
+
+        let largest = FloatDuration::seconds(f64::from_bits((MAX_DAYS * duration::SECS_PER_DAY)
+                                                                .to_bits() - 1));
+        assert_eq!(FloatDuration::from_components(largest.decompose()), largest);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_decompose_too_many_days_panic() {
+        FloatDuration::days(MAX_DAYS).decompose();
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_decompose_nan_panic() {
+        (FloatDuration::zero() / 0.0).decompose();
+    }
+}
@@ -0,0 +1,198 @@
+//! Debouncing and throttling of events over a time window.
+//!
+//! Both types take the current time as a `FloatInstant` rather than reading a
+//! clock, so they work with real time, using `FloatInstant::now`, as well as with
+//! the time of a simulation.
+use duration::FloatDuration;
+use instant::FloatInstant;
+
+/// Collapse a burst of events into a single one, fired once no event has happened
+/// for a quiet window.
+///
+/// Each event is reported with `trigger`, and `should_fire` returns `true` once the
+/// window has passed since the last event.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FloatInstant};
+/// use float_duration::debounce::Debouncer;
+///
+/// let at = |ms| FloatInstant::from_epoch(FloatDuration::milliseconds(ms));
+/// let mut save = Debouncer::new(FloatDuration::milliseconds(300.0));
+///
+/// // Keystrokes at 0, 100 and 200ms...
+/// for &ms in &[0.0, 100.0, 200.0] {
+///     save.trigger(at(ms));
+/// }
+/// assert!(!save.should_fire(at(400.0)));
+/// // ...save once, 300ms after the last one.
+/// assert!(save.should_fire(at(500.0)));
+/// assert!(!save.should_fire(at(600.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Debouncer {
+    window: FloatDuration,
+    last_event: Option<FloatInstant>,
+}
+
+impl Debouncer {
+    /// Create a new `Debouncer` firing after `window` without events.
+    ///
+    /// # Panics
+    /// This function panics if `window` is negative or NaN.
+    pub fn new(window: FloatDuration) -> Debouncer {
+        assert!(window >= FloatDuration::zero(), "the debounce window must not be negative");
+        Debouncer {
+            window,
+            last_event: None,
+        }
+    }
+
+    /// The quiet time required after the last event.
+    pub fn window(&self) -> FloatDuration {
+        self.window
+    }
+    /// Whether an event is waiting to be fired.
+    pub fn is_pending(&self) -> bool {
+        self.last_event.is_some()
+    }
+
+    /// Report an event happening at `now`, restarting the window.
+    pub fn trigger(&mut self, now: FloatInstant) {
+        self.last_event = Some(now);
+    }
+    /// Return whether the pending events should fire at `now`.
+    ///
+    /// This returns `true` only once per burst of events.
+    pub fn should_fire(&mut self, now: FloatInstant) -> bool {
+        match self.last_event {
+            Some(last_event) if now - last_event >= self.window => {
+                self.last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+    /// The time left at `now` until the pending events fire, or `None` if there
+    /// are none.
+    pub fn time_until_fire(&self, now: FloatInstant) -> Option<FloatDuration> {
+        self.last_event
+            .map(|last_event| (last_event + self.window - now).max(FloatDuration::zero()))
+    }
+    /// Drop the pending events without firing.
+    pub fn reset(&mut self) {
+        self.last_event = None;
+    }
+}
+
+/// Let events through at most once per window, dropping the others.
+///
+/// The first event fires immediately, and the following events only fire once the
+/// window has passed since the last one that fired.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FloatInstant};
+/// use float_duration::debounce::Throttler;
+///
+/// let at = |ms| FloatInstant::from_epoch(FloatDuration::milliseconds(ms));
+/// let mut log = Throttler::new(FloatDuration::seconds(1.0));
+///
+/// let fired: Vec<bool> = [0.0, 500.0, 999.0, 1000.0, 1500.0].iter()
+///     .map(|&ms| log.should_fire(at(ms)))
+///     .collect();
+/// assert_eq!(fired, vec![true, false, false, true, false]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Throttler {
+    window: FloatDuration,
+    last_fired: Option<FloatInstant>,
+}
+
+impl Throttler {
+    /// Create a new `Throttler` firing at most once per `window`.
+    ///
+    /// # Panics
+    /// This function panics if `window` is negative or NaN.
+    pub fn new(window: FloatDuration) -> Throttler {
+        assert!(window >= FloatDuration::zero(), "the throttle window must not be negative");
+        Throttler {
+            window,
+            last_fired: None,
+        }
+    }
+
+    /// The minimum time between two events firing.
+    pub fn window(&self) -> FloatDuration {
+        self.window
+    }
+
+    /// Return whether an event happening at `now` should fire, and if so start a
+    /// new window.
+    pub fn should_fire(&mut self, now: FloatInstant) -> bool {
+        let ready = match self.last_fired {
+            Some(last_fired) => now - last_fired >= self.window,
+            None => true,
+        };
+        if ready {
+            self.last_fired = Some(now);
+        }
+        ready
+    }
+    /// The time left at `now` until an event would fire.
+    pub fn time_until_ready(&self, now: FloatInstant) -> FloatDuration {
+        match self.last_fired {
+            Some(last_fired) => (last_fired + self.window - now).max(FloatDuration::zero()),
+            None => FloatDuration::zero(),
+        }
+    }
+    /// Let the next event fire immediately.
+    pub fn reset(&mut self) {
+        self.last_fired = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: f64) -> FloatInstant {
+        FloatInstant::from_epoch(FloatDuration::seconds(secs))
+    }
+
+    #[test]
+    fn test_debouncer() {
+        let mut debouncer = Debouncer::new(FloatDuration::seconds(1.0));
+        assert!(!debouncer.should_fire(at(0.0)));
+        assert_eq!(debouncer.time_until_fire(at(0.0)), None);
+
+        debouncer.trigger(at(1.0));
+        debouncer.trigger(at(1.5));
+        assert!(debouncer.is_pending());
+        assert_eq!(debouncer.time_until_fire(at(2.0)), Some(FloatDuration::seconds(0.5)));
+        assert!(!debouncer.should_fire(at(2.0)));
+        assert!(debouncer.should_fire(at(2.5)));
+        assert!(!debouncer.is_pending());
+
+        debouncer.trigger(at(3.0));
+        debouncer.reset();
+        assert!(!debouncer.should_fire(at(10.0)));
+    }
+
+    #[test]
+    fn test_throttler() {
+        let mut throttler = Throttler::new(FloatDuration::seconds(2.0));
+        assert_eq!(throttler.time_until_ready(at(0.0)), FloatDuration::zero());
+        assert!(throttler.should_fire(at(1.0)));
+        assert!(!throttler.should_fire(at(2.0)));
+        assert_eq!(throttler.time_until_ready(at(2.5)), FloatDuration::seconds(0.5));
+        assert!(throttler.should_fire(at(3.0)));
+
+        throttler.reset();
+        assert!(throttler.should_fire(at(3.5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_window_panic() {
+        Throttler::new(FloatDuration::seconds(-1.0));
+    }
+}
@@ -0,0 +1,251 @@
+//! A queue of items due at points in time, for event loops and simulations.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use duration::FloatDuration;
+use instant::FloatInstant;
+
+/// A queue of items each due at a `FloatInstant`, driven by a current time that
+/// only moves when advanced.
+///
+/// Items are popped once the current time reaches their due time, earliest first,
+/// and items due at the same time in the order they were scheduled. Since the
+/// current time is not read from a clock, the same queue serves real-time event
+/// loops, advanced by the frame time, and discrete-event simulations, which jump
+/// straight to the next event with `pop_next`.
+///
+/// ```rust
+/// use float_duration::{DelayQueue, FloatDuration};
+///
+/// let mut queue = DelayQueue::new();
+/// queue.schedule(FloatDuration::seconds(2.0), "second");
+/// queue.schedule(FloatDuration::seconds(1.0), "first");
+/// assert_eq!(queue.time_until_next(), Some(FloatDuration::seconds(1.0)));
+///
+/// queue.advance(FloatDuration::seconds(1.5));
+/// assert_eq!(queue.pop_expired().map(|(_, item)| item), Some("first"));
+/// assert_eq!(queue.pop_expired(), None);
+/// assert_eq!(queue.time_until_next(), Some(FloatDuration::seconds(0.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DelayQueue<T> {
+    now: FloatInstant,
+    entries: BinaryHeap<Entry<T>>,
+    next_seq: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    due: FloatInstant,
+    seq: u64,
+    item: T,
+}
+
+impl<T> Entry<T> {
+    fn key(&self) -> (f64, u64) {
+        (self.due.since_epoch().as_seconds(), self.seq)
+    }
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Entry<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Entry<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Entry<T>) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the earliest entry must compare greatest.
+        let (due, seq) = self.key();
+        let (other_due, other_seq) = other.key();
+        other_due.total_cmp(&due).then(other_seq.cmp(&seq))
+    }
+}
+
+impl<T> Default for DelayQueue<T> {
+    fn default() -> DelayQueue<T> {
+        DelayQueue::new()
+    }
+}
+
+impl<T> DelayQueue<T> {
+    /// Create a new, empty `DelayQueue` with the current time at the epoch.
+    pub fn new() -> DelayQueue<T> {
+        DelayQueue::starting_at(FloatInstant::EPOCH)
+    }
+    /// Create a new, empty `DelayQueue` with the current time at `now`.
+    pub fn starting_at(now: FloatInstant) -> DelayQueue<T> {
+        DelayQueue {
+            now,
+            entries: BinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// The current time of the queue.
+    pub fn now(&self) -> FloatInstant {
+        self.now
+    }
+    /// The number of items in the queue, expired or not.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Whether the queue holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add `item` to the queue, due `delay` after the current time.
+    ///
+    /// # Panics
+    /// This function panics if `delay` is NaN.
+    pub fn schedule(&mut self, delay: FloatDuration, item: T) {
+        let due = self.now + delay;
+        self.schedule_at(due, item);
+    }
+    /// Add `item` to the queue, due at `due`.
+    ///
+    /// Items due in the past expire immediately.
+    ///
+    /// # Panics
+    /// This function panics if `due` is NaN.
+    pub fn schedule_at(&mut self, due: FloatInstant, item: T) {
+        assert!(!due.since_epoch().as_seconds().is_nan(), "the due time must not be NaN");
+        self.entries.push(Entry {
+            due,
+            seq: self.next_seq,
+            item,
+        });
+        self.next_seq += 1;
+    }
+
+    /// Move the current time forward by `delta`.
+    ///
+    /// Negative deltas are ignored, since the time of the queue never goes back.
+    pub fn advance(&mut self, delta: FloatDuration) {
+        if delta > FloatDuration::zero() {
+            self.now += delta;
+        }
+    }
+    /// Move the current time forward to `now`, if it is later than the current time.
+    pub fn advance_to(&mut self, now: FloatInstant) {
+        self.now = self.now.max(now);
+    }
+
+    /// The due time of the earliest item in the queue.
+    pub fn next_due(&self) -> Option<FloatInstant> {
+        self.entries.peek().map(|entry| entry.due)
+    }
+    /// The time left until the earliest item in the queue expires.
+    ///
+    /// This is zero if an item has already expired, and `None` if the queue is
+    /// empty.
+    pub fn time_until_next(&self) -> Option<FloatDuration> {
+        self.next_due().map(|due| (due - self.now).max(FloatDuration::zero()))
+    }
+
+    /// Remove and return the earliest item that has expired, with its due time.
+    pub fn pop_expired(&mut self) -> Option<(FloatInstant, T)> {
+        match self.entries.peek() {
+            Some(entry) if entry.due <= self.now => {},
+            _ => return None,
+        }
+        self.entries.pop().map(|entry| (entry.due, entry.item))
+    }
+    /// Return an iterator removing the items that have expired, earliest first.
+    pub fn drain_expired(&mut self) -> DrainExpired<'_, T> {
+        DrainExpired { queue: self }
+    }
+    /// Advance the current time to the due time of the earliest item, if it is in
+    /// the future, and remove and return the item.
+    ///
+    /// This is the step of a discrete-event simulation.
+    pub fn pop_next(&mut self) -> Option<(FloatInstant, T)> {
+        let due = self.next_due()?;
+        self.advance_to(due);
+        self.pop_expired()
+    }
+    /// Remove all items from the queue, keeping the current time.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// An iterator removing the expired items of a `DelayQueue`.
+///
+/// This type is returned by `DelayQueue::drain_expired`.
+#[derive(Debug)]
+pub struct DrainExpired<'a, T: 'a> {
+    queue: &'a mut DelayQueue<T>,
+}
+
+impl<'a, T> Iterator for DrainExpired<'a, T> {
+    type Item = (FloatInstant, T);
+
+    fn next(&mut self) -> Option<(FloatInstant, T)> {
+        self.queue.pop_expired()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: f64) -> FloatInstant {
+        FloatInstant::from_epoch(FloatDuration::seconds(secs))
+    }
+
+    #[test]
+    fn test_expiry_order() {
+        let mut queue = DelayQueue::new();
+        queue.schedule(FloatDuration::seconds(3.0), 'c');
+        queue.schedule(FloatDuration::seconds(1.0), 'a');
+        queue.schedule_at(at(1.0), 'b');
+        queue.schedule(FloatDuration::seconds(-1.0), 'z');
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.time_until_next(), Some(FloatDuration::zero()));
+        assert_eq!(queue.pop_expired(), Some((at(-1.0), 'z')));
+        assert_eq!(queue.pop_expired(), None);
+
+        queue.advance(FloatDuration::seconds(2.0));
+        let expired: Vec<char> = queue.drain_expired().map(|(_, item)| item).collect();
+        assert_eq!(expired, vec!['a', 'b']);
+        assert_eq!(queue.next_due(), Some(at(3.0)));
+        assert_eq!(queue.time_until_next(), Some(FloatDuration::seconds(1.0)));
+
+        queue.advance(FloatDuration::seconds(-5.0));
+        assert_eq!(queue.now(), at(2.0));
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.time_until_next(), None);
+    }
+
+    #[test]
+    fn test_pop_next() {
+        let mut queue = DelayQueue::starting_at(at(10.0));
+        queue.schedule(FloatDuration::seconds(5.0), 2);
+        queue.schedule(FloatDuration::seconds(0.5), 1);
+
+        assert_eq!(queue.pop_next(), Some((at(10.5), 1)));
+        assert_eq!(queue.now(), at(10.5));
+        queue.schedule(FloatDuration::seconds(1.0), 3);
+        assert_eq!(queue.pop_next(), Some((at(11.5), 3)));
+        assert_eq!(queue.pop_next(), Some((at(15.0), 2)));
+        assert_eq!(queue.pop_next(), None);
+        assert_eq!(queue.now(), at(15.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan_due_panic() {
+        let mut queue = DelayQueue::new();
+        queue.schedule(FloatDuration::seconds(f64::NAN), ());
+    }
+}
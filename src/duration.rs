@@ -1,22 +1,27 @@
 //! Floating-point duration type `FloatDuration` and helpers.
-use std::time;
-use std::fmt;
-use std::ops;
-use std::f64;
-use std::u64;
-use std::iter::Sum;
+use core::time;
+use core::fmt;
+use core::ops;
+use core::iter::Sum;
+use core::cmp::Ordering;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+use num_traits::{Float, ToPrimitive};
+use num_traits::float::TotalOrder;
 
 #[cfg(feature = "chrono")]
 use chrono;
 #[cfg(feature = "approx")]
-use approx::ApproxEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde_crate::{Serialize, Deserialize, Serializer, Deserializer};
 #[cfg(feature = "serde")]
-use serde::de::{self, Visitor};
+use serde_crate::de::{self, Visitor};
 
 use super::error;
+use unit::TimeUnit;
 
 /// Number of nanoseconds in a second.
 pub const NANOS_PER_SEC: f64 = 1.0e9;
@@ -30,8 +35,12 @@ pub const SECS_PER_MINUTE: f64 = 60.0;
 pub const SECS_PER_HOUR: f64 = SECS_PER_MINUTE * 60.0;
 /// Number of seconds in a day.
 pub const SECS_PER_DAY: f64 = SECS_PER_HOUR * 24.0;
-/// Number of seconds in a year.
+/// Number of seconds in a week.
+pub const SECS_PER_WEEK: f64 = SECS_PER_DAY * 7.0;
+/// Number of seconds in a 365 day year.
 pub const SECS_PER_YEAR: f64 = SECS_PER_DAY * 365.0;
+/// Number of seconds in a month, one twelfth of a year.
+pub const SECS_PER_MONTH: f64 = SECS_PER_YEAR / 12.0;
 
 /// A fallible conversion from one duration representation to another.
 ///
@@ -92,124 +101,337 @@ pub trait TimePoint<Rhs = Self> {
     fn float_duration_since(self, rhs: Rhs) -> Result<FloatDuration, Self::Error>;
 }
 
+/// A `TimePoint` which can report the current time.
+///
+/// This provides `float_elapsed`, a shorthand for computing the time passed since
+/// an earlier point in time.
+///
+/// ```rust
+/// use float_duration::{Elapsed, FloatDuration};
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// // Do some work...
+/// let elapsed = start.float_elapsed().unwrap();
+/// assert!(elapsed >= FloatDuration::zero());
+/// ```
+pub trait Elapsed: TimePoint + Sized {
+    /// Return the current point in time.
+    fn now() -> Self;
+    /// The amount of time between `self` and now.
+    ///
+    /// This is named to avoid clashing with inherent `elapsed` methods, such as
+    /// `Instant::elapsed`, which take precedence over trait methods.
+    #[inline]
+    fn float_elapsed(self) -> Result<FloatDuration, Self::Error> {
+        Self::now().float_duration_since(self)
+    }
+}
+
+/// The amount of time between `since` and now.
+///
+/// Equivalent to `since.float_elapsed()`.
+///
+/// ```rust
+/// use float_duration::float_elapsed_since;
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// println!("Took {}.", float_elapsed_since(start).unwrap());
+/// ```
+#[inline]
+pub fn float_elapsed_since<T: Elapsed>(since: T) -> Result<FloatDuration, T::Error> {
+    since.float_elapsed()
+}
+
+/// The amount of time between `start` and now.
+///
+/// Computing a duration between two `Instant`s cannot fail, so this is an
+/// infallible version of `start.float_elapsed()`.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn instant_elapsed(start: ::std::time::Instant) -> FloatDuration {
+    FloatDuration::from_std(start.elapsed())
+}
+
+/// A point in time that can be offset by a `FloatDuration`.
+///
+/// Types implementing this trait may also implement `Add<FloatDuration>` and
+/// `Sub<FloatDuration>`, which panic when the result is not representable. The
+/// methods of this trait are their fallible counterparts.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, TimeOffset};
+/// use std::time::Instant;
+///
+/// let now = Instant::now();
+/// let deadline = now + FloatDuration::milliseconds(250.0);
+///
+/// assert_eq!(now.checked_add_float(FloatDuration::milliseconds(250.0)), Some(deadline));
+/// assert_eq!(now.checked_add_float(FloatDuration::seconds(f64::NAN)), None);
+/// ```
+pub trait TimeOffset: Sized {
+    /// Return the point in time `duration` after `self`, or `None` if it cannot be
+    /// represented.
+    ///
+    /// A negative `duration` results in a point in time before `self`.
+    fn checked_add_float(&self, duration: FloatDuration) -> Option<Self>;
+    /// Return the point in time `duration` before `self`, or `None` if it cannot be
+    /// represented.
+    ///
+    /// A negative `duration` results in a point in time after `self`.
+    fn checked_sub_float(&self, duration: FloatDuration) -> Option<Self> {
+        self.checked_add_float(-duration)
+    }
+}
+
 /// A time duration stored as a floating point quantity.
 ///
-/// Unlike `std::time::Duration` or `chrono::Duration`, `FloatDuration`
+/// Unlike `std::time::Duration` or `chrono::Duration`, `GenericFloatDuration`
 /// aims to be convenient and fast to use in simulation and mathematical expressions
 /// rather than to behave like a calendar or perfectly
 /// accurately represent precise time scales.
 ///
-/// Internally, a `FloatDuration` stores a single `f64` number of floating-point seconds,
-/// thus it is only as precise as the `f64` type.
+/// Internally, a `GenericFloatDuration` stores a single floating-point number of
+/// seconds of type `F`, thus it is only as precise as `F`. Most code will want to
+/// use one of the [`FloatDuration`](type.FloatDuration.html) (`f64`) or
+/// [`FloatDuration32`](type.FloatDuration32.html) (`f32`) aliases rather than
+/// naming this type directly.
+///
+/// `GenericFloatDuration<F>` is guaranteed to have the same layout and ABI as `F`.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct FloatDuration {
-    secs: f64,
+#[repr(transparent)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression,
+                                    diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel",
+           diesel(sql_type = diesel::sql_types::Interval, sql_type = diesel::sql_types::Double))]
+pub struct GenericFloatDuration<F> {
+    secs: F,
 }
 
+/// A `GenericFloatDuration` backed by an `f64`.
+pub type FloatDuration64 = GenericFloatDuration<f64>;
+/// A `GenericFloatDuration` backed by an `f32`.
+///
+/// Useful for game and audio code that does its math in single precision.
+pub type FloatDuration32 = GenericFloatDuration<f32>;
+/// The default duration type, backed by an `f64`.
+pub type FloatDuration = FloatDuration64;
+
+/// Convert one of the `f64` unit constants into `F`.
+#[inline]
+pub(crate) fn float<F: Float>(value: f64) -> F {
+    F::from(value).unwrap()
+}
 
-impl FloatDuration {
-    /// Create a new `FloatDuration` representing a number of years.
-    ///
-    /// `float_duration` considers one year to be exactly 365 days, with
-    /// no consideration of leap years.
-    #[inline]
-    pub fn years(years: f64) -> FloatDuration {
-        FloatDuration { secs: years * SECS_PER_YEAR }
-    }
-    /// Create a new `FloatDuration` representing a number of days.
-    #[inline]
-    pub fn days(days: f64) -> FloatDuration {
-        FloatDuration { secs: days * SECS_PER_DAY }
-    }
-    /// Create a new `FloatDuration` representing a number of hours.
-    #[inline]
-    pub fn hours(hours: f64) -> FloatDuration {
-        FloatDuration { secs: hours * SECS_PER_HOUR }
-    }
-    /// Create a new `FloatDuration` representing a number of minutes.
-    #[inline]
-    pub fn minutes(mins: f64) -> FloatDuration {
-        FloatDuration { secs: mins * SECS_PER_MINUTE }
+/// Implement the `const` constructors and unit constants for a concrete float type.
+///
+/// These cannot be generic over `Float` since trait methods are not callable in a
+/// `const fn`.
+macro_rules! impl_const_constructors {
+    ($float:ident) => {
+        impl GenericFloatDuration<$float> {
+            /// A duration of zero elapsed time.
+            pub const ZERO: GenericFloatDuration<$float> = GenericFloatDuration { secs: 0.0 };
+            /// A duration of one nanosecond.
+            pub const NANOSECOND: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: 1.0 / NANOS_PER_SEC as $float };
+            /// A duration of one microsecond.
+            pub const MICROSECOND: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: 1.0 / MICROS_PER_SEC as $float };
+            /// A duration of one millisecond.
+            pub const MILLISECOND: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: 1.0 / MILLIS_PER_SEC as $float };
+            /// A duration of one second.
+            pub const SECOND: GenericFloatDuration<$float> = GenericFloatDuration { secs: 1.0 };
+            /// A duration of one minute.
+            pub const MINUTE: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_MINUTE as $float };
+            /// A duration of one hour.
+            pub const HOUR: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_HOUR as $float };
+            /// A duration of one day.
+            pub const DAY: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_DAY as $float };
+            /// A duration of one week.
+            pub const WEEK: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_WEEK as $float };
+            /// A duration of one month, a twelfth of a 365 day year.
+            pub const MONTH: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_MONTH as $float };
+            /// A duration of one 365 day year.
+            pub const YEAR: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: SECS_PER_YEAR as $float };
+            /// The minimum (most negative) representable duration.
+            pub const MIN: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: $float::MIN };
+            /// The maximum representable duration.
+            pub const MAX: GenericFloatDuration<$float> =
+                GenericFloatDuration { secs: $float::MAX };
+
+            /// Create a new `FloatDuration` representing a number of years.
+            ///
+            /// `float_duration` considers one year to be exactly 365 days, with
+            /// no consideration of leap years. Use
+            /// [`years_in`](#method.years_in) to pick a different convention.
+            #[inline]
+            pub const fn years(years: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: years * SECS_PER_YEAR as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of months.
+            ///
+            /// One month is a twelfth of a 365 day year, or about 30.42 days. Use
+            /// [`months_in`](#method.months_in) to pick a different convention.
+            #[inline]
+            pub const fn months(months: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: months * SECS_PER_MONTH as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of weeks.
+            #[inline]
+            pub const fn weeks(weeks: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: weeks * SECS_PER_WEEK as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of days.
+            #[inline]
+            pub const fn days(days: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: days * SECS_PER_DAY as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of hours.
+            #[inline]
+            pub const fn hours(hours: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: hours * SECS_PER_HOUR as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of minutes.
+            #[inline]
+            pub const fn minutes(mins: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: mins * SECS_PER_MINUTE as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of milliseconds.
+            #[inline]
+            pub const fn milliseconds(millis: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: millis / MILLIS_PER_SEC as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of microseconds.
+            #[inline]
+            pub const fn microseconds(micros: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: micros / MICROS_PER_SEC as $float }
+            }
+            /// Create a new `FloatDuration` representing a number of nanoseconds.
+            #[inline]
+            pub const fn nanoseconds(nanos: $float) -> GenericFloatDuration<$float> {
+                GenericFloatDuration { secs: nanos / NANOS_PER_SEC as $float }
+            }
+
+            /// Return a new `FloatDuration` that represents zero elapsed time.
+            #[inline]
+            pub const fn zero() -> GenericFloatDuration<$float> {
+                Self::ZERO
+            }
+            /// Return a new `FloatDuration` with the minimum possible value.
+            #[inline]
+            pub const fn min_value() -> GenericFloatDuration<$float> {
+                Self::MIN
+            }
+            /// Return a new `FloatDuration` with the maximum possible value.
+            #[inline]
+            pub const fn max_value() -> GenericFloatDuration<$float> {
+                Self::MAX
+            }
+        }
     }
+}
+
+impl_const_constructors!(f32);
+impl_const_constructors!(f64);
+
+impl<F: Float> GenericFloatDuration<F> {
     /// Create a new `FloatDuration` representing a number of seconds.
     #[inline]
-    pub fn seconds(secs: f64) -> FloatDuration {
-        FloatDuration { secs: secs }
+    pub const fn seconds(secs: F) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs }
     }
-    /// Create a new `FloatDuration` representing a number of milliseconds.
+    /// Create a duration from a number of units that are each `secs_per_unit` long.
     #[inline]
-    pub fn milliseconds(millis: f64) -> FloatDuration {
-        FloatDuration { secs: millis / MILLIS_PER_SEC }
+    pub(crate) fn from_multiple(value: F, secs_per_unit: f64) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: value * float(secs_per_unit) }
     }
-    /// Create a new `FloatDuration` representing a number of microseconds.
-    #[inline]
-    pub fn microseconds(micros: f64) -> FloatDuration {
-        FloatDuration { secs: micros / MICROS_PER_SEC }
-    }
-    /// Create a new `FloatDuration` representing a number of nanoseconds.
+    /// Create a duration from a number of units that each last `1 / units_per_sec`.
+    ///
+    /// Sub-second units divide rather than multiply by a reciprocal, which
+    /// keeps results identical to the `const` constructors.
     #[inline]
-    pub fn nanoseconds(nanos: f64) -> FloatDuration {
-        FloatDuration { secs: nanos / NANOS_PER_SEC }
+    pub(crate) fn from_fraction(value: F, units_per_sec: f64) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: value / float(units_per_sec) }
     }
 
     /// Return the total number of fractional years represented by the `FloatDuration`.
     ///
     /// `float_duration` considers one year to be exactly 365 days, with
-    /// no consideration of leap years.
+    /// no consideration of leap years. Use
+    /// [`as_years_in`](#method.as_years_in) to pick a different convention.
     #[inline]
-    pub fn as_years(&self) -> f64 {
-        self.secs / SECS_PER_YEAR
+    pub fn as_years(&self) -> F {
+        self.secs / float(SECS_PER_YEAR)
+    }
+    /// Return the total number of fractional months represented by the `FloatDuration`.
+    ///
+    /// One month is a twelfth of a 365 day year. Use
+    /// [`as_months_in`](#method.as_months_in) to pick a different convention.
+    #[inline]
+    pub fn as_months(&self) -> F {
+        self.secs / float(SECS_PER_MONTH)
+    }
+    /// Return the total number of fractional weeks represented by the `FloatDuration`.
+    #[inline]
+    pub fn as_weeks(&self) -> F {
+        self.secs / float(SECS_PER_WEEK)
     }
     /// Return the total number of fractional days represented by the `FloatDuration`.
     #[inline]
-    pub fn as_days(&self) -> f64 {
-        self.secs / SECS_PER_DAY
+    pub fn as_days(&self) -> F {
+        self.secs / float(SECS_PER_DAY)
     }
     /// Return the total number of fractional hours represented by the `FloatDuration`.
     #[inline]
-    pub fn as_hours(&self) -> f64 {
-        self.secs / SECS_PER_HOUR
+    pub fn as_hours(&self) -> F {
+        self.secs / float(SECS_PER_HOUR)
     }
     /// Return the total number of fractional minutes represented by the `FloatDuration`.
     #[inline]
-    pub fn as_minutes(&self) -> f64 {
-        self.secs / SECS_PER_MINUTE
+    pub fn as_minutes(&self) -> F {
+        self.secs / float(SECS_PER_MINUTE)
     }
     /// Return the total number of fractional seconds represented by the `FloatDuration`.
     #[inline]
-    pub fn as_seconds(&self) -> f64 {
+    pub fn as_seconds(&self) -> F {
         self.secs
     }
     /// Return the total number of fractional milliseconds represented by the `FloatDuration`.
     #[inline]
-    pub fn as_milliseconds(&self) -> f64 {
-        self.secs * MILLIS_PER_SEC
+    pub fn as_milliseconds(&self) -> F {
+        self.secs * float(MILLIS_PER_SEC)
     }
     /// Return the total number of fractional microseconds represented by the `FloatDuration`.
     #[inline]
-    pub fn as_microseconds(&self) -> f64 {
-        self.secs * MICROS_PER_SEC
+    pub fn as_microseconds(&self) -> F {
+        self.secs * float(MICROS_PER_SEC)
     }
     /// Return the total number of fractional nanoseconds represented by the `FloatDuration`.
     #[inline]
-    pub fn as_nanoseconds(&self) -> f64 {
-        self.secs * NANOS_PER_SEC
+    pub fn as_nanoseconds(&self) -> F {
+        self.secs * float(NANOS_PER_SEC)
     }
 
     /// Compute the absolute value of this duration.
     #[inline]
-    pub fn abs(self) -> FloatDuration {
-        FloatDuration { secs: self.secs.abs() }
-    }
-    /// Return a new `FloatDuration` that represents zero elapsed time.
-    #[inline]
-    pub fn zero() -> FloatDuration {
-        FloatDuration { secs: 0.0 }
+    pub fn abs(self) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs.abs() }
     }
     /// Returns true is this duration represents zero elapsed time (equals `FloatDuration::zero()`).
     #[inline]
     pub fn is_zero(&self) -> bool {
-        self.secs == 0.0
+        self.secs == F::zero()
     }
     /// Returns true if the FloatDuration holds a positive amount of time.
     #[inline]
@@ -228,32 +450,308 @@ impl FloatDuration {
     /// - -1.0 if the value is negative, `-0.0` or `NEG_INFINITY`
     /// - `NAN` if the value is `NAN`
     #[inline]
-    pub fn signum(&self) -> f64 {
+    pub fn signum(&self) -> F {
         self.secs.signum()
     }
+    /// Return a duration with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let step = FloatDuration::milliseconds(16.0);
+    /// let rewind = FloatDuration::seconds(-2.0);
+    /// assert_eq!(step.copysign(rewind), FloatDuration::milliseconds(-16.0));
+    /// ```
+    #[inline]
+    pub fn copysign(self, sign: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs.copysign(sign.secs) }
+    }
 
     /// Return the maximum of two durations.
+    ///
+    /// Like `f64::max`, if one of the durations is `NAN` the other is returned.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let timeout = FloatDuration::seconds(5.0);
+    /// assert_eq!(timeout.max(FloatDuration::seconds(30.0)), FloatDuration::seconds(30.0));
+    /// assert_eq!(timeout.max(FloatDuration::zero() / 0.0), timeout);
+    /// ```
     #[inline]
-    pub fn max(self, other: FloatDuration) -> FloatDuration {
-        FloatDuration { secs: self.secs.max(other.secs) }
+    pub fn max(self, other: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs.max(other.secs) }
     }
     /// Return the minimum of two durations.
+    ///
+    /// Like `f64::min`, if one of the durations is `NAN` the other is returned.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let timeout = FloatDuration::seconds(5.0);
+    /// assert_eq!(timeout.min(FloatDuration::seconds(30.0)), timeout);
+    /// assert_eq!((FloatDuration::zero() / 0.0).min(timeout), timeout);
+    /// ```
+    #[inline]
+    pub fn min(self, other: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs.min(other.secs) }
+    }
+
+    /// Compute `self * a + b` with only one rounding error.
+    ///
+    /// This forwards to the fused multiply-add of the underlying float, which is
+    /// both more precise and, on most hardware, faster than a separate multiply
+    /// and add. It is meant for tight integration loops.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let dt = FloatDuration::milliseconds(16.0);
+    /// let elapsed = dt.mul_add(2.0, FloatDuration::seconds(1.0));
+    /// assert_eq!(elapsed, FloatDuration::milliseconds(1032.0));
+    /// ```
+    #[inline]
+    pub fn mul_add(self, a: F, b: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs.mul_add(a, b.secs) }
+    }
+
+    /// Linearly interpolate between `a` and `b`.
+    ///
+    /// A `t` of 0 returns exactly `a` and a `t` of 1 returns exactly `b`. Values of
+    /// `t` outside `[0, 1]` extrapolate past the endpoints.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let short = FloatDuration::milliseconds(100.0);
+    /// let long = FloatDuration::milliseconds(500.0);
+    /// assert_eq!(FloatDuration::lerp(short, long, 0.25), FloatDuration::milliseconds(200.0));
+    /// ```
+    #[inline]
+    pub fn lerp(a: GenericFloatDuration<F>,
+                b: GenericFloatDuration<F>,
+                t: F)
+                -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: a.secs * (F::one() - t) + b.secs * t }
+    }
+    /// Return how far `value` lies between `a` and `b`, as a fraction.
+    ///
+    /// This is the inverse of [`lerp`](#method.lerp): `a` maps to 0 and `b` maps
+    /// to 1. The result is not finite if `a` and `b` are equal.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let start = FloatDuration::seconds(10.0);
+    /// let end = FloatDuration::seconds(20.0);
+    /// assert_eq!(FloatDuration::inverse_lerp(start, end, FloatDuration::seconds(12.5)), 0.25);
+    /// ```
+    #[inline]
+    pub fn inverse_lerp(a: GenericFloatDuration<F>,
+                        b: GenericFloatDuration<F>,
+                        value: GenericFloatDuration<F>)
+                        -> F {
+        (value.secs - a.secs) / (b.secs - a.secs)
+    }
+
+    /// Return the number of whole times `rhs` fits into `self`, rounded towards
+    /// negative infinity for a positive `rhs`.
+    ///
+    /// This is the Euclidean quotient, which together with
+    /// [`rem_euclid`](#method.rem_euclid) satisfies
+    /// `self == rhs * self.div_euclid(rhs) + self.rem_euclid(rhs)`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let period = FloatDuration::seconds(2.0);
+    /// assert_eq!(FloatDuration::seconds(5.0).div_euclid(period), 2.0);
+    /// assert_eq!(FloatDuration::seconds(-1.0).div_euclid(period), -1.0);
+    /// ```
+    #[inline]
+    pub fn div_euclid(self, rhs: GenericFloatDuration<F>) -> F {
+        let quotient = (self.secs / rhs.secs).trunc();
+        if self.secs % rhs.secs < F::zero() {
+            if rhs.secs > F::zero() {
+                quotient - F::one()
+            } else {
+                quotient + F::one()
+            }
+        } else {
+            quotient
+        }
+    }
+    /// Return the non-negative remainder of dividing `self` by `rhs`.
+    ///
+    /// Unlike the `%` operator, the result is never negative, which makes this the
+    /// right choice for wrapping a time that may run backwards into a cycle.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let period = FloatDuration::seconds(2.0);
+    /// assert_eq!(FloatDuration::seconds(5.0).rem_euclid(period), FloatDuration::seconds(1.0));
+    /// assert_eq!(FloatDuration::seconds(-0.5).rem_euclid(period), FloatDuration::seconds(1.5));
+    /// ```
+    #[inline]
+    pub fn rem_euclid(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        let rem = self.secs % rhs.secs;
+        if rem < F::zero() {
+            GenericFloatDuration { secs: rem + rhs.secs.abs() }
+        } else {
+            GenericFloatDuration { secs: rem }
+        }
+    }
+
+    /// Return the absolute difference between two durations.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let a = FloatDuration::seconds(1.0);
+    /// let b = FloatDuration::milliseconds(1250.0);
+    /// assert_eq!(a.abs_diff(b), FloatDuration::milliseconds(250.0));
+    /// assert_eq!(b.abs_diff(a), FloatDuration::milliseconds(250.0));
+    /// ```
+    #[inline]
+    pub fn abs_diff(self, other: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: (self.secs - other.secs).abs() }
+    }
+    /// Returns true if the two durations differ by at most `tolerance`.
+    ///
+    /// This is a simple absolute comparison. The `approx` feature provides relative
+    /// and ULP-based comparisons as well.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let measured = FloatDuration::seconds(0.1) + FloatDuration::seconds(0.2);
+    /// assert!(measured.approx_eq(FloatDuration::seconds(0.3), FloatDuration::MILLISECOND));
+    /// ```
+    #[inline]
+    pub fn approx_eq(self,
+                     other: GenericFloatDuration<F>,
+                     tolerance: GenericFloatDuration<F>)
+                     -> bool {
+        self.abs_diff(other).secs <= tolerance.secs
+    }
+
+    /// Restrict the duration to the range `[min, max]`.
+    ///
+    /// A `NAN` duration is returned unchanged.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let max_dt = FloatDuration::milliseconds(100.0);
+    /// let dt = FloatDuration::seconds(2.5).clamp(FloatDuration::zero(), max_dt);
+    /// assert_eq!(dt, max_dt);
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if `min > max`, or if either bound is `NAN`.
+    #[inline]
+    pub fn clamp(self,
+                 min: GenericFloatDuration<F>,
+                 max: GenericFloatDuration<F>)
+                 -> GenericFloatDuration<F> {
+        assert!(min.secs <= max.secs, "the clamp minimum must not be greater than the maximum");
+        if self.secs < min.secs {
+            min
+        } else if self.secs > max.secs {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Add two durations, returning `None` if the result is not finite.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::minutes(1.0).checked_add(FloatDuration::seconds(30.0)),
+    ///            Some(FloatDuration::seconds(90.0)));
+    /// assert_eq!(FloatDuration::max_value().checked_add(FloatDuration::max_value()), None);
+    /// ```
+    #[inline]
+    pub fn checked_add(self, rhs: GenericFloatDuration<F>) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration { secs: self.secs + rhs.secs }.finite()
+    }
+    /// Subtract two durations, returning `None` if the result is not finite.
+    #[inline]
+    pub fn checked_sub(self, rhs: GenericFloatDuration<F>) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration { secs: self.secs - rhs.secs }.finite()
+    }
+    /// Multiply the duration by a scalar, returning `None` if the result is not finite.
+    #[inline]
+    pub fn checked_mul(self, rhs: F) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration { secs: self.secs * rhs }.finite()
+    }
+    /// Divide the duration by a scalar, returning `None` if the result is not finite.
+    ///
+    /// In particular, this returns `None` when dividing by zero.
+    #[inline]
+    pub fn checked_div(self, rhs: F) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration { secs: self.secs / rhs }.finite()
+    }
+
+    /// Add two durations, clamping the result to
+    /// `[FloatDuration::min_value(), FloatDuration::max_value()]`.
+    ///
+    /// A `NAN` result is returned unchanged.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::max_value().saturating_add(FloatDuration::max_value()),
+    ///            FloatDuration::max_value());
+    /// ```
+    #[inline]
+    pub fn saturating_add(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs + rhs.secs }.saturate()
+    }
+    /// Subtract two durations, clamping the result to
+    /// `[FloatDuration::min_value(), FloatDuration::max_value()]`.
+    ///
+    /// A `NAN` result is returned unchanged.
     #[inline]
-    pub fn min(self, other: FloatDuration) -> FloatDuration {
-        FloatDuration { secs: self.secs.min(other.secs) }
+    pub fn saturating_sub(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs - rhs.secs }.saturate()
+    }
+    /// Multiply the duration by a scalar, clamping the result to
+    /// `[FloatDuration::min_value(), FloatDuration::max_value()]`.
+    ///
+    /// A `NAN` result is returned unchanged.
+    #[inline]
+    pub fn saturating_mul(self, rhs: F) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs * rhs }.saturate()
     }
 
-    /// Return a new `FloatDuration` with the minimum possible value.
+    /// Clamp infinite durations to the largest finite value of the same sign.
     #[inline]
-    pub fn min_value() -> FloatDuration {
-        FloatDuration { secs: f64::MIN }
+    fn saturate(self) -> GenericFloatDuration<F> {
+        if self.secs == F::infinity() {
+            GenericFloatDuration { secs: F::max_value() }
+        } else if self.secs == F::neg_infinity() {
+            GenericFloatDuration { secs: F::min_value() }
+        } else {
+            self
+        }
     }
-    /// Return a new `FloatDuration` with the maximum possible value.
+
+    /// Return `Some(self)` if the duration is finite and `None` otherwise.
     #[inline]
-    pub fn max_value() -> FloatDuration {
-        FloatDuration { secs: f64::MAX }
+    fn finite(self) -> Option<GenericFloatDuration<F>> {
+        if self.secs.is_finite() {
+            Some(self)
+        } else {
+            None
+        }
     }
 
+
     /// Create a `std::time::Duration` object from a `FloatDuration`.
     ///
     /// # Errors
@@ -262,104 +760,277 @@ impl FloatDuration {
     /// `DurationError::StdOutOfRange` if the `FloatDuration` value is outside
     /// of either of those bounds.
     pub fn to_std(&self) -> Result<time::Duration, error::OutOfRangeError> {
-        if self.secs.is_sign_negative() {
+        let secs = self.secs.to_f64().ok_or_else(error::OutOfRangeError::new)?;
+        if secs.is_sign_negative() {
             Err(error::OutOfRangeError::new())
         } else {
-            let seconds = self.secs.trunc();
-            let nanos = self.secs.fract() * NANOS_PER_SEC;
+            let seconds = secs.trunc();
+            let nanos = secs.fract() * NANOS_PER_SEC;
 
-            if seconds > u64::MAX as f64 {
-                Err(error::OutOfRangeError::new())
-            } else {
-                Ok(time::Duration::new(seconds as u64, nanos as u32))
+            match seconds.to_u64() {
+                Some(seconds) => Ok(time::Duration::new(seconds, nanos as u32)),
+                None => Err(error::OutOfRangeError::new()),
             }
         }
     }
 
+    /// Create a `std::time::Duration` object from a `FloatDuration`, saturating
+    /// values that cannot be represented.
+    ///
+    /// Negative and NaN durations become `Duration::ZERO`, and durations that are too
+    /// large, including positive infinity, become `Duration::MAX`. This is useful
+    /// for computed timeouts and delays, where such values should not be errors.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(FloatDuration::seconds(-1.0).to_std_saturating(), Duration::ZERO);
+    /// assert_eq!(FloatDuration::seconds(f64::NAN).to_std_saturating(), Duration::ZERO);
+    /// assert_eq!(FloatDuration::seconds(f64::INFINITY).to_std_saturating(), Duration::MAX);
+    /// ```
+    pub fn to_std_saturating(&self) -> time::Duration {
+        if self.secs.is_nan() || self.secs <= F::zero() {
+            time::Duration::from_secs(0)
+        } else {
+            self.to_std().unwrap_or(time::Duration::MAX)
+        }
+    }
+
+    /// Return the duration as a whole number of nanoseconds, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// The whole seconds and the fraction of a second are converted separately, so
+    /// no precision is lost to multiplying the full value by 10^9.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let d = FloatDuration::from_nanos_i128(86_400_000_000_123);
+    /// assert_eq!(d.as_nanos_i128(), Ok(86_400_000_000_123));
+    /// assert_eq!(FloatDuration::seconds(-1.5).as_nanos_i128(), Ok(-1_500_000_000));
+    /// ```
+    ///
+    /// # Errors
+    /// This function returns an `OutOfRangeError` if the duration is not finite or
+    /// is too large to fit in an `i128` number of nanoseconds.
+    pub fn as_nanos_i128(&self) -> Result<i128, error::OutOfRangeError> {
+        let secs = self.secs.to_f64().ok_or_else(error::OutOfRangeError::new)?;
+        if !secs.is_finite() {
+            return Err(error::OutOfRangeError::new());
+        }
+        let whole = secs.trunc();
+        let nanos = (secs - whole) * NANOS_PER_SEC;
+        whole.to_i128()
+            .and_then(|whole| whole.checked_mul(NANOS_PER_SEC as i128))
+            .and_then(|whole| whole.checked_add(nanos.round() as i128))
+            .ok_or_else(error::OutOfRangeError::new)
+    }
+    /// Create a `FloatDuration` from a whole number of nanoseconds.
+    ///
+    /// The whole seconds and the remaining nanoseconds are converted separately, so
+    /// the result is as close to `nanos` as the float type allows.
+    pub fn from_nanos_i128(nanos: i128) -> GenericFloatDuration<F> {
+        let per_sec = NANOS_PER_SEC as i128;
+        let secs: F = F::from(nanos / per_sec).unwrap_or_else(F::nan);
+        let subsec_nanos: F = float((nanos % per_sec) as f64);
+        GenericFloatDuration { secs: secs + subsec_nanos / float(NANOS_PER_SEC) }
+    }
+
     /// Create a `FloatDuration` object from a `std::time::Duration`.
     ///
     /// Equivalent to using `FloatDuration::from(duration)`
     #[inline]
-    pub fn from_std(duration: time::Duration) -> FloatDuration {
-        FloatDuration::seconds((duration.as_secs() as f64) +
-                               (duration.subsec_nanos() as f64) / NANOS_PER_SEC)
+    pub fn from_std(duration: time::Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(float((duration.as_secs() as f64) +
+                                            (duration.subsec_nanos() as f64) / NANOS_PER_SEC))
+    }
+}
+
+impl<F: Float + TotalOrder> GenericFloatDuration<F> {
+    /// Compare two durations using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike `partial_cmp`, this always produces an ordering. Values are ordered as
+    /// negative `NAN`, `NEG_INFINITY`, negative durations, `-0.0`, `+0.0`, positive
+    /// durations, `INFINITY` and finally positive `NAN`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut durations = vec![FloatDuration::hours(1.0), FloatDuration::seconds(5.0)];
+    /// durations.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(durations, [FloatDuration::seconds(5.0), FloatDuration::hours(1.0)]);
+    ///
+    /// let nan = FloatDuration::seconds(std::f64::NAN);
+    /// assert_eq!(nan.total_cmp(&FloatDuration::max_value()), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &GenericFloatDuration<F>) -> Ordering {
+        self.secs.total_cmp(&other.secs)
+    }
+    /// Wrap the duration in a [`TotalOrd`](struct.TotalOrd.html), which
+    /// implements `Ord` using `total_cmp`.
+    #[inline]
+    pub fn total_ord(self) -> TotalOrd<F> {
+        TotalOrd(self)
+    }
+}
+
+/// A `GenericFloatDuration` wrapper that implements `Eq` and `Ord`.
+///
+/// Comparison is done with [`total_cmp`](struct.GenericFloatDuration.html#method.total_cmp),
+/// so `NAN` durations are ordered rather than incomparable and `-0.0` is less than `+0.0`.
+/// This makes it possible to use durations as keys of a `BTreeMap` or to `sort()` them.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::duration::TotalOrd;
+/// use std::collections::BTreeMap;
+///
+/// let mut timers = BTreeMap::new();
+/// timers.insert(FloatDuration::seconds(10.0).total_ord(), "slow");
+/// timers.insert(FloatDuration::seconds(1.0).total_ord(), "fast");
+///
+/// let (first, name) = timers.iter().next().unwrap();
+/// assert_eq!(first.0, FloatDuration::seconds(1.0));
+/// assert_eq!(*name, "fast");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TotalOrd<F>(pub GenericFloatDuration<F>);
+
+impl<F: Float + TotalOrder> PartialEq for TotalOrd<F> {
+    #[inline]
+    fn eq(&self, other: &TotalOrd<F>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<F: Float + TotalOrder> Eq for TotalOrd<F> {}
+impl<F: Float + TotalOrder> PartialOrd for TotalOrd<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &TotalOrd<F>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<F: Float + TotalOrder> Ord for TotalOrd<F> {
+    #[inline]
+    fn cmp(&self, other: &TotalOrd<F>) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+impl<F> From<GenericFloatDuration<F>> for TotalOrd<F> {
+    #[inline]
+    fn from(from: GenericFloatDuration<F>) -> TotalOrd<F> {
+        TotalOrd(from)
+    }
+}
+
+impl FloatDuration32 {
+    /// Convert to an `f64`-backed `FloatDuration`.
+    ///
+    /// This conversion is lossless and is equivalent to `FloatDuration::from(self)`.
+    #[inline]
+    pub fn to_f64(self) -> FloatDuration64 {
+        GenericFloatDuration { secs: f64::from(self.secs) }
+    }
+}
+
+impl FloatDuration64 {
+    /// Convert to an `f32`-backed `FloatDuration32`.
+    ///
+    /// The conversion rounds to the nearest representable `f32`, and durations
+    /// outside of the `f32` range become infinite.
+    #[inline]
+    pub fn to_f32(self) -> FloatDuration32 {
+        GenericFloatDuration { secs: self.secs as f32 }
+    }
+}
+
+impl From<FloatDuration32> for FloatDuration64 {
+    #[inline]
+    fn from(from: FloatDuration32) -> FloatDuration64 {
+        from.to_f64()
     }
 }
 
 #[cfg(feature = "chrono")]
-impl FloatDuration {
+impl<F: Float> GenericFloatDuration<F> {
     /// Create a `chrono::Duration` object from a `FloatDuration`.
     ///
+    /// The sub-nanosecond part of the duration is truncated.
+    ///
     /// # Errors
-    /// Presently, the conversion to `chrono::Duration` first goes through
-    /// `std::time::Duration` and return an error if `to_std` returns an error.
+    /// `chrono::Duration` cannot represent non-finite values or more than
+    /// `i64::MAX` milliseconds. This function will return an `OutOfRangeError`
+    /// if the `FloatDuration` value is outside of those bounds.
     pub fn to_chrono(&self) -> Result<chrono::Duration, error::OutOfRangeError> {
-        let is_negative = self.is_negative();
-        let std_duration = self.abs().to_std()?;
-        let chrono_duration = chrono::Duration::from_std(std_duration)?;
-        if is_negative {
-            Ok(-chrono_duration)
-        } else {
-            Ok(chrono_duration)
+        let secs = self.secs.to_f64().ok_or_else(error::OutOfRangeError::new)?;
+        let seconds = secs.trunc().to_i64().ok_or_else(error::OutOfRangeError::new)?;
+        let nanos = (secs.fract() * NANOS_PER_SEC) as i64;
+
+        let max_seconds = chrono::Duration::max_value().num_seconds();
+        if seconds > max_seconds || seconds < -max_seconds {
+            return Err(error::OutOfRangeError::new());
         }
+        chrono::Duration::seconds(seconds)
+            .checked_add(&chrono::Duration::nanoseconds(nanos))
+            .ok_or_else(error::OutOfRangeError::new)
     }
 
     /// Create a `FloatDuration` object from a `chrono::Duration`.
     ///
-    /// `chrono::Duration` does not provide a way to access sub-millisecond
-    /// precision if the duration is too large to be entirely represented as a single
-    /// value. Thus, if the absolute value of the total number of nanoseconds is
-    /// greater than `i64::MAX`, only millisecond precision will be captured.
-    ///
     /// Equivalent to using `FloatDuration::from(duration)`
     #[inline]
-    pub fn from_chrono(duration: chrono::Duration) -> FloatDuration {
-        if let Some(nanos) = duration.num_nanoseconds() {
-            FloatDuration::nanoseconds(nanos as f64)
-        } else {
-            FloatDuration::milliseconds(duration.num_milliseconds() as f64)
-        }
+    pub fn from_chrono(duration: chrono::Duration) -> GenericFloatDuration<F> {
+        let seconds = duration.num_seconds();
+        // The remainder is always less than a second, so it fits in nanoseconds.
+        let nanos = (duration - chrono::Duration::seconds(seconds))
+            .num_nanoseconds()
+            .unwrap();
+        GenericFloatDuration::seconds(float(seconds as f64 + nanos as f64 / NANOS_PER_SEC))
     }
 }
 
 #[cfg(feature = "serde")]
-struct FloatDurationVisitor;
+struct FloatDurationVisitor<F> {
+    marker: PhantomData<F>,
+}
 
-// We want to serialize a `FloatDuration` as a single f64 instead of a struct.
+// We want to serialize a `FloatDuration` as a single float instead of a struct.
 #[cfg(feature = "serde")]
-impl<'de> Visitor<'de> for FloatDurationVisitor {
-    type Value = FloatDuration;
+impl<'de, F: Float> Visitor<'de> for FloatDurationVisitor<F> {
+    type Value = GenericFloatDuration<F>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a floating-point value")
     }
-    fn visit_f32<E>(self, value: f32) -> Result<FloatDuration, E>
+    fn visit_f32<E>(self, value: f32) -> Result<GenericFloatDuration<F>, E>
         where E: de::Error
     {
-        Ok(FloatDuration::seconds(value as f64))
+        Ok(GenericFloatDuration::seconds(float(value as f64)))
     }
-    fn visit_f64<E>(self, value: f64) -> Result<FloatDuration, E>
+    fn visit_f64<E>(self, value: f64) -> Result<GenericFloatDuration<F>, E>
         where E: de::Error
     {
-        Ok(FloatDuration::seconds(value))
+        Ok(GenericFloatDuration::seconds(float(value)))
     }
 }
 
 #[cfg(feature = "serde")]
-impl Serialize for FloatDuration {
+impl<F: Serialize> Serialize for GenericFloatDuration<F> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer
     {
-        serializer.serialize_f64(self.secs)
+        self.secs.serialize(serializer)
     }
 }
 
 #[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for FloatDuration {
+impl<'de, F: Float> Deserialize<'de> for GenericFloatDuration<F> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
     {
-        deserializer.deserialize_f64(FloatDurationVisitor)
+        deserializer.deserialize_f64(FloatDurationVisitor { marker: PhantomData })
     }
 }
 
@@ -409,253 +1080,478 @@ impl TimePoint for chrono::NaiveDateTime {
     }
 }
 
-impl TimePoint for time::Instant {
+#[cfg(feature = "std")]
+impl TimePoint for ::std::time::Instant {
     type Error = ();
     #[inline]
-    fn float_duration_since(self, since: time::Instant) -> Result<FloatDuration, ()> {
+    fn float_duration_since(self, since: ::std::time::Instant) -> Result<FloatDuration, ()> {
         let std_duration = self.duration_since(since);
         Ok(FloatDuration::from_std(std_duration))
     }
 }
-impl TimePoint for time::SystemTime {
-    type Error = time::SystemTimeError;
+#[cfg(feature = "std")]
+impl TimePoint for ::std::time::SystemTime {
+    type Error = ::std::time::SystemTimeError;
     #[inline]
     fn float_duration_since(self,
-                            since: time::SystemTime)
-                            -> Result<FloatDuration, time::SystemTimeError> {
+                            since: ::std::time::SystemTime)
+                            -> Result<FloatDuration, ::std::time::SystemTimeError> {
         let std_duration = self.duration_since(since)?;
         Ok(FloatDuration::from_std(std_duration))
     }
 }
 
-impl FromDuration<time::Duration> for FloatDuration {
+/// Convert any `GenericFloatDuration` to a `FloatDuration`, mapping values that
+/// cannot be converted to NaN.
+#[cfg(any(feature = "std", feature = "chrono"))]
+fn widen<F: Float>(duration: GenericFloatDuration<F>) -> FloatDuration {
+    FloatDuration::seconds(duration.secs.to_f64().unwrap_or(f64::NAN))
+}
+
+/// Implement the panicking `Add`, `Sub`, `AddAssign` and `SubAssign` operators for a
+/// type implementing `TimeOffset`.
+#[cfg(any(feature = "std", feature = "chrono"))]
+macro_rules! impl_time_offset_ops {
+    ([$($generics:tt)*] $time_point:ty) => {
+        impl<$($generics)* F: Float> ops::Add<GenericFloatDuration<F>> for $time_point {
+            type Output = $time_point;
+
+            /// # Panics
+            /// Panics if the resulting point in time cannot be represented.
+            #[inline]
+            fn add(self, rhs: GenericFloatDuration<F>) -> $time_point {
+                self.checked_add_float(widen(rhs))
+                    .expect("overflow when adding a FloatDuration to a point in time")
+            }
+        }
+        impl<$($generics)* F: Float> ops::Sub<GenericFloatDuration<F>> for $time_point {
+            type Output = $time_point;
+
+            /// # Panics
+            /// Panics if the resulting point in time cannot be represented.
+            #[inline]
+            fn sub(self, rhs: GenericFloatDuration<F>) -> $time_point {
+                self.checked_sub_float(widen(rhs))
+                    .expect("overflow when subtracting a FloatDuration from a point in time")
+            }
+        }
+        impl<$($generics)* F: Float> ops::AddAssign<GenericFloatDuration<F>> for $time_point {
+            #[inline]
+            fn add_assign(&mut self, rhs: GenericFloatDuration<F>) {
+                *self = self.checked_add_float(widen(rhs))
+                    .expect("overflow when adding a FloatDuration to a point in time");
+            }
+        }
+        impl<$($generics)* F: Float> ops::SubAssign<GenericFloatDuration<F>> for $time_point {
+            #[inline]
+            fn sub_assign(&mut self, rhs: GenericFloatDuration<F>) {
+                *self = self.checked_sub_float(widen(rhs))
+                    .expect("overflow when subtracting a FloatDuration from a point in time");
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Elapsed for ::std::time::Instant {
+    #[inline]
+    fn now() -> ::std::time::Instant {
+        ::std::time::Instant::now()
+    }
+}
+#[cfg(feature = "std")]
+impl Elapsed for ::std::time::SystemTime {
+    #[inline]
+    fn now() -> ::std::time::SystemTime {
+        ::std::time::SystemTime::now()
+    }
+}
+#[cfg(feature = "chrono")]
+impl Elapsed for chrono::DateTime<chrono::Utc> {
+    #[inline]
+    fn now() -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc::now()
+    }
+}
+#[cfg(feature = "chrono")]
+impl Elapsed for chrono::DateTime<chrono::Local> {
+    #[inline]
+    fn now() -> chrono::DateTime<chrono::Local> {
+        chrono::Local::now()
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_std_time_offset {
+    ($time_point:ty) => {
+        impl TimeOffset for $time_point {
+            fn checked_add_float(&self, duration: FloatDuration) -> Option<$time_point> {
+                let std_duration = duration.abs().to_std().ok()?;
+                if duration.is_negative() {
+                    self.checked_sub(std_duration)
+                } else {
+                    self.checked_add(std_duration)
+                }
+            }
+        }
+        impl_time_offset_ops!([] $time_point);
+    }
+}
+#[cfg(feature = "std")]
+impl_std_time_offset!(::std::time::Instant);
+#[cfg(feature = "std")]
+impl_std_time_offset!(::std::time::SystemTime);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TimeOffset for chrono::DateTime<Tz> {
+    fn checked_add_float(&self, duration: FloatDuration) -> Option<chrono::DateTime<Tz>> {
+        self.clone().checked_add_signed(duration.to_chrono().ok()?)
+    }
+}
+#[cfg(feature = "chrono")]
+impl_time_offset_ops!([Tz: chrono::TimeZone,] chrono::DateTime<Tz>);
+#[cfg(feature = "chrono")]
+impl TimeOffset for chrono::NaiveDateTime {
+    fn checked_add_float(&self, duration: FloatDuration) -> Option<chrono::NaiveDateTime> {
+        self.checked_add_signed(duration.to_chrono().ok()?)
+    }
+}
+#[cfg(feature = "chrono")]
+impl_time_offset_ops!([] chrono::NaiveDateTime);
+#[cfg(feature = "chrono")]
+impl TimeOffset for chrono::NaiveTime {
+    /// Offsetting a `NaiveTime` wraps around midnight, so this only fails if the
+    /// duration cannot be converted to a `chrono::Duration`.
+    fn checked_add_float(&self, duration: FloatDuration) -> Option<chrono::NaiveTime> {
+        Some(self.overflowing_add_signed(duration.to_chrono().ok()?).0)
+    }
+}
+#[cfg(feature = "chrono")]
+impl_time_offset_ops!([] chrono::NaiveTime);
+
+impl<F: Float> FromDuration<time::Duration> for GenericFloatDuration<F> {
     type Error = ();
     #[inline]
-    fn from_duration(from: time::Duration) -> Result<FloatDuration, ()> {
-        Ok(FloatDuration::from_std(from))
+    fn from_duration(from: time::Duration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_std(from))
     }
 }
 #[cfg(feature = "chrono")]
-impl FromDuration<chrono::Duration> for FloatDuration {
+impl<F: Float> FromDuration<chrono::Duration> for GenericFloatDuration<F> {
     type Error = ();
     #[inline]
-    fn from_duration(from: chrono::Duration) -> Result<FloatDuration, ()> {
-        Ok(FloatDuration::from_chrono(from))
+    fn from_duration(from: chrono::Duration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_chrono(from))
     }
 }
-impl FromDuration<FloatDuration> for time::Duration {
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for time::Duration {
     type Error = error::OutOfRangeError;
     #[inline]
-    fn from_duration(from: FloatDuration) -> Result<time::Duration, error::OutOfRangeError> {
+    fn from_duration(from: GenericFloatDuration<F>)
+                     -> Result<time::Duration, error::OutOfRangeError> {
         from.to_std()
     }
 }
 #[cfg(feature = "chrono")]
-impl FromDuration<FloatDuration> for chrono::Duration {
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for chrono::Duration {
     type Error = error::OutOfRangeError;
     #[inline]
-    fn from_duration(from: FloatDuration) -> Result<chrono::Duration, error::OutOfRangeError> {
+    fn from_duration(from: GenericFloatDuration<F>)
+                     -> Result<chrono::Duration, error::OutOfRangeError> {
         from.to_chrono()
     }
 }
 
-impl From<time::Duration> for FloatDuration {
+impl<F: Float> From<time::Duration> for GenericFloatDuration<F> {
     #[inline]
-    fn from(from: time::Duration) -> FloatDuration {
-        FloatDuration::from_std(from)
+    fn from(from: time::Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_std(from)
     }
 }
 #[cfg(feature = "chrono")]
-impl From<chrono::Duration> for FloatDuration {
+impl<F: Float> From<chrono::Duration> for GenericFloatDuration<F> {
     #[inline]
-    fn from(from: chrono::Duration) -> FloatDuration {
-        FloatDuration::from_chrono(from)
+    fn from(from: chrono::Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_chrono(from)
     }
 }
 
-impl fmt::Display for FloatDuration {
+/// Display the duration as a number followed by the name of the unit chosen by
+/// `appropriate_unit`.
+///
+/// The unit is chosen on the magnitude of the duration, and negative durations are
+/// prefixed with `-`. Durations of a nanosecond or less are shown in seconds in
+/// scientific notation, and infinite and `NAN` durations are shown in seconds as
+/// `inf`, `-inf`, and `NaN`.
+///
+/// Without a precision, the output parses back into exactly the same duration. For
+/// the few durations that no number of the chosen unit converts back into exactly,
+/// a shorter unit is used instead.
+///
+/// The formatting flags, such as precision, width, fill, and alignment, apply to
+/// the number, so durations line up in tables:
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// assert_eq!(format!("{}", FloatDuration::minutes(3.5)), "3.5 minutes");
+/// assert_eq!(format!("{:>6.2}", FloatDuration::minutes(3.5)), "  3.50 minutes");
+/// assert_eq!(format!("{:_<5}", FloatDuration::hours(12.0)), "12___ hours");
+/// ```
+impl<F> fmt::Display for GenericFloatDuration<F>
+    where F: Float + fmt::Display + fmt::LowerExp
+{
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let decimals = fmt.precision();
-
-        match decimals {
-            Some(decimals) => {
-                if self.secs > SECS_PER_YEAR {
-                    return write!(fmt, "{:.*} years", decimals, self.as_years());
-                } else if self.secs > SECS_PER_DAY {
-                    return write!(fmt, "{:.*} days", decimals, self.as_days());
-                } else if self.secs > SECS_PER_HOUR {
-                    return write!(fmt, "{:.*} hours", decimals, self.as_hours());
-                } else if self.secs > SECS_PER_MINUTE {
-                    return write!(fmt, "{:.*} minutes", decimals, self.as_minutes());
-                } else if self.secs > 1.0 {
-                    return write!(fmt, "{:.*} seconds", decimals, self.as_seconds());
-                } else if self.secs > 1.0e-3 {
-                    return write!(fmt, "{:.*} milliseconds", decimals, self.as_milliseconds());
-                } else if self.secs > 1.0e-6 {
-                    return write!(fmt, "{:.*} microseconds", decimals, self.as_microseconds());
-                } else if self.secs > 1.0e-9 {
-                    return write!(fmt, "{:.*} nanoseconds", decimals, self.as_nanoseconds());
-                } else if self.is_zero() {
-                    return write!(fmt, "0 seconds");
-                } else {
-                    // Here we simply print seconds in scientific notation.
-                    return write!(fmt, "{:e} seconds", self.as_seconds());
-                }
-            }
-            None => {
-                if self.secs > SECS_PER_YEAR {
-                    return write!(fmt, "{} years", self.as_years());
-                } else if self.secs > SECS_PER_DAY {
-                    return write!(fmt, "{} days", self.as_days());
-                } else if self.secs > SECS_PER_HOUR {
-                    return write!(fmt, "{} hours", self.as_hours());
-                } else if self.secs > SECS_PER_MINUTE {
-                    return write!(fmt, "{} minutes", self.as_minutes());
-                } else if self.secs > 1.0 {
-                    return write!(fmt, "{} seconds", self.as_seconds());
-                } else if self.secs > 1.0e-3 {
-                    return write!(fmt, "{} milliseconds", self.as_milliseconds());
-                } else if self.secs > 1.0e-6 {
-                    return write!(fmt, "{} microseconds", self.as_microseconds());
-                } else if self.secs > 1.0e-9 {
-                    return write!(fmt, "{} nanoseconds", self.as_nanoseconds());
-                } else if self.is_zero() {
-                    return write!(fmt, "0 seconds");
-                } else {
-                    return write!(fmt, "{:e} seconds", self.as_seconds());
-                }
+        let unit = self.appropriate_unit();
+        if unit == TimeUnit::Second && (!self.secs.is_finite() || self.secs.abs() <= F::one()) {
+            if self.is_zero() {
+                fmt::Display::fmt(&F::zero(), fmt)?;
+            } else {
+                // Here we simply print seconds in scientific notation.
+                fmt::LowerExp::fmt(&self.secs, fmt)?;
             }
+            return write!(fmt, " seconds");
         }
+
+        // Without a precision, pick the number so that the output parses back
+        // into exactly this duration.
+        let (value, unit) = match fmt.precision() {
+            Some(_) => (self.in_unit(unit), unit),
+            None => self.display_value(),
+        };
+        fmt::Display::fmt(&value, fmt)?;
+        write!(fmt, " {}", unit.name())
     }
 }
 
-impl ops::Neg for FloatDuration {
-    type Output = FloatDuration;
+impl<F: Float> ops::Neg for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
 
     #[inline]
-    fn neg(self) -> FloatDuration {
-        FloatDuration { secs: -self.secs }
+    fn neg(self) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: -self.secs }
     }
 }
 
-impl ops::Add<FloatDuration> for FloatDuration {
-    type Output = FloatDuration;
+impl<F: Float> ops::Add<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
 
     #[inline]
-    fn add(self, rhs: FloatDuration) -> FloatDuration {
-        FloatDuration { secs: self.secs + rhs.secs }
+    fn add(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs + rhs.secs }
     }
 }
-impl ops::Sub<FloatDuration> for FloatDuration {
-    type Output = FloatDuration;
+impl<F: Float> ops::Sub<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
 
     #[inline]
-    fn sub(self, rhs: FloatDuration) -> FloatDuration {
-        FloatDuration { secs: self.secs - rhs.secs }
+    fn sub(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs - rhs.secs }
     }
 }
 
-impl ops::Mul<f64> for FloatDuration {
-    type Output = FloatDuration;
+impl<F: Float> ops::Mul<F> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
+
+    #[inline]
+    fn mul(self, rhs: F) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs * rhs }
+    }
+}
+impl ops::Mul<FloatDuration64> for f64 {
+    type Output = FloatDuration64;
 
     #[inline]
-    fn mul(self, rhs: f64) -> FloatDuration {
-        FloatDuration { secs: self.secs * rhs }
+    fn mul(self, rhs: FloatDuration64) -> FloatDuration64 {
+        GenericFloatDuration { secs: self * rhs.secs }
     }
 }
-impl ops::Mul<FloatDuration> for f64 {
-    type Output = FloatDuration;
+impl ops::Mul<FloatDuration32> for f32 {
+    type Output = FloatDuration32;
 
     #[inline]
-    fn mul(self, rhs: FloatDuration) -> FloatDuration {
-        FloatDuration { secs: self * rhs.secs }
+    fn mul(self, rhs: FloatDuration32) -> FloatDuration32 {
+        GenericFloatDuration { secs: self * rhs.secs }
     }
 }
-impl ops::Div<f64> for FloatDuration {
-    type Output = FloatDuration;
+impl<F: Float> ops::Div<F> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
 
     #[inline]
-    fn div(self, rhs: f64) -> FloatDuration {
-        FloatDuration { secs: self.secs / rhs }
+    fn div(self, rhs: F) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs / rhs }
     }
 }
-impl ops::Div<FloatDuration> for FloatDuration {
-    type Output = f64;
+impl<F: Float> ops::Div<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    type Output = F;
 
     #[inline]
-    fn div(self, rhs: FloatDuration) -> f64 {
+    fn div(self, rhs: GenericFloatDuration<F>) -> F {
         self.secs / rhs.secs
     }
 }
 
-impl ops::AddAssign<FloatDuration> for FloatDuration {
+impl<F: Float> ops::Rem<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
+
+    /// The remainder of dividing `self` by `rhs`, such as the position of a
+    /// running time within a looping cycle.
+    ///
+    /// As with `%` on floats, the result has the same sign as `self`.
+    #[inline]
+    fn rem(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs % rhs.secs }
+    }
+}
+impl<F: Float> ops::Rem<F> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
+
+    /// The remainder of dividing `self` by `rhs` seconds.
+    #[inline]
+    fn rem(self, rhs: F) -> GenericFloatDuration<F> {
+        GenericFloatDuration { secs: self.secs % rhs }
+    }
+}
+
+impl<F: Float> ops::AddAssign<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: GenericFloatDuration<F>) {
+        self.secs = self.secs + rhs.secs;
+    }
+}
+impl<F: Float> ops::SubAssign<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: GenericFloatDuration<F>) {
+        self.secs = self.secs - rhs.secs;
+    }
+}
+
+impl<F: Float> ops::Add<time::Duration> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
+
+    #[inline]
+    fn add(self, rhs: time::Duration) -> GenericFloatDuration<F> {
+        self + GenericFloatDuration::from_std(rhs)
+    }
+}
+impl<F: Float> ops::Sub<time::Duration> for GenericFloatDuration<F> {
+    type Output = GenericFloatDuration<F>;
+
     #[inline]
-    fn add_assign(&mut self, rhs: FloatDuration) {
-        self.secs += rhs.secs;
+    fn sub(self, rhs: time::Duration) -> GenericFloatDuration<F> {
+        self - GenericFloatDuration::from_std(rhs)
     }
 }
-impl ops::SubAssign<FloatDuration> for FloatDuration {
+impl<F: Float> ops::Add<GenericFloatDuration<F>> for time::Duration {
+    type Output = GenericFloatDuration<F>;
+
     #[inline]
-    fn sub_assign(&mut self, rhs: FloatDuration) {
-        self.secs -= rhs.secs;
+    fn add(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_std(self) + rhs
     }
 }
+impl<F: Float> ops::Sub<GenericFloatDuration<F>> for time::Duration {
+    type Output = GenericFloatDuration<F>;
 
-impl ops::MulAssign<f64> for FloatDuration {
     #[inline]
-    fn mul_assign(&mut self, rhs: f64) {
-        self.secs *= rhs;
+    fn sub(self, rhs: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_std(self) - rhs
+    }
+}
+impl<F: Float> ops::AddAssign<time::Duration> for GenericFloatDuration<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: time::Duration) {
+        *self = *self + rhs;
     }
 }
-impl ops::DivAssign<f64> for FloatDuration {
+impl<F: Float> ops::SubAssign<time::Duration> for GenericFloatDuration<F> {
     #[inline]
-    fn div_assign(&mut self, rhs: f64) {
-        self.secs /= rhs;
+    fn sub_assign(&mut self, rhs: time::Duration) {
+        *self = *self - rhs;
     }
 }
-impl Default for FloatDuration {
+
+impl<F: Float> ops::MulAssign<F> for GenericFloatDuration<F> {
     #[inline]
-    fn default() -> FloatDuration {
-        FloatDuration::zero()
+    fn mul_assign(&mut self, rhs: F) {
+        self.secs = self.secs * rhs;
     }
 }
-impl Sum for FloatDuration {
-    fn sum<I>(iter: I) -> FloatDuration
-        where I: Iterator<Item = FloatDuration>
+impl<F: Float> ops::DivAssign<F> for GenericFloatDuration<F> {
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        self.secs = self.secs / rhs;
+    }
+}
+impl<F: Float> ops::RemAssign<GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: GenericFloatDuration<F>) {
+        self.secs = self.secs % rhs.secs;
+    }
+}
+impl<F: Float> ops::RemAssign<F> for GenericFloatDuration<F> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: F) {
+        self.secs = self.secs % rhs;
+    }
+}
+impl<F: Float> Default for GenericFloatDuration<F> {
+    #[inline]
+    fn default() -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(F::zero())
+    }
+}
+impl<F: Float> Sum for GenericFloatDuration<F> {
+    fn sum<I>(iter: I) -> GenericFloatDuration<F>
+        where I: Iterator<Item = GenericFloatDuration<F>>
     {
-        iter.fold(FloatDuration::zero(), |a, b| a + b)
+        iter.fold(GenericFloatDuration::seconds(F::zero()), |a, b| a + b)
     }
 }
-impl<'a> Sum<&'a FloatDuration> for FloatDuration {
-    fn sum<I>(iter: I) -> FloatDuration
-        where I: Iterator<Item = &'a FloatDuration>
+impl<'a, F: Float> Sum<&'a GenericFloatDuration<F>> for GenericFloatDuration<F> {
+    fn sum<I>(iter: I) -> GenericFloatDuration<F>
+        where I: Iterator<Item = &'a GenericFloatDuration<F>>
     {
-        iter.fold(FloatDuration::zero(), |a, &b| a + b)
+        iter.fold(GenericFloatDuration::seconds(F::zero()), |a, &b| a + b)
     }
 }
 
 #[cfg(feature = "approx")]
-impl ApproxEq for FloatDuration {
-    type Epsilon = f64;
+impl<F: AbsDiffEq> AbsDiffEq for GenericFloatDuration<F> {
+    type Epsilon = F::Epsilon;
 
     #[inline]
-    fn default_epsilon() -> f64 {
-        f64::default_epsilon()
+    fn default_epsilon() -> F::Epsilon {
+        F::default_epsilon()
     }
     #[inline]
-    fn default_max_relative() -> f64 {
-        f64::default_max_relative()
+    fn abs_diff_eq(&self, other: &GenericFloatDuration<F>, epsilon: F::Epsilon) -> bool {
+        self.secs.abs_diff_eq(&other.secs, epsilon)
     }
+}
+#[cfg(feature = "approx")]
+impl<F: RelativeEq> RelativeEq for GenericFloatDuration<F> {
     #[inline]
-    fn default_max_ulps() -> u32 {
-        f64::default_max_ulps()
+    fn default_max_relative() -> F::Epsilon {
+        F::default_max_relative()
     }
     #[inline]
-    fn relative_eq(&self, other: &FloatDuration, epsilon: f64, max_relative: f64) -> bool {
+    fn relative_eq(&self,
+                   other: &GenericFloatDuration<F>,
+                   epsilon: F::Epsilon,
+                   max_relative: F::Epsilon)
+                   -> bool {
         self.secs.relative_eq(&other.secs, epsilon, max_relative)
     }
+}
+#[cfg(feature = "approx")]
+impl<F: UlpsEq> UlpsEq for GenericFloatDuration<F> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
     #[inline]
-    fn ulps_eq(&self, other: &FloatDuration, epsilon: f64, max_ulps: u32) -> bool {
+    fn ulps_eq(&self, other: &GenericFloatDuration<F>, epsilon: F::Epsilon, max_ulps: u32) -> bool {
         self.secs.ulps_eq(&other.secs, epsilon, max_ulps)
     }
 }
@@ -705,6 +1601,10 @@ mod tests {
         assert_eq!(duration4.signum(), -1.0);
 
         assert_eq!(FloatDuration::years(2.0), FloatDuration::days(365.0 * 2.0));
+        assert_eq!(FloatDuration::weeks(2.0), FloatDuration::days(14.0));
+        assert_eq!(FloatDuration::months(12.0), FloatDuration::years(1.0));
+        assert_eq!(FloatDuration::years(0.5).as_months(), 6.0);
+        assert_eq!(FloatDuration::days(3.5).as_weeks(), 0.5);
     }
 
     #[test]
@@ -751,6 +1651,235 @@ mod tests {
         assert_eq!(d1, FloatDuration::zero());
     }
 
+    #[test]
+    fn test_rem() {
+        let cycle = FloatDuration::seconds(2.0);
+        assert_eq!(FloatDuration::seconds(7.5) % cycle, FloatDuration::seconds(1.5));
+        assert_eq!(FloatDuration::seconds(-7.5) % cycle, FloatDuration::seconds(-1.5));
+        assert_eq!(FloatDuration::minutes(1.0) % 7.0, FloatDuration::seconds(4.0));
+        assert!((FloatDuration::seconds(1.0) % FloatDuration::zero()).as_seconds().is_nan());
+
+        let mut t = FloatDuration::seconds(5.0);
+        t %= cycle;
+        assert_eq!(t, FloatDuration::seconds(1.0));
+        t %= 0.75;
+        assert_eq!(t, FloatDuration::seconds(0.25));
+    }
+
+    #[test]
+    fn test_mul_add() {
+        assert_eq!(FloatDuration::seconds(2.0).mul_add(3.0, FloatDuration::seconds(1.0)),
+                   FloatDuration::seconds(7.0));
+        assert_eq!(FloatDuration32::minutes(1.0).mul_add(-0.5, FloatDuration32::zero()),
+                   FloatDuration32::seconds(-30.0));
+
+        // A separately rounded product would lose the low bits of 0.1 * 0.1.
+        let tenth = FloatDuration::seconds(0.1);
+        assert_eq!(tenth.mul_add(0.1, FloatDuration::seconds(-0.01)),
+                   FloatDuration::seconds(0.1f64.mul_add(0.1, -0.01)));
+        assert!(tenth.mul_add(0.1, FloatDuration::seconds(-0.01)) != FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = FloatDuration::seconds(0.1);
+        let b = FloatDuration::seconds(0.7);
+        assert_eq!(FloatDuration::lerp(a, b, 0.0), a);
+        assert_eq!(FloatDuration::lerp(a, b, 1.0), b);
+        assert_eq!(FloatDuration::lerp(FloatDuration::zero(), FloatDuration::minutes(1.0), 1.5),
+                   FloatDuration::seconds(90.0));
+        assert_eq!(FloatDuration::lerp(FloatDuration::seconds(4.0), FloatDuration::zero(), 0.5),
+                   FloatDuration::seconds(2.0));
+
+        assert_eq!(FloatDuration::inverse_lerp(FloatDuration::seconds(4.0),
+                                               FloatDuration::zero(),
+                                               FloatDuration::seconds(1.0)),
+                   0.75);
+        assert_eq!(FloatDuration::inverse_lerp(a, b, b), 1.0);
+        assert!(!FloatDuration::inverse_lerp(a, a, b).is_finite());
+        assert!(FloatDuration::inverse_lerp(a, a, a).is_nan());
+    }
+
+    #[test]
+    fn test_copysign() {
+        let d = FloatDuration::seconds(3.0);
+        assert_eq!(d.copysign(FloatDuration::seconds(-0.5)), FloatDuration::seconds(-3.0));
+        assert_eq!((-d).copysign(FloatDuration::minutes(1.0)), d);
+        assert_eq!(d.copysign(-FloatDuration::zero()), -d);
+        assert_eq!(d.copysign(FloatDuration::zero()).signum(), 1.0);
+        assert_eq!((-d).signum(), -1.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = FloatDuration::minutes(1.0);
+        assert_eq!(a.abs_diff(-a), FloatDuration::minutes(2.0));
+        assert!(a.approx_eq(FloatDuration::seconds(60.5), FloatDuration::seconds(0.5)));
+        assert!(!a.approx_eq(FloatDuration::seconds(60.5), FloatDuration::seconds(0.4)));
+        assert!(a.approx_eq(a, FloatDuration::zero()));
+
+        let nan = FloatDuration::zero() / 0.0;
+        assert!(!nan.approx_eq(nan, FloatDuration::max_value()));
+        let inf = FloatDuration::seconds(1.0) / 0.0;
+        assert!(!inf.approx_eq(inf, FloatDuration::max_value()));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let min = FloatDuration::seconds(-1.0);
+        let max = FloatDuration::seconds(1.0);
+        assert_eq!(FloatDuration::seconds(0.5).clamp(min, max), FloatDuration::seconds(0.5));
+        assert_eq!(FloatDuration::minutes(1.0).clamp(min, max), max);
+        assert_eq!(FloatDuration::minutes(-1.0).clamp(min, max), min);
+        assert_eq!(FloatDuration::max_value().clamp(max, max), max);
+        assert!((FloatDuration::zero() / 0.0).clamp(min, max).as_seconds().is_nan());
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_clamp_panic() {
+        FloatDuration::zero().clamp(FloatDuration::seconds(1.0), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_euclid() {
+        let period = FloatDuration::seconds(3.0);
+        for &(t, q, r) in &[(7.0, 2.0, 1.0), (-7.0, -3.0, 2.0), (-3.0, -1.0, 0.0), (0.0, 0.0, 0.0)] {
+            let t = FloatDuration::seconds(t);
+            assert_eq!(t.div_euclid(period), q);
+            assert_eq!(t.rem_euclid(period), FloatDuration::seconds(r));
+            assert_eq!(period * t.div_euclid(period) + t.rem_euclid(period), t);
+        }
+
+        let negative = FloatDuration::seconds(-3.0);
+        assert_eq!(FloatDuration::seconds(7.0).div_euclid(negative), -2.0);
+        assert_eq!(FloatDuration::seconds(-7.0).div_euclid(negative), 3.0);
+        assert_eq!(FloatDuration::seconds(-7.0).rem_euclid(negative),
+                   FloatDuration::seconds(2.0));
+        assert_eq!(FloatDuration32::seconds(-1.0).rem_euclid(FloatDuration32::seconds(4.0)),
+                   FloatDuration32::seconds(3.0));
+    }
+
+    #[test]
+    fn test_nanos_i128() {
+        assert_eq!(FloatDuration::seconds(1.123).as_nanos_i128(), Ok(1_123_000_000));
+        assert_eq!(FloatDuration::zero().as_nanos_i128(), Ok(0));
+        assert_eq!(FloatDuration::days(-1.0).as_nanos_i128(), Ok(-86_400_000_000_000));
+        assert!(FloatDuration::max_value().as_nanos_i128().is_err());
+        assert!((FloatDuration::zero() / 0.0).as_nanos_i128().is_err());
+
+        for &nanos in &[0, 1, 123, -999_999_999, 1_000_000_001, 86_400_000_000_123] {
+            assert_eq!(FloatDuration::from_nanos_i128(nanos).as_nanos_i128(), Ok(nanos));
+        }
+        assert_eq!(FloatDuration::from_nanos_i128(-1_500_000_000), FloatDuration::seconds(-1.5));
+        assert_eq!(FloatDuration32::from_nanos_i128(250_000_000),
+                   FloatDuration32::milliseconds(250.0));
+        assert_eq!(FloatDuration::from_nanos_i128(i128::MAX).as_seconds(), i128::MAX as f64 / 1e9);
+    }
+
+    #[test]
+    fn test_constants() {
+        const TIMEOUT: FloatDuration = FloatDuration::minutes(2.5);
+        const FRAME: FloatDuration32 = FloatDuration32::milliseconds(16.0);
+
+        assert_eq!(TIMEOUT, FloatDuration::seconds(150.0));
+        assert_eq!(FRAME, FloatDuration32::seconds(0.016));
+        assert_eq!(FloatDuration::ZERO, FloatDuration::zero());
+        assert_eq!(FloatDuration::SECOND, FloatDuration::seconds(1.0));
+        assert_eq!(FloatDuration::MINUTE, FloatDuration::minutes(1.0));
+        assert_eq!(FloatDuration::HOUR, FloatDuration::hours(1.0));
+        assert_eq!(FloatDuration::DAY, FloatDuration::days(1.0));
+        assert_eq!(FloatDuration32::WEEK, FloatDuration32::weeks(1.0));
+        assert_eq!(FloatDuration::MONTH, FloatDuration::months(1.0));
+        assert_eq!(FloatDuration::YEAR, FloatDuration::years(1.0));
+        assert_eq!(FloatDuration::MILLISECOND, FloatDuration::milliseconds(1.0));
+        assert_eq!(FloatDuration::MICROSECOND, FloatDuration::microseconds(1.0));
+        assert_eq!(FloatDuration::NANOSECOND, FloatDuration::nanoseconds(1.0));
+        assert_eq!(FloatDuration::MAX, FloatDuration::max_value());
+        assert_eq!(FloatDuration::MIN, FloatDuration::min_value());
+        assert_eq!(FloatDuration32::HOUR, FloatDuration32::minutes(60.0));
+    }
+
+    #[test]
+    fn test_f32() {
+        let duration = FloatDuration32::minutes(1.5);
+        assert_eq!(duration.as_seconds(), 90.0f32);
+        assert_eq!(duration * 2.0, FloatDuration32::minutes(3.0));
+        assert_eq!(2.0 * duration, FloatDuration32::minutes(3.0));
+        assert_eq!(duration / FloatDuration32::seconds(30.0), 3.0f32);
+        assert_eq!(duration.to_f64(), FloatDuration::minutes(1.5));
+        assert_eq!(FloatDuration::from(duration), FloatDuration::seconds(90.0));
+        assert_eq!(FloatDuration::seconds(0.25).to_f32(),
+                   FloatDuration32::seconds(0.25));
+        assert_eq!(duration.to_std().unwrap(), time::Duration::new(90, 0));
+        assert_eq!(format!("{}", duration), "1.5 minutes");
+    }
+
+    #[test]
+    fn test_checked() {
+        let nan = FloatDuration::seconds(f64::NAN);
+
+        assert_eq!(FloatDuration::hours(1.0).checked_sub(FloatDuration::minutes(90.0)),
+                   Some(FloatDuration::minutes(-30.0)));
+        assert_eq!(FloatDuration::seconds(3.0).checked_mul(2.0),
+                   Some(FloatDuration::seconds(6.0)));
+        assert_eq!(FloatDuration::seconds(3.0).checked_div(2.0),
+                   Some(FloatDuration::seconds(1.5)));
+        assert_eq!(FloatDuration::seconds(3.0).checked_div(0.0), None);
+        assert_eq!(FloatDuration::zero().checked_div(0.0), None);
+        assert_eq!(FloatDuration::seconds(1.0).checked_add(nan), None);
+        assert_eq!(FloatDuration::min_value().checked_sub(FloatDuration::max_value()),
+                   None);
+        assert_eq!(FloatDuration::max_value().checked_mul(2.0), None);
+        assert_eq!(FloatDuration32::max_value().checked_add(FloatDuration32::max_value()),
+                   None);
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(FloatDuration::minutes(1.0).saturating_add(FloatDuration::seconds(1.0)),
+                   FloatDuration::seconds(61.0));
+        assert_eq!(FloatDuration::min_value().saturating_sub(FloatDuration::max_value()),
+                   FloatDuration::min_value());
+        assert_eq!(FloatDuration::max_value().saturating_mul(-2.0),
+                   FloatDuration::min_value());
+        assert_eq!(FloatDuration::hours(2.0).saturating_mul(0.5),
+                   FloatDuration::hours(1.0));
+        assert_eq!(FloatDuration32::max_value().saturating_mul(10.0),
+                   FloatDuration32::max_value());
+        assert!(FloatDuration::seconds(f64::NAN)
+                    .saturating_add(FloatDuration::zero())
+                    .as_seconds()
+                    .is_nan());
+    }
+
+    #[test]
+    fn test_total_order() {
+        let nan = FloatDuration::seconds(f64::NAN);
+        let inf = FloatDuration::seconds(f64::INFINITY);
+
+        assert_eq!(FloatDuration::seconds(1.0).total_cmp(&FloatDuration::seconds(2.0)),
+                   Ordering::Less);
+        assert_eq!(FloatDuration::seconds(-0.0).total_cmp(&FloatDuration::zero()),
+                   Ordering::Less);
+        assert_eq!(nan.total_cmp(&inf), Ordering::Greater);
+        assert_eq!((-nan).total_cmp(&-inf), Ordering::Less);
+        assert_eq!(nan.total_ord(), nan.total_ord());
+
+        let mut durations = vec![FloatDuration::minutes(1.0).total_ord(),
+                                 nan.total_ord(),
+                                 FloatDuration::seconds(-5.0).total_ord(),
+                                 inf.total_ord()];
+        durations.sort();
+        let durations: Vec<FloatDuration> = durations.into_iter().map(|d| d.0).collect();
+        assert_eq!(durations[..3],
+                   [FloatDuration::seconds(-5.0), FloatDuration::minutes(1.0), inf]);
+        assert!(durations[3].as_seconds().is_nan());
+
+        assert!(FloatDuration32::seconds(1.0).total_ord() <
+                FloatDuration32::seconds(1.5).total_ord());
+    }
+
     #[test]
     fn test_min_max() {
         assert_eq!(FloatDuration::minutes(5.0).max(FloatDuration::minutes(10.0)),
@@ -761,9 +1890,15 @@ mod tests {
                    FloatDuration::seconds(1.0));
         assert_eq!(FloatDuration::zero().min(FloatDuration::seconds(1.0)),
                    FloatDuration::zero());
+
+        let nan = FloatDuration::zero() / 0.0;
+        assert_eq!(nan.min(FloatDuration::seconds(1.0)), FloatDuration::seconds(1.0));
+        assert_eq!(FloatDuration::seconds(1.0).max(nan), FloatDuration::seconds(1.0));
+        assert!(nan.max(nan).as_seconds().is_nan());
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn test_std_conversion() {
         let duration1 = FloatDuration::minutes(5.0);
         let std_duration1 = duration1.to_std().unwrap();
@@ -796,6 +1931,38 @@ mod tests {
                    FloatDuration::seconds(1.0) + FloatDuration::nanoseconds(1.0));
     }
 
+    #[test]
+    fn test_std_saturating() {
+        assert_eq!(FloatDuration::milliseconds(1500.0).to_std_saturating(),
+                   time::Duration::from_millis(1500));
+        assert_eq!(FloatDuration::zero().to_std_saturating(), time::Duration::from_secs(0));
+        assert_eq!(FloatDuration::seconds(-0.0).to_std_saturating(),
+                   time::Duration::from_secs(0));
+        assert_eq!(FloatDuration::hours(-1.0).to_std_saturating(), time::Duration::from_secs(0));
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_std_saturating(),
+                   time::Duration::from_secs(0));
+        assert_eq!(FloatDuration::max_value().to_std_saturating(), time::Duration::MAX);
+        assert_eq!(FloatDuration32::seconds(f32::INFINITY).to_std_saturating(),
+                   time::Duration::MAX);
+    }
+
+    #[test]
+    fn test_std_arithmetic() {
+        let std_duration = time::Duration::from_millis(1500);
+        assert_eq!(FloatDuration::seconds(1.0) + std_duration, FloatDuration::seconds(2.5));
+        assert_eq!(FloatDuration::seconds(1.0) - std_duration, FloatDuration::seconds(-0.5));
+        assert_eq!(std_duration + FloatDuration::seconds(1.0), FloatDuration::seconds(2.5));
+        assert_eq!(std_duration - FloatDuration::seconds(2.0), FloatDuration::seconds(-0.5));
+        assert_eq!(std_duration + FloatDuration32::seconds(1.0), FloatDuration32::seconds(2.5));
+
+        let mut duration = FloatDuration::zero();
+        duration += std_duration;
+        duration += std_duration;
+        duration -= time::Duration::from_secs(1);
+        assert_eq!(duration, FloatDuration::seconds(2.0));
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_time_point_std() {
         use std::time::{Instant, SystemTime, UNIX_EPOCH};
@@ -811,6 +1978,66 @@ mod tests {
                 FloatDuration::years(30.0));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_elapsed_std() {
+        use std::time::{Instant, SystemTime};
+
+        let start = Instant::now();
+        ::std::thread::sleep(::std::time::Duration::from_millis(2));
+        assert!(start.float_elapsed().unwrap() >= FloatDuration::milliseconds(2.0));
+        assert!(float_elapsed_since(start).unwrap() >= FloatDuration::milliseconds(2.0));
+
+        let start = SystemTime::now();
+        assert!(float_elapsed_since(start).unwrap() >= FloatDuration::zero());
+        assert!((SystemTime::now() + FloatDuration::hours(1.0)).float_elapsed().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_elapsed_chrono() {
+        use chrono::{Local, Utc};
+
+        let start = Utc::now();
+        assert!(start.float_elapsed().unwrap() >= FloatDuration::zero());
+        let start = Local::now() + FloatDuration::hours(1.0);
+        assert!(float_elapsed_since(start).unwrap() < FloatDuration::zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_time_offset_std() {
+        use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+        let epoch_plus = UNIX_EPOCH + FloatDuration::seconds(90.5);
+        assert_eq!(epoch_plus, UNIX_EPOCH + Duration::from_millis(90500));
+        assert_eq!(epoch_plus - FloatDuration::seconds(0.5),
+                   UNIX_EPOCH + Duration::from_secs(90));
+        assert_eq!(epoch_plus + FloatDuration::seconds(-0.5),
+                   UNIX_EPOCH + Duration::from_secs(90));
+        assert_eq!(UNIX_EPOCH + FloatDuration32::seconds(1.0),
+                   UNIX_EPOCH + Duration::from_secs(1));
+
+        let now = Instant::now();
+        let mut later = now;
+        later += FloatDuration::seconds(2.0);
+        later -= FloatDuration::seconds(0.5);
+        assert_eq!(later.float_duration_since(now), Ok(FloatDuration::seconds(1.5)));
+        assert_eq!(now.checked_sub_float(FloatDuration::seconds(-1.5)), Some(later));
+
+        assert_eq!(now.checked_add_float(FloatDuration::seconds(f64::NAN)), None);
+        assert_eq!(now.checked_add_float(FloatDuration::max_value()), None);
+        assert_eq!(SystemTime::now().checked_add_float(FloatDuration::seconds(f64::INFINITY)),
+                   None);
+    }
+
+    #[cfg(feature = "std")]
+    #[should_panic]
+    #[test]
+    fn test_time_offset_overflow_panic() {
+        let _ = ::std::time::Instant::now() + FloatDuration::max_value();
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", FloatDuration::minutes(3.5)), "3.5 minutes");
@@ -836,6 +2063,40 @@ mod tests {
         assert_eq!(format!("{}", FloatDuration::years(2.5)), "2.5 years");
         assert_eq!(format!("{}", FloatDuration::seconds(1.5e-30)),
                    "1.5e-30 seconds");
+        assert_eq!(format!("{}", FloatDuration::minutes(-5.0)), "-5 minutes");
+        assert_eq!(format!("{:.1}", FloatDuration::hours(1.25)), "1.2 hours");
+        assert_eq!(format!("{:.2}", FloatDuration::zero()), "0.00 seconds");
+        assert_eq!(format!("{:.1}", FloatDuration::seconds(1.25e-12)), "1.2e-12 seconds");
+        assert_eq!(format!("{:+}", FloatDuration::days(2.0)), "+2 days");
+        assert_eq!(format!("{:08.3}", FloatDuration::milliseconds(-1.5)),
+                   "-001.500 milliseconds");
+        assert_eq!(format!("{:^7}|", FloatDuration::seconds(2.5)), "  2.5   seconds|");
+    }
+
+    #[test]
+    fn test_display_signed_and_non_finite() {
+        assert_eq!(format!("{}", FloatDuration::hours(-3.0)), "-3 hours");
+        assert_eq!(format!("{}", FloatDuration::nanoseconds(-0.5)), "-5e-10 seconds");
+        assert_eq!(format!("{}", FloatDuration::seconds(f64::INFINITY)), "inf seconds");
+        assert_eq!(format!("{}", FloatDuration::seconds(f64::NEG_INFINITY)), "-inf seconds");
+        assert_eq!(format!("{}", FloatDuration::seconds(f64::NAN)), "NaN seconds");
+        assert_eq!(format!("{:>5}", FloatDuration32::seconds(f32::INFINITY)), "  inf seconds");
+        assert_eq!(format!("{:+}", FloatDuration::seconds(f64::INFINITY)), "+inf seconds");
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        let a = FloatDuration::seconds(0.1) + FloatDuration::seconds(0.2);
+        let b = FloatDuration::seconds(0.3);
+        assert!(a != b);
+        ::approx::assert_abs_diff_eq!(a, b);
+        ::approx::assert_relative_eq!(a, b);
+        ::approx::assert_ulps_eq!(a, b);
+        ::approx::assert_relative_ne!(a, FloatDuration::seconds(0.31));
+        assert!(a.abs_diff_eq(&FloatDuration::seconds(0.31), 0.02));
+        ::approx::assert_relative_eq!(FloatDuration32::seconds(0.1) * 3.0,
+                                      FloatDuration32::seconds(0.3));
     }
 
     #[test]
@@ -879,7 +2140,17 @@ mod tests {
         assert!(FloatDuration::max_value().to_chrono().is_err());
         assert_eq!(FloatDuration::nanoseconds(-20.0).to_chrono().unwrap(),
                    chrono::Duration::nanoseconds(-20));
+        assert_eq!(FloatDuration::seconds(-1.5).to_chrono().unwrap(),
+                   chrono::Duration::milliseconds(-1500));
+        assert_eq!(FloatDuration::years(1000.0).to_chrono().unwrap(),
+                   chrono::Duration::days(365000));
+        assert!(FloatDuration::years(1e12).to_chrono().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_chrono().is_err());
 
+        // Sub-millisecond precision is kept beyond the range of `num_nanoseconds`.
+        let large = chrono::Duration::days(200000) + chrono::Duration::microseconds(1500);
+        assert_eq!(FloatDuration::from_chrono(large),
+                   FloatDuration::seconds(200000.0 * SECS_PER_DAY + 0.0015));
     }
 
     #[cfg(feature = "chrono")]
@@ -899,6 +2170,30 @@ mod tests {
         assert!(date3.float_duration_since(Local::now()).unwrap() < FloatDuration::zero());
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_time_offset() {
+        use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+
+        let date = Utc.ymd(2017, 5, 25).and_hms(10, 0, 0);
+        assert_eq!(date + FloatDuration::hours(26.5), Utc.ymd(2017, 5, 26).and_hms(12, 30, 0));
+        assert_eq!(date - FloatDuration::milliseconds(1.5),
+                   Utc.ymd(2017, 5, 25).and_hms_micro(9, 59, 59, 998500));
+        assert_eq!(date.checked_add_float(FloatDuration::years(1e12)), None);
+
+        let mut naive = NaiveDate::from_ymd(2017, 5, 25).and_hms(10, 0, 0);
+        naive += FloatDuration::minutes(-90.0);
+        assert_eq!(naive, NaiveDate::from_ymd(2017, 5, 25).and_hms(8, 30, 0));
+        naive -= FloatDuration32::days(1.0);
+        assert_eq!(naive, NaiveDate::from_ymd(2017, 5, 24).and_hms(8, 30, 0));
+        assert_eq!(naive.checked_add_float(FloatDuration::years(1e6)), None);
+
+        assert_eq!(NaiveTime::from_hms(23, 0, 0) + FloatDuration::hours(2.0),
+                   NaiveTime::from_hms(1, 0, 0));
+        assert_eq!(NaiveTime::from_hms(1, 0, 0).checked_sub_float(FloatDuration::seconds(1.0)),
+                   Some(NaiveTime::from_hms(0, 59, 59)));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
@@ -0,0 +1,197 @@
+//! Easing curves, mapping the linear progress of an animation to an eased one.
+//!
+//! The progress is usually computed from durations, such as `elapsed / total`, or
+//! taken from a [`Tween`](../tween/struct.Tween.html):
+//!
+//! ```rust
+//! use float_duration::FloatDuration;
+//! use float_duration::easing::{ease, Easing};
+//!
+//! let elapsed = FloatDuration::milliseconds(150.0);
+//! let total = FloatDuration::milliseconds(300.0);
+//! assert_eq!(ease(Easing::CubicOut, elapsed / total), 0.875);
+//! ```
+use core::f64::consts::PI;
+
+use num_traits::Float;
+
+use tween::Tween;
+
+/// An easing curve. The formulas are those popularized by Robert Penner.
+///
+/// Every curve maps 0 to 0 and 1 to 1. `In` curves start slowly, `Out` curves end
+/// slowly, and `InOut` curves do both. The `Back` curves overshoot slightly, and
+/// the `Elastic` curves oscillate around their ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// No easing.
+    Linear,
+    /// A quadratic curve starting slowly.
+    QuadIn,
+    /// A quadratic curve ending slowly.
+    QuadOut,
+    /// A quadratic curve starting and ending slowly.
+    QuadInOut,
+    /// A cubic curve starting slowly.
+    CubicIn,
+    /// A cubic curve ending slowly.
+    CubicOut,
+    /// A cubic curve starting and ending slowly.
+    CubicInOut,
+    /// A sine curve starting slowly.
+    SineIn,
+    /// A sine curve ending slowly.
+    SineOut,
+    /// A sine curve starting and ending slowly.
+    SineInOut,
+    /// An exponential curve starting slowly.
+    ExpoIn,
+    /// An exponential curve ending slowly.
+    ExpoOut,
+    /// An exponential curve starting and ending slowly.
+    ExpoInOut,
+    /// A curve first moving back slightly before the start.
+    BackIn,
+    /// A curve overshooting the end slightly before settling.
+    BackOut,
+    /// A curve moving back slightly at the start and overshooting the end.
+    BackInOut,
+    /// A curve oscillating with growing amplitude before leaving the start.
+    ElasticIn,
+    /// A curve oscillating with shrinking amplitude around the end.
+    ElasticOut,
+    /// A curve oscillating around both the start and the end.
+    ElasticInOut,
+}
+
+/// The amount of overshoot of the `Back` curves.
+const BACK: f64 = 1.70158;
+
+impl Easing {
+    /// Every easing curve.
+    pub const ALL: [Easing; 19] = [Easing::Linear,
+                                   Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut,
+                                   Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut,
+                                   Easing::SineIn, Easing::SineOut, Easing::SineInOut,
+                                   Easing::ExpoIn, Easing::ExpoOut, Easing::ExpoInOut,
+                                   Easing::BackIn, Easing::BackOut, Easing::BackInOut,
+                                   Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut];
+
+    /// Map `progress`, clamped to `[0, 1]`, through the curve.
+    ///
+    /// The result is in `[0, 1]` except for the `Back` and `Elastic` curves, which
+    /// go slightly beyond.
+    pub fn apply(&self, progress: f64) -> f64 {
+        let t = progress.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - Float::powi(1.0 - t, 2),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - Float::powi(1.0 - t, 3),
+            Easing::CubicInOut => in_out(t, |t| t * t * t),
+            Easing::SineIn => 1.0 - Float::cos(t * PI / 2.0),
+            Easing::SineOut => Float::sin(t * PI / 2.0),
+            Easing::SineInOut => (1.0 - Float::cos(t * PI)) / 2.0,
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => 1.0 - expo_in(1.0 - t),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => 1.0 - back_in(1.0 - t),
+            Easing::BackInOut => in_out(t, back_in),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t),
+            Easing::ElasticInOut => in_out(t, elastic_in),
+        }
+    }
+}
+
+/// Map `progress`, clamped to `[0, 1]`, through the `easing` curve.
+///
+/// This is a shorthand for `easing.apply(progress)`.
+#[inline]
+pub fn ease(easing: Easing, progress: f64) -> f64 {
+    easing.apply(progress)
+}
+
+impl Tween {
+    /// The progress of the tween mapped through the `easing` curve.
+    pub fn eased_progress(&self, easing: Easing) -> f64 {
+        easing.apply(self.progress())
+    }
+}
+
+/// Build an `InOut` curve by running the `In` curve `ease_in` over the first half
+/// and its mirror image over the second.
+fn in_out<E: Fn(f64) -> f64>(t: f64, ease_in: E) -> f64 {
+    if t < 0.5 {
+        ease_in(2.0 * t) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+fn expo_in(t: f64) -> f64 {
+    if t == 0.0 {
+        0.0
+    } else {
+        Float::powf(2.0, 10.0 * t - 10.0)
+    }
+}
+
+fn back_in(t: f64) -> f64 {
+    (BACK + 1.0) * t * t * t - BACK * t * t
+}
+
+fn elastic_in(t: f64) -> f64 {
+    if t == 0.0 || t == 1.0 {
+        t
+    } else {
+        -Float::powf(2.0, 10.0 * t - 10.0) * Float::sin((10.0 * t - 10.75) * 2.0 * PI / 3.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::FloatDuration;
+    use tween::TweenMode;
+
+    #[test]
+    fn test_endpoints() {
+        for easing in Easing::ALL.iter() {
+            assert!(easing.apply(0.0).abs() < 1e-12, "{:?} at 0", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-12, "{:?} at 1", easing);
+            assert_eq!(easing.apply(-1.0), easing.apply(0.0));
+            assert_eq!(easing.apply(2.0), easing.apply(1.0));
+        }
+    }
+
+    #[test]
+    fn test_curves() {
+        assert_eq!(ease(Easing::Linear, 0.3), 0.3);
+        assert_eq!(ease(Easing::QuadIn, 0.5), 0.25);
+        assert_eq!(ease(Easing::QuadOut, 0.5), 0.75);
+        assert_eq!(ease(Easing::CubicInOut, 0.25), 0.0625);
+        assert_eq!(ease(Easing::CubicInOut, 0.75), 0.9375);
+        assert!((ease(Easing::SineInOut, 0.5) - 0.5).abs() < 1e-12);
+        assert_eq!(ease(Easing::ExpoIn, 0.5), 1.0 / 32.0);
+        assert!(ease(Easing::BackIn, 0.2) < 0.0);
+        assert!(ease(Easing::BackOut, 0.8) > 1.0);
+        assert!(ease(Easing::ElasticOut, 0.2) > 1.0);
+
+        let in_out = [Easing::QuadInOut, Easing::CubicInOut, Easing::SineInOut,
+                      Easing::ExpoInOut, Easing::BackInOut, Easing::ElasticInOut];
+        for easing in in_out.iter() {
+            assert!((easing.apply(0.3) + easing.apply(0.7) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tween_easing() {
+        let mut tween = Tween::new(FloatDuration::seconds(2.0), TweenMode::Once);
+        tween.tick(FloatDuration::seconds(1.0));
+        assert_eq!(tween.eased_progress(Easing::QuadIn), 0.25);
+    }
+}
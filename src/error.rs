@@ -1,11 +1,18 @@
 //! Error handling facilities.
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+#[cfg(feature = "std")]
+use std::string::String;
+use core::fmt;
 
 #[cfg(feature = "chrono")]
 use time;
+#[cfg(feature = "serde")]
+use serde_crate::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Default)]
+/// An error returned when a duration cannot be represented by the target type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutOfRangeError {}
 
 impl OutOfRangeError {
@@ -14,6 +21,7 @@ impl OutOfRangeError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for OutOfRangeError {
     fn description(&self) -> &str {
         "The converted duration value is out of range."
@@ -22,7 +30,7 @@ impl Error for OutOfRangeError {
 
 impl fmt::Display for OutOfRangeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        write!(f, "The converted duration value is out of range.")
     }
 }
 
@@ -32,3 +40,120 @@ impl From<time::OutOfRangeError> for OutOfRangeError {
         OutOfRangeError {}
     }
 }
+
+/// An error encountered while parsing a `FloatDuration` from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParseDurationError {
+    /// The input string was empty or only contained whitespace.
+    Empty,
+    /// The numeric part of the input could not be parsed as a float.
+    InvalidNumber,
+    /// The input contained a number with no unit following it.
+    MissingUnit,
+    /// The unit following the number was not recognized.
+    UnknownUnit,
+    /// The input did not match the expected structure of the format.
+    InvalidFormat,
+}
+
+impl ParseDurationError {
+    fn message(&self) -> &'static str {
+        match *self {
+            ParseDurationError::Empty => "The duration string was empty.",
+            ParseDurationError::InvalidNumber => "The duration string contained an invalid number.",
+            ParseDurationError::MissingUnit => "The duration string is missing a unit.",
+            ParseDurationError::UnknownUnit => "The duration string contained an unknown unit.",
+            ParseDurationError::InvalidFormat => "The duration string was not in the expected format.",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseDurationError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// An error encountered while reading a `FloatDuration` from an environment variable.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnvDurationError {
+    /// The variable with this name is not set.
+    NotPresent(String),
+    /// The variable with this name does not contain valid Unicode.
+    NotUnicode(String),
+    /// The variable does not contain a valid duration.
+    Invalid {
+        /// The name of the variable.
+        name: String,
+        /// The value of the variable.
+        value: String,
+        /// The reason the value could not be parsed.
+        error: ParseDurationError,
+    },
+}
+
+#[cfg(feature = "std")]
+impl Error for EnvDurationError {
+    fn description(&self) -> &str {
+        match *self {
+            EnvDurationError::NotPresent(_) => "The environment variable is not set.",
+            EnvDurationError::NotUnicode(_) => "The environment variable is not valid Unicode.",
+            EnvDurationError::Invalid { ref error, .. } => error.message(),
+        }
+    }
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EnvDurationError::Invalid { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for EnvDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EnvDurationError::NotPresent(ref name) => {
+                write!(f, "The environment variable `{}` is not set.", name)
+            }
+            EnvDurationError::NotUnicode(ref name) => {
+                write!(f, "The environment variable `{}` is not valid Unicode.", name)
+            }
+            EnvDurationError::Invalid { ref name, ref value, ref error } => {
+                write!(f, "The environment variable `{}` has an invalid duration {:?}: {}",
+                       name, value, error)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(&ParseDurationError::UnknownUnit,
+                      &[Token::UnitVariant {
+                            name: "ParseDurationError",
+                            variant: "UnknownUnit",
+                        }]);
+        assert_tokens(&ParseDurationError::Empty,
+                      &[Token::UnitVariant {
+                            name: "ParseDurationError",
+                            variant: "Empty",
+                        }]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Construction of durations from numbers with method syntax.
+use duration::FloatDuration;
+
+/// Create a `FloatDuration` from a number with method syntax, such as
+/// `3.5.seconds()` or `250.milliseconds()`.
+///
+/// The trait is implemented for `f32`, `f64` and the primitive integer types, and
+/// each method is equivalent to the `FloatDuration` constructor of the same name.
+///
+/// ```rust
+/// use float_duration::{DurationNumExt, FloatDuration};
+///
+/// assert_eq!(3.5.seconds(), FloatDuration::seconds(3.5));
+/// assert_eq!(16.minutes(), FloatDuration::minutes(16.0));
+/// assert_eq!(1.hours() + 250.milliseconds(), FloatDuration::seconds(3600.25));
+/// ```
+pub trait DurationNumExt {
+    /// Create a duration of this many nanoseconds.
+    fn nanoseconds(self) -> FloatDuration;
+    /// Create a duration of this many microseconds.
+    fn microseconds(self) -> FloatDuration;
+    /// Create a duration of this many milliseconds.
+    fn milliseconds(self) -> FloatDuration;
+    /// Create a duration of this many seconds.
+    fn seconds(self) -> FloatDuration;
+    /// Create a duration of this many minutes.
+    fn minutes(self) -> FloatDuration;
+    /// Create a duration of this many hours.
+    fn hours(self) -> FloatDuration;
+    /// Create a duration of this many days.
+    fn days(self) -> FloatDuration;
+    /// Create a duration of this many weeks.
+    fn weeks(self) -> FloatDuration;
+    /// Create a duration of this many months.
+    fn months(self) -> FloatDuration;
+    /// Create a duration of this many years.
+    fn years(self) -> FloatDuration;
+}
+
+macro_rules! impl_duration_num_ext {
+    ($($num:ty),*) => {
+        $(
+            impl DurationNumExt for $num {
+                #[inline]
+                fn nanoseconds(self) -> FloatDuration {
+                    FloatDuration::nanoseconds(self as f64)
+                }
+                #[inline]
+                fn microseconds(self) -> FloatDuration {
+                    FloatDuration::microseconds(self as f64)
+                }
+                #[inline]
+                fn milliseconds(self) -> FloatDuration {
+                    FloatDuration::milliseconds(self as f64)
+                }
+                #[inline]
+                fn seconds(self) -> FloatDuration {
+                    FloatDuration::seconds(self as f64)
+                }
+                #[inline]
+                fn minutes(self) -> FloatDuration {
+                    FloatDuration::minutes(self as f64)
+                }
+                #[inline]
+                fn hours(self) -> FloatDuration {
+                    FloatDuration::hours(self as f64)
+                }
+                #[inline]
+                fn days(self) -> FloatDuration {
+                    FloatDuration::days(self as f64)
+                }
+                #[inline]
+                fn weeks(self) -> FloatDuration {
+                    FloatDuration::weeks(self as f64)
+                }
+                #[inline]
+                fn months(self) -> FloatDuration {
+                    FloatDuration::months(self as f64)
+                }
+                #[inline]
+                fn years(self) -> FloatDuration {
+                    FloatDuration::years(self as f64)
+                }
+            }
+        )*
+    }
+}
+
+impl_duration_num_ext!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_ext() {
+        assert_eq!(2.5.minutes(), FloatDuration::seconds(150.0));
+        assert_eq!((-3).days(), FloatDuration::days(-3.0));
+        assert_eq!(1.5f32.hours(), FloatDuration::minutes(90.0));
+        assert_eq!(10u8.nanoseconds(), FloatDuration::nanoseconds(10.0));
+        assert_eq!(100usize.microseconds(), FloatDuration::microseconds(100.0));
+        assert_eq!(2i64.weeks(), FloatDuration::days(14.0));
+        assert_eq!(6u32.months(), FloatDuration::years(0.5));
+        assert_eq!(0.years(), FloatDuration::zero());
+    }
+}
@@ -0,0 +1,156 @@
+//! A C interface to `FloatDuration`.
+//!
+//! `FloatDuration` is `#[repr(transparent)]` over an `f64`, so it crosses the FFI
+//! boundary as a plain C `double`. The matching declarations are in
+//! `include/float_duration.h`:
+//!
+//! ```c
+//! #include "float_duration.h"
+//!
+//! float_duration_t timeout = float_duration_add(float_duration_from_seconds(1.0),
+//!                                               float_duration_from_milliseconds(500.0));
+//! char text[64];
+//! float_duration_format(timeout, text, sizeof text); /* "1.5 seconds" */
+//! ```
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+use duration::FloatDuration;
+
+macro_rules! ffi_units {
+    ($($from:ident, $to:ident, $unit:ident, $as:ident;)*) => {
+        $(
+            /// Create a duration from a number of units.
+            #[no_mangle]
+            pub extern "C" fn $from(value: f64) -> FloatDuration {
+                FloatDuration::$unit(value)
+            }
+            /// Return the duration as a number of units.
+            #[no_mangle]
+            pub extern "C" fn $to(duration: FloatDuration) -> f64 {
+                duration.$as()
+            }
+        )*
+    }
+}
+
+ffi_units! {
+    float_duration_from_nanoseconds, float_duration_as_nanoseconds, nanoseconds, as_nanoseconds;
+    float_duration_from_microseconds, float_duration_as_microseconds, microseconds, as_microseconds;
+    float_duration_from_milliseconds, float_duration_as_milliseconds, milliseconds, as_milliseconds;
+    float_duration_from_seconds, float_duration_as_seconds, seconds, as_seconds;
+    float_duration_from_minutes, float_duration_as_minutes, minutes, as_minutes;
+    float_duration_from_hours, float_duration_as_hours, hours, as_hours;
+    float_duration_from_days, float_duration_as_days, days, as_days;
+}
+
+/// Return the sum of two durations.
+#[no_mangle]
+pub extern "C" fn float_duration_add(a: FloatDuration, b: FloatDuration) -> FloatDuration {
+    a + b
+}
+/// Return the difference of two durations.
+#[no_mangle]
+pub extern "C" fn float_duration_sub(a: FloatDuration, b: FloatDuration) -> FloatDuration {
+    a - b
+}
+/// Return a duration multiplied by a factor.
+#[no_mangle]
+pub extern "C" fn float_duration_mul(duration: FloatDuration, factor: f64) -> FloatDuration {
+    duration * factor
+}
+/// Return a duration divided by a divisor.
+#[no_mangle]
+pub extern "C" fn float_duration_div(duration: FloatDuration, divisor: f64) -> FloatDuration {
+    duration / divisor
+}
+/// Return the absolute value of a duration.
+#[no_mangle]
+pub extern "C" fn float_duration_abs(duration: FloatDuration) -> FloatDuration {
+    duration.abs()
+}
+
+/// Write a duration in its `Display` form, such as `1.5 seconds`, to `buffer`.
+///
+/// Like `snprintf`, at most `len` bytes are written, the text is always
+/// NUL-terminated if `len` is not zero, and the length of the full text, without
+/// the terminator, is returned.
+///
+/// # Safety
+/// `buffer` must be valid for writes of `len` bytes, or be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn float_duration_format(duration: FloatDuration,
+                                               buffer: *mut c_char,
+                                               len: usize)
+                                               -> usize {
+    let text = duration.to_string();
+    if len > 0 && !buffer.is_null() {
+        let count = text.len().min(len - 1);
+        ptr::copy_nonoverlapping(text.as_ptr() as *const c_char, buffer, count);
+        *buffer.add(count) = 0;
+    }
+    text.len()
+}
+
+/// Parse a NUL-terminated duration string, such as `1h 30m`, into `out`.
+///
+/// Returns `false`, leaving `out` untouched, if `text` is null, is not UTF-8, or is
+/// not a valid duration.
+///
+/// # Safety
+/// `text` must be null or a valid NUL-terminated string, and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn float_duration_parse(text: *const c_char,
+                                              out: *mut FloatDuration)
+                                              -> bool {
+    if text.is_null() || out.is_null() {
+        return false;
+    }
+    match CStr::from_ptr(text).to_str().ok().and_then(|text| text.parse().ok()) {
+        Some(duration) => {
+            *out = duration;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_and_arithmetic() {
+        let duration = float_duration_add(float_duration_from_minutes(1.0),
+                                          float_duration_from_milliseconds(500.0));
+        assert_eq!(float_duration_as_seconds(duration), 60.5);
+        assert_eq!(float_duration_as_hours(float_duration_from_days(0.5)), 12.0);
+        assert_eq!(float_duration_abs(float_duration_sub(float_duration_from_seconds(1.0),
+                                                         float_duration_from_seconds(3.0))),
+                   FloatDuration::seconds(2.0));
+        assert_eq!(float_duration_div(float_duration_mul(duration, 4.0), 2.0),
+                   FloatDuration::seconds(121.0));
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        let mut buffer = [1 as c_char; 8];
+        let len = unsafe {
+            float_duration_format(FloatDuration::seconds(1.5), buffer.as_mut_ptr(), buffer.len())
+        };
+        assert_eq!(len, "1.5 seconds".len());
+        let text = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(text.to_str().unwrap(), "1.5 sec");
+        assert_eq!(unsafe { float_duration_format(FloatDuration::zero(), ptr::null_mut(), 0) },
+                   "0 seconds".len());
+
+        let mut out = FloatDuration::zero();
+        assert!(unsafe { float_duration_parse(b"1h 30m\0".as_ptr() as *const c_char, &mut out) });
+        assert_eq!(out, FloatDuration::minutes(90.0));
+        assert!(!unsafe { float_duration_parse(b"soon\0".as_ptr() as *const c_char, &mut out) });
+        assert!(!unsafe { float_duration_parse(ptr::null(), &mut out) });
+        assert_eq!(out, FloatDuration::minutes(90.0));
+    }
+}
@@ -0,0 +1,649 @@
+//! Alternative ways of formatting durations.
+//!
+//! Each formatting method on `FloatDuration` returns a small adapter type that
+//! implements `Display`, so it can be used directly in `format!` and friends
+//! without allocating.
+use core::fmt;
+
+use num_traits::Float;
+
+use duration::{float, FloatDuration, GenericFloatDuration};
+use unit::TimeUnit;
+
+/// A `Display` adapter that shows a duration in a fixed unit.
+///
+/// This type is returned by `FloatDuration::display_in`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayIn<F> {
+    duration: GenericFloatDuration<F>,
+    unit: TimeUnit,
+}
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Return an object that displays the duration in `unit`, rather than in the
+    /// unit chosen automatically by `Display`.
+    ///
+    /// As with `Display`, formatting flags apply to the number.
+    ///
+    /// ```rust
+    /// use float_duration::{FloatDuration, TimeUnit};
+    ///
+    /// let d = FloatDuration::seconds(1.0001);
+    /// assert_eq!(format!("{}", d.display_in(TimeUnit::Millisecond)), "1000.1 milliseconds");
+    /// assert_eq!(format!("{:.2}", d.display_in(TimeUnit::Second)), "1.00 seconds");
+    /// ```
+    pub fn display_in(self, unit: TimeUnit) -> DisplayIn<F> {
+        DisplayIn {
+            duration: self,
+            unit,
+        }
+    }
+}
+
+impl<F: Float + fmt::Display> fmt::Display for DisplayIn<F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.duration.in_unit(self.unit), fmt)?;
+        write!(fmt, " {}", self.unit.name())
+    }
+}
+
+/// A `Display` adapter that shows a duration split over several units, such as
+/// `2d 3h 5m 1.25s`.
+///
+/// This type is returned by `FloatDuration::format_compound`.
+#[derive(Debug, Clone, Copy)]
+pub struct Compound {
+    duration: FloatDuration,
+    smallest_unit: TimeUnit,
+}
+
+impl Compound {
+    /// Stop at `unit`, showing the rest of the duration as a fraction of it.
+    ///
+    /// The default is `TimeUnit::Second`. Units longer than a day are treated as
+    /// days.
+    pub fn smallest_unit(mut self, unit: TimeUnit) -> Compound {
+        self.smallest_unit = unit.min(TimeUnit::Day);
+        self
+    }
+}
+
+impl FloatDuration {
+    /// Return an object that displays the duration split over days, hours,
+    /// minutes, and seconds, such as `2d 3h 5m 1.25s`.
+    ///
+    /// Components that are zero are left out. The last component holds the rest
+    /// of the duration as a fraction, and the precision flag of the formatter
+    /// applies to it. Without a precision, the last component is the shortest
+    /// number that makes the output parse back into exactly the same duration.
+    /// Non-finite durations are shown as by `Display`.
+    ///
+    /// Durations of `2^53` seconds (about 285 million years) or more are not
+    /// split, and are shown in the smallest unit alone.
+    ///
+    /// ```rust
+    /// use float_duration::{FloatDuration, TimeUnit};
+    ///
+    /// let d = FloatDuration::days(2.0) + FloatDuration::hours(3.0) +
+    ///         FloatDuration::minutes(5.0) + FloatDuration::seconds(1.25);
+    /// assert_eq!(format!("{}", d.format_compound()), "2d 3h 5m 1.25s");
+    /// assert_eq!(format!("{}", d.format_compound().smallest_unit(TimeUnit::Hour)),
+    ///            "2d 3.083680555555556h");
+    /// assert_eq!(format!("{:.1}", d.format_compound().smallest_unit(TimeUnit::Hour)),
+    ///            "2d 3.1h");
+    /// ```
+    pub fn format_compound(self) -> Compound {
+        Compound {
+            duration: self,
+            smallest_unit: TimeUnit::Second,
+        }
+    }
+}
+
+/// The short symbol of each of the units used by compound formats.
+fn symbol(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Nanosecond => "ns",
+        TimeUnit::Microsecond => "µs",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Second => "s",
+        TimeUnit::Minute => "m",
+        TimeUnit::Hour => "h",
+        _ => "d",
+    }
+}
+
+/// The units of compound formats, from the largest.
+const COMPOUND_UNITS: [TimeUnit; 7] = [TimeUnit::Day,
+                                       TimeUnit::Hour,
+                                       TimeUnit::Minute,
+                                       TimeUnit::Second,
+                                       TimeUnit::Millisecond,
+                                       TimeUnit::Microsecond,
+                                       TimeUnit::Nanosecond];
+
+/// The magnitude, in seconds, from which compound formats stop splitting a
+/// duration into components, `2^53`.
+///
+/// Below it, every whole number of seconds is exact, so taking the larger
+/// components away from the duration loses nothing.
+const MAX_SPLIT_SECS: f64 = 9_007_199_254_740_992.0;
+
+/// Split `magnitude` seconds into whole numbers of the units larger than
+/// `smallest`, and the rest in `smallest`.
+fn split(magnitude: f64, smallest: TimeUnit) -> ([f64; 7], f64) {
+    let mut values = [0.0; 7];
+    if magnitude < MAX_SPLIT_SECS {
+        let mut rest = FloatDuration::seconds(magnitude);
+        for (value, &unit) in values.iter_mut().zip(COMPOUND_UNITS.iter()) {
+            if unit <= smallest {
+                break;
+            }
+            let mut whole = Float::floor(rest.in_unit(unit));
+            if FloatDuration::from_unit(whole, unit) > rest {
+                whole -= 1.0;
+            }
+            *value = whole;
+            rest -= FloatDuration::from_unit(whole, unit);
+        }
+    }
+    let rest = FloatDuration::seconds(magnitude - sum(&values, 0.0, smallest));
+    (values, rest.in_unit(smallest))
+}
+
+/// Add up compound components the same way as `FromStr` does.
+fn sum(values: &[f64; 7], rest: f64, smallest: TimeUnit) -> f64 {
+    let mut total = FloatDuration::zero();
+    for (&value, &unit) in values.iter().zip(COMPOUND_UNITS.iter()) {
+        if value != 0.0 {
+            total += FloatDuration::from_unit(value, unit);
+        }
+    }
+    (total + FloatDuration::from_unit(rest, smallest)).as_seconds()
+}
+
+/// Return the number of `smallest` with the fewest significant digits that,
+/// shown after `values`, adds up to exactly `magnitude` seconds.
+///
+/// Like `in_unit_exact`, the few floats around `rest` are tried last, and `None`
+/// is returned if none of them adds up exactly.
+fn exact_rest(values: &[f64; 7], rest: f64, smallest: TimeUnit, magnitude: f64) -> Option<f64> {
+    let is_exact = |candidate: f64| {
+        candidate >= 0.0 && sum(values, candidate, smallest) == magnitude
+    };
+    for precision in 0..17 {
+        let mut buffer = NumberBuffer::new();
+        if fmt::Write::write_fmt(&mut buffer, format_args!("{:.*e}", precision, rest)).is_ok() {
+            if let Some(candidate) = buffer.parse() {
+                if is_exact(candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+    if !rest.is_finite() {
+        return None;
+    }
+    [1i64, -1, 2, -2, 3, -3].iter()
+        .map(|&step| f64::from_bits((rest.to_bits() as i64 + step) as u64))
+        .find(|&candidate| is_exact(candidate))
+}
+
+/// A fixed-size buffer to format a float in scientific notation without
+/// allocating.
+struct NumberBuffer {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl NumberBuffer {
+    fn new() -> NumberBuffer {
+        NumberBuffer {
+            bytes: [0; 32],
+            len: 0,
+        }
+    }
+    fn parse(&self) -> Option<f64> {
+        ::core::str::from_utf8(&self.bytes[..self.len]).ok()?.parse().ok()
+    }
+}
+
+impl fmt::Write for NumberBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Compound {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.duration.as_seconds();
+        if !secs.is_finite() {
+            return fmt::Display::fmt(&self.duration, fmt);
+        }
+        let magnitude = Float::abs(secs);
+        let smallest = self.smallest_unit;
+        let (mut values, mut last) = split(magnitude, smallest);
+        if fmt.precision().is_none() {
+            match exact_rest(&values, last, smallest, magnitude) {
+                Some(exact) => last = exact,
+                None => {
+                    // Fall back to the whole duration in the smallest unit.
+                    values = [0.0; 7];
+                    last = FloatDuration::seconds(magnitude).in_unit(smallest);
+                    last = exact_rest(&values, last, smallest, magnitude).unwrap_or(last);
+                }
+            }
+        }
+
+        if secs < 0.0 {
+            write!(fmt, "-")?;
+        }
+        let mut separator = "";
+        for (&value, &unit) in values.iter().zip(COMPOUND_UNITS.iter()) {
+            if value != 0.0 {
+                write!(fmt, "{}{}{}", separator, value, symbol(unit))?;
+                separator = " ";
+            }
+        }
+        if last != 0.0 || separator.is_empty() {
+            write!(fmt, "{}", separator)?;
+            match fmt.precision() {
+                Some(decimals) => write!(fmt, "{:.*}", decimals, last)?,
+                None => write!(fmt, "{}", last)?,
+            }
+            write!(fmt, "{}", symbol(smallest))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Display` adapter that shows a duration like a clock, such as
+/// `01:30:15.500`.
+///
+/// This type is returned by `FloatDuration::format_clock`.
+#[derive(Debug, Clone, Copy)]
+pub struct Clock {
+    duration: FloatDuration,
+    days: bool,
+    sign: bool,
+}
+
+impl Clock {
+    /// Split off whole days as a leading field, as in `1:02:00:00.000`.
+    ///
+    /// The days field is only shown when it is not zero. Without it, the hours
+    /// field counts past 24.
+    pub fn with_days(mut self) -> Clock {
+        self.days = true;
+        self
+    }
+    /// Show a `+` before non-negative durations as well as a `-` before negative
+    /// ones.
+    pub fn with_sign(mut self) -> Clock {
+        self.sign = true;
+        self
+    }
+}
+
+impl FloatDuration {
+    /// Return an object that displays the duration as hours, minutes, and seconds
+    /// separated by colons, such as `01:30:15.500`.
+    ///
+    /// The seconds are shown with three decimal places by default. The precision
+    /// flag of the formatter changes the number of decimal places, up to nine, and
+    /// the duration is rounded to that precision before being split into fields.
+    /// Negative durations are prefixed with `-`, and non-finite durations are shown
+    /// as by `Display`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let d = FloatDuration::from_hms(1.0, 30.0, 15.5);
+    /// assert_eq!(format!("{}", d.format_clock()), "01:30:15.500");
+    /// assert_eq!(format!("{:.0}", (-d).format_clock()), "-01:30:16");
+    /// assert_eq!(format!("{}", FloatDuration::hours(26.0).format_clock().with_days()),
+    ///            "1:02:00:00.000");
+    /// ```
+    pub fn format_clock(self) -> Clock {
+        Clock {
+            duration: self,
+            days: false,
+            sign: false,
+        }
+    }
+}
+
+impl fmt::Display for Clock {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let digits = fmt.precision().unwrap_or(3).min(9);
+        let scale = 10u128.pow(digits as u32);
+        let secs = self.duration.as_seconds();
+        let scaled = Float::round(Float::abs(secs) * scale as f64);
+        if !secs.is_finite() || scaled >= u128::MAX as f64 {
+            return fmt::Display::fmt(&self.duration, fmt);
+        }
+
+        let ticks = scaled as u128;
+        if secs < 0.0 && ticks != 0 {
+            write!(fmt, "-")?;
+        } else if self.sign {
+            write!(fmt, "+")?;
+        }
+        let whole = ticks / scale;
+        let mut hours = whole / 3600;
+        if self.days && hours >= 24 {
+            write!(fmt, "{}:", hours / 24)?;
+            hours %= 24;
+        }
+        write!(fmt, "{:02}:{:02}:{:02}", hours, whole / 60 % 60, whole % 60)?;
+        if digits > 0 {
+            write!(fmt, ".{:0width$}", ticks % scale, width = digits)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Display` adapter that phrases a signed duration relative to the present,
+/// such as `in 3 hours` or `5 minutes ago`.
+///
+/// This type is returned by `FloatDuration::format_relative`.
+#[derive(Debug, Clone, Copy)]
+pub struct Relative {
+    duration: FloatDuration,
+    now_threshold: FloatDuration,
+    largest_unit: TimeUnit,
+}
+
+impl Relative {
+    /// Show durations shorter than `threshold` as `just now`.
+    ///
+    /// The default is one second.
+    pub fn now_threshold(mut self, threshold: FloatDuration) -> Relative {
+        self.now_threshold = threshold.abs();
+        self
+    }
+    /// Never use a unit longer than `unit`, so that, for example, a duration of
+    /// two years can be shown as `in 730 days`.
+    ///
+    /// The default is `TimeUnit::Year`.
+    pub fn largest_unit(mut self, unit: TimeUnit) -> Relative {
+        self.largest_unit = unit;
+        self
+    }
+}
+
+impl FloatDuration {
+    /// Return an object that displays the duration as an offset from the present,
+    /// such as `in 3 hours`, `5 minutes ago` or `just now`.
+    ///
+    /// Positive durations are in the future and negative ones in the past. The
+    /// duration is shown as a whole number of the longest unit it contains at
+    /// least once, rounded to the nearest integer. Non-finite durations are shown
+    /// as by `Display`.
+    ///
+    /// ```rust
+    /// use float_duration::{FloatDuration, TimeUnit};
+    ///
+    /// assert_eq!(format!("{}", FloatDuration::hours(2.7).format_relative()), "in 3 hours");
+    /// assert_eq!(format!("{}", FloatDuration::minutes(-5.0).format_relative()), "5 minutes ago");
+    /// assert_eq!(format!("{}", FloatDuration::milliseconds(300.0).format_relative()),
+    ///            "just now");
+    /// assert_eq!(format!("{}",
+    ///                    FloatDuration::seconds(-20.0)
+    ///                        .format_relative()
+    ///                        .now_threshold(FloatDuration::minutes(1.0))),
+    ///            "just now");
+    /// assert_eq!(format!("{}", FloatDuration::weeks(1.0).format_relative()
+    ///                              .largest_unit(TimeUnit::Day)),
+    ///            "in 7 days");
+    /// ```
+    pub fn format_relative(self) -> Relative {
+        Relative {
+            duration: self,
+            now_threshold: FloatDuration::seconds(1.0),
+            largest_unit: TimeUnit::Year,
+        }
+    }
+}
+
+impl fmt::Display for Relative {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.duration.as_seconds();
+        if !secs.is_finite() {
+            return fmt::Display::fmt(&self.duration, fmt);
+        }
+        let magnitude = Float::abs(secs);
+        if magnitude < self.now_threshold.as_seconds() || magnitude == 0.0 {
+            return write!(fmt, "just now");
+        }
+
+        let units = [TimeUnit::Year,
+                     TimeUnit::Month,
+                     TimeUnit::Week,
+                     TimeUnit::Day,
+                     TimeUnit::Hour,
+                     TimeUnit::Minute,
+                     TimeUnit::Second,
+                     TimeUnit::Millisecond,
+                     TimeUnit::Microsecond,
+                     TimeUnit::Nanosecond];
+        let unit = units.iter()
+            .cloned()
+            .filter(|&unit| unit <= self.largest_unit)
+            .find(|&unit| magnitude >= unit.as_seconds())
+            .unwrap_or(TimeUnit::Nanosecond);
+        let count = Float::round(self.duration.abs().in_unit(unit));
+
+        let name = unit.name();
+        let name = if count == 1.0 { &name[..name.len() - 1] } else { name };
+        if secs > 0.0 {
+            write!(fmt, "in {} {}", count, name)
+        } else {
+            write!(fmt, "{} {} ago", count, name)
+        }
+    }
+}
+
+/// A `Display` adapter that shows a duration in seconds with an SI prefix, such
+/// as `12.5 ms` or `1.5 ks`.
+///
+/// This type is returned by `FloatDuration::format_engineering`.
+#[derive(Debug, Clone, Copy)]
+pub struct Engineering<F> {
+    duration: GenericFloatDuration<F>,
+}
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Return an object that displays the duration in engineering notation, with
+    /// the SI prefix that keeps the number in `[1, 1000)`.
+    ///
+    /// Prefixes from `y` (10⁻²⁴) to `Y` (10²⁴) are used. Formatting flags apply to
+    /// the number, and a number rounded up to 1000 by the precision flag moves to
+    /// the next prefix. Zero is shown as `0 s`, and non-finite durations are shown
+    /// as by `Display`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(format!("{}", FloatDuration::milliseconds(12.5).format_engineering()),
+    ///            "12.5 ms");
+    /// assert_eq!(format!("{:.1}", FloatDuration::nanoseconds(3210.0).format_engineering()),
+    ///            "3.2 µs");
+    /// assert_eq!(format!("{}", FloatDuration::seconds(-1500.0).format_engineering()),
+    ///            "-1.5 ks");
+    /// ```
+    pub fn format_engineering(self) -> Engineering<F> {
+        Engineering { duration: self }
+    }
+}
+
+/// The SI prefixes from 10⁻²⁴ to 10²⁴, in steps of 10³.
+const SI_PREFIXES: [&str; 17] = ["y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T",
+                                 "P", "E", "Z", "Y"];
+
+impl<F: Float + fmt::Display + fmt::LowerExp> fmt::Display for Engineering<F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.duration.as_seconds();
+        if !secs.is_finite() {
+            return fmt::Display::fmt(&self.duration, fmt);
+        }
+        if secs == F::zero() {
+            fmt::Display::fmt(&F::zero(), fmt)?;
+            return write!(fmt, " s");
+        }
+
+        let thousand: F = float(1000.0);
+        let max_index = SI_PREFIXES.len() as i32 - 1;
+        let mut index = Float::floor(Float::log10(Float::abs(secs)) / float(3.0))
+            .to_i32()
+            .unwrap_or(0)
+            .saturating_add(8)
+            .max(0)
+            .min(max_index);
+        // Scale by an exact power of 1000 in either direction, so that values
+        // created from a prefixed unit display without rounding residue.
+        let mut mantissa = if index < 8 {
+            secs * Float::powi(thousand, 8 - index)
+        } else {
+            secs / Float::powi(thousand, index - 8)
+        };
+        if let Some(decimals) = fmt.precision() {
+            let scale = Float::powi(float::<F>(10.0), decimals.min(i32::MAX as usize) as i32);
+            let rounded = Float::round(Float::abs(mantissa) * scale) / scale;
+            if rounded >= thousand && index < max_index {
+                index += 1;
+                mantissa = mantissa / thousand;
+            }
+        }
+
+        fmt::Display::fmt(&mantissa, fmt)?;
+        write!(fmt, " {}s", SI_PREFIXES[index as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::{FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_display_in() {
+        let d = FloatDuration::milliseconds(999.0);
+        assert_eq!(format!("{}", d.display_in(TimeUnit::Millisecond)), "999 milliseconds");
+        assert_eq!(format!("{:>6}", d.display_in(TimeUnit::Millisecond)), "   999 milliseconds");
+        assert_eq!(format!("{}", FloatDuration::days(-3.5).display_in(TimeUnit::Week)),
+                   "-0.5 weeks");
+        assert_eq!(format!("{}", FloatDuration32::zero().display_in(TimeUnit::Hour)), "0 hours");
+    }
+
+    #[test]
+    fn test_compound() {
+        let d = FloatDuration::hours(1.0) + FloatDuration::seconds(15.5);
+        assert_eq!(format!("{}", d.format_compound()), "1h 15.5s");
+        assert_eq!(format!("{}", (-d).format_compound()), "-1h 15.5s");
+        assert_eq!(format!("{}", FloatDuration::minutes(3.0).format_compound()), "3m");
+        assert_eq!(format!("{}", FloatDuration::zero().format_compound()), "0s");
+        assert_eq!(format!("{}", (-FloatDuration::zero()).format_compound()), "0s");
+        assert_eq!(format!("{}", FloatDuration::seconds(1.123).format_compound()), "1.123s");
+        assert_eq!(format!("{:.2}", FloatDuration::seconds(0.001).format_compound()), "0.00s");
+
+        let precise = FloatDuration::seconds(61.0012345);
+        assert_eq!(format!("{:.1}", precise.format_compound().smallest_unit(TimeUnit::Microsecond)),
+                   "1m 1s 1ms 234.5µs");
+        assert_eq!(format!("{:.4}", precise.format_compound().smallest_unit(TimeUnit::Minute)),
+                   "1.0167m");
+        assert_eq!(format!("{}",
+                           FloatDuration::weeks(1.5).format_compound().smallest_unit(TimeUnit::Year)),
+                   "10.5d");
+        assert_eq!(format!("{}", (FloatDuration::zero() / 0.0).format_compound()), "NaN seconds");
+    }
+
+    #[test]
+    fn test_compound_shortest() {
+        let compound = |secs: f64| format!("{}", FloatDuration::seconds(secs).format_compound());
+        assert_eq!(compound(61.7), "1m 1.7s");
+        assert_eq!(compound(0.9999999), "0.9999999s");
+        assert_eq!(compound(12.3456789), "12.3456789s");
+        assert_eq!(compound(-3723.1), "-1h 2m 3.1s");
+
+        let millis = |secs: f64| {
+            format!("{}",
+                    FloatDuration::seconds(secs).format_compound().smallest_unit(TimeUnit::Millisecond))
+        };
+        assert_eq!(millis(1.1), "1s 100ms");
+        assert_eq!(millis(2.675), "2s 675ms");
+        assert_eq!(millis(12.3456789), "12s 345.6789ms");
+    }
+
+    #[test]
+    fn test_clock() {
+        let d = FloatDuration::seconds(59.9996);
+        assert_eq!(format!("{}", d.format_clock()), "00:01:00.000");
+        assert_eq!(format!("{:.4}", d.format_clock()), "00:00:59.9996");
+        assert_eq!(format!("{}", FloatDuration::hours(49.5).format_clock()), "49:30:00.000");
+        assert_eq!(format!("{:.0}", FloatDuration::hours(49.5).format_clock().with_days()),
+                   "2:01:30:00");
+        assert_eq!(format!("{:.0}", FloatDuration::hours(5.0).format_clock().with_days()),
+                   "05:00:00");
+        assert_eq!(format!("{:.1}", FloatDuration::seconds(-0.01).format_clock()), "00:00:00.0");
+        assert_eq!(format!("{:.1}", FloatDuration::seconds(-0.1).format_clock().with_sign()),
+                   "-00:00:00.1");
+        assert_eq!(format!("{:.2}", FloatDuration::zero().format_clock().with_sign()),
+                   "+00:00:00.00");
+        assert_eq!(format!("{:.12}", FloatDuration::seconds(1.5).format_clock()),
+                   "00:00:01.500000000");
+        assert_eq!(format!("{}", (FloatDuration::seconds(1.0) / 0.0).format_clock()),
+                   "inf seconds");
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(format!("{}", FloatDuration::hours(1.0).format_relative()), "in 1 hour");
+        assert_eq!(format!("{}", FloatDuration::days(-1.4).format_relative()), "1 day ago");
+        assert_eq!(format!("{}", FloatDuration::days(45.0).format_relative()), "in 1 month");
+        assert_eq!(format!("{}", FloatDuration::years(-2.5).format_relative()), "3 years ago");
+        assert_eq!(format!("{}", FloatDuration::seconds(59.0).format_relative()),
+                   "in 59 seconds");
+        assert_eq!(format!("{}", FloatDuration::zero().format_relative()), "just now");
+        assert_eq!(format!("{}", FloatDuration::seconds(-0.999).format_relative()), "just now");
+
+        let zero = FloatDuration::zero();
+        assert_eq!(format!("{}", FloatDuration::milliseconds(250.0).format_relative()
+                                     .now_threshold(zero)),
+                   "in 250 milliseconds");
+        assert_eq!(format!("{}", zero.format_relative().now_threshold(zero)), "just now");
+        assert_eq!(format!("{}", FloatDuration::days(-3.0).format_relative()
+                                     .largest_unit(TimeUnit::Hour)),
+                   "72 hours ago");
+        assert_eq!(format!("{}", (FloatDuration::seconds(-1.0) / 0.0).format_relative()),
+                   "-inf seconds");
+    }
+
+    #[test]
+    fn test_engineering() {
+        assert_eq!(format!("{}", FloatDuration::seconds(1.0).format_engineering()), "1 s");
+        assert_eq!(format!("{}", FloatDuration::seconds(999.0).format_engineering()), "999 s");
+        assert_eq!(format!("{}", FloatDuration::hours(1.0).format_engineering()), "3.6 ks");
+        assert_eq!(format!("{}", FloatDuration::microseconds(-250.0).format_engineering()),
+                   "-250 µs");
+        assert_eq!(format!("{:.2}", FloatDuration::years(1.0).format_engineering()),
+                   "31.54 Ms");
+        assert_eq!(format!("{:.1}", FloatDuration::seconds(0.99996).format_engineering()),
+                   "1.0 s");
+        assert_eq!(format!("{:.2}", FloatDuration32::nanoseconds(999.999).format_engineering()),
+                   "1.00 µs");
+        assert_eq!(format!("{:>5}", FloatDuration::zero().format_engineering()), "    0 s");
+        assert_eq!(format!("{}", FloatDuration::seconds(1.0e-27).format_engineering()),
+                   "0.001 ys");
+        assert_eq!(format!("{}", FloatDuration::seconds(1.0e30).format_engineering()),
+                   "1000000 Ys");
+        assert_eq!(format!("{}", (FloatDuration::zero() / 0.0).format_engineering()),
+                   "NaN seconds");
+    }
+}
@@ -0,0 +1,247 @@
+//! Frame rate measurement and pacing for game and rendering loops.
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use duration::FloatDuration;
+use frequency::Frequency;
+use timing::precise_sleep;
+
+/// A counter of frames per second over the last few frames.
+///
+/// Each frame's time is pushed to the counter, which keeps the most recent ones,
+/// up to the size of its window, to report averages that do not jitter from one
+/// frame to the next.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::frame::FpsCounter;
+///
+/// let mut fps = FpsCounter::new(4);
+/// for &ms in &[10.0, 20.0, 10.0, 20.0, 20.0] {
+///     fps.push(FloatDuration::milliseconds(ms));
+/// }
+/// assert_eq!(fps.instant_fps(), Some(50.0));
+/// assert_eq!(fps.average_frame_time(), Some(FloatDuration::milliseconds(17.5)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FpsCounter {
+    window: usize,
+    frames: VecDeque<FloatDuration>,
+}
+
+impl FpsCounter {
+    /// Create a new `FpsCounter` averaging over the last `window` frames.
+    ///
+    /// # Panics
+    /// This function panics if `window` is zero.
+    pub fn new(window: usize) -> FpsCounter {
+        assert!(window > 0, "the frame window must not be empty");
+        FpsCounter {
+            window,
+            frames: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// The maximum number of frames averaged over.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+    /// The number of frames currently averaged over.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    /// Whether no frame has been pushed since the counter was created or cleared.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Record the time taken by a frame, dropping the oldest frame if the window
+    /// is full.
+    pub fn push(&mut self, frame_time: FloatDuration) {
+        if self.frames.len() == self.window {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame_time);
+    }
+    /// Forget all recorded frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// The time taken by the last frame.
+    pub fn last_frame_time(&self) -> Option<FloatDuration> {
+        self.frames.back().cloned()
+    }
+    /// The frames per second implied by the last frame alone.
+    pub fn instant_fps(&self) -> Option<f64> {
+        self.last_frame_time().map(|frame_time| 1.0 / frame_time.as_seconds())
+    }
+    /// The mean time taken by the frames in the window.
+    pub fn average_frame_time(&self) -> Option<FloatDuration> {
+        if self.frames.is_empty() {
+            None
+        } else {
+            Some(self.frames.iter().sum::<FloatDuration>() / self.frames.len() as f64)
+        }
+    }
+    /// The frames per second over the window: the number of frames divided by the
+    /// time they took.
+    pub fn average_fps(&self) -> Option<f64> {
+        self.average_frame_time().map(|frame_time| 1.0 / frame_time.as_seconds())
+    }
+    /// The longest frame in the window, which shows stutters that averages hide.
+    pub fn max_frame_time(&self) -> Option<FloatDuration> {
+        self.frames.iter().cloned().fold(None, |max, frame_time| match max {
+            Some(max) if max >= frame_time => Some(max),
+            _ => Some(frame_time),
+        })
+    }
+}
+
+/// A limiter holding a loop to a target frame period, by waiting out the rest of
+/// each frame.
+///
+/// Frames are scheduled on a steady timeline, so a frame finishing a little late
+/// is followed by a slightly shorter wait. If a frame takes longer than a whole
+/// period, the timeline starts over from the end of that frame instead of running
+/// the following frames back to back to catch up.
+///
+/// The waits use `precise_sleep`, which busy-waits for the last couple of
+/// milliseconds of each frame for accuracy. Unlike `precise_sleep` itself, they
+/// never block forever, as the period must be finite.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, Frequency};
+/// use float_duration::frame::FrameLimiter;
+///
+/// let mut limiter = FrameLimiter::from_rate(Frequency::hertz(500.0));
+/// for _ in 0..5 {
+///     // Update and render...
+///     let dt = limiter.wait();
+///     assert!(dt > FloatDuration::zero());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameLimiter {
+    period: FloatDuration,
+    last_frame: Instant,
+    deadline: Instant,
+}
+
+impl FrameLimiter {
+    /// Create a new `FrameLimiter` with frames lasting `period`, the first one
+    /// starting now.
+    ///
+    /// # Panics
+    /// This function panics if `period` is not a positive duration that can be
+    /// represented by `std::time::Duration`, or if the end of the first frame
+    /// cannot be represented by `Instant`.
+    pub fn new(period: FloatDuration) -> FrameLimiter {
+        assert!(period > FloatDuration::zero() && period.to_std().is_ok(),
+                "the frame period must be positive and finite");
+        let now = Instant::now();
+        FrameLimiter {
+            period,
+            last_frame: now,
+            deadline: now + period,
+        }
+    }
+    /// Create a new `FrameLimiter` running at most `rate` frames per second.
+    ///
+    /// # Panics
+    /// This function panics if `rate` is not positive and finite.
+    pub fn from_rate(rate: Frequency) -> FrameLimiter {
+        FrameLimiter::new(rate.period())
+    }
+
+    /// The target time of a frame.
+    pub fn period(&self) -> FloatDuration {
+        self.period
+    }
+
+    /// Wait until the end of the current frame, and return the time elapsed since
+    /// the end of the previous one.
+    ///
+    /// The returned time is the actual frame time, to be used as the delta time of
+    /// the next update.
+    pub fn wait(&mut self) -> FloatDuration {
+        let now = Instant::now();
+        if now < self.deadline {
+            precise_sleep(FloatDuration::from_std(self.deadline - now));
+        }
+
+        let now = Instant::now();
+        let frame_time = FloatDuration::from_std(now - self.last_frame);
+        self.last_frame = now;
+        self.deadline = if now > self.deadline + self.period {
+            now + self.period
+        } else {
+            self.deadline + self.period
+        };
+        frame_time
+    }
+    /// Start a new frame now, such as after the loop was paused.
+    pub fn reset(&mut self) {
+        let now = Instant::now();
+        self.last_frame = now;
+        self.deadline = now + self.period;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fps_counter() {
+        let mut fps = FpsCounter::new(3);
+        assert!(fps.is_empty());
+        assert_eq!(fps.instant_fps(), None);
+        assert_eq!(fps.average_fps(), None);
+        assert_eq!(fps.max_frame_time(), None);
+
+        fps.push(FloatDuration::milliseconds(100.0));
+        fps.push(FloatDuration::milliseconds(250.0));
+        assert_eq!(fps.instant_fps(), Some(4.0));
+        assert_eq!(fps.average_frame_time(), Some(FloatDuration::milliseconds(175.0)));
+
+        fps.push(FloatDuration::milliseconds(100.0));
+        fps.push(FloatDuration::milliseconds(100.0));
+        assert_eq!(fps.len(), 3);
+        assert_eq!(fps.average_fps(), Some(1.0 / 0.15));
+        assert_eq!(fps.max_frame_time(), Some(FloatDuration::milliseconds(250.0)));
+        assert_eq!(fps.last_frame_time(), Some(FloatDuration::milliseconds(100.0)));
+
+        fps.clear();
+        assert_eq!(fps.average_frame_time(), None);
+    }
+
+    #[test]
+    fn test_frame_limiter() {
+        use std::thread;
+        use std::time::Duration;
+
+        let period = FloatDuration::milliseconds(5.0);
+        let start = Instant::now();
+        let mut limiter = FrameLimiter::new(period);
+        let total: FloatDuration = (0..4).map(|_| limiter.wait()).sum();
+        let elapsed = FloatDuration::from_std(start.elapsed());
+        assert!(total >= period * 4.0);
+        assert!(total <= elapsed);
+
+        // A slow frame is not followed by a burst of short ones.
+        thread::sleep(Duration::from_millis(12));
+        assert!(limiter.wait() >= FloatDuration::milliseconds(12.0));
+        assert!(limiter.wait() >= period * 0.5);
+
+        limiter.reset();
+        assert!(limiter.wait() >= period);
+        assert_eq!(FrameLimiter::from_rate(Frequency::hertz(200.0)).period(), period);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_window_panic() {
+        FpsCounter::new(0);
+    }
+}
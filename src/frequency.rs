@@ -0,0 +1,265 @@
+//! Frequencies, the reciprocal of durations.
+use core::fmt;
+use core::ops;
+
+use duration::FloatDuration;
+
+/// A frequency, or rate of events per unit of time, stored in hertz.
+///
+/// `Frequency` is the reciprocal of `FloatDuration`: the period of a frequency
+/// is a duration, and multiplying a frequency by a duration gives the number of
+/// cycles in that span of time.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, Frequency};
+///
+/// let tick_rate = Frequency::hertz(60.0);
+/// assert_eq!(tick_rate.period(), FloatDuration::seconds(1.0 / 60.0));
+/// assert_eq!(FloatDuration::milliseconds(250.0).recip(), Frequency::hertz(4.0));
+/// assert_eq!(tick_rate * FloatDuration::minutes(1.0), 3600.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Frequency {
+    hertz: f64,
+}
+
+impl Frequency {
+    /// Create a new `Frequency` from a number of cycles per second.
+    #[inline]
+    pub const fn hertz(hertz: f64) -> Frequency {
+        Frequency { hertz }
+    }
+    /// Create a new `Frequency` from a number of thousands of cycles per second.
+    #[inline]
+    pub const fn kilohertz(kilohertz: f64) -> Frequency {
+        Frequency { hertz: kilohertz * 1.0e3 }
+    }
+    /// Create a new `Frequency` from a number of millions of cycles per second.
+    #[inline]
+    pub const fn megahertz(megahertz: f64) -> Frequency {
+        Frequency { hertz: megahertz * 1.0e6 }
+    }
+    /// Create a new `Frequency` from a number of cycles per minute.
+    #[inline]
+    pub const fn per_minute(per_minute: f64) -> Frequency {
+        Frequency { hertz: per_minute / 60.0 }
+    }
+
+    /// Return the number of cycles per second.
+    #[inline]
+    pub fn as_hertz(&self) -> f64 {
+        self.hertz
+    }
+    /// Return the number of thousands of cycles per second.
+    #[inline]
+    pub fn as_kilohertz(&self) -> f64 {
+        self.hertz / 1.0e3
+    }
+    /// Return the number of millions of cycles per second.
+    #[inline]
+    pub fn as_megahertz(&self) -> f64 {
+        self.hertz / 1.0e6
+    }
+    /// Return the number of cycles per minute.
+    #[inline]
+    pub fn as_per_minute(&self) -> f64 {
+        self.hertz * 60.0
+    }
+
+    /// Return the duration of a single cycle.
+    ///
+    /// The period of a zero frequency is an infinite duration.
+    #[inline]
+    pub fn period(&self) -> FloatDuration {
+        FloatDuration::seconds(1.0 / self.hertz)
+    }
+    /// Return the duration of a single cycle.
+    ///
+    /// This is an alias for `period`, mirroring `FloatDuration::recip`.
+    #[inline]
+    pub fn recip(&self) -> FloatDuration {
+        self.period()
+    }
+}
+
+impl FloatDuration {
+    /// Return the frequency whose period is this duration.
+    ///
+    /// The reciprocal of a zero duration is an infinite frequency.
+    #[inline]
+    pub fn recip(&self) -> Frequency {
+        Frequency::hertz(1.0 / self.as_seconds())
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match fmt.precision() {
+            Some(decimals) => write!(fmt, "{:.*} Hz", decimals, self.hertz),
+            None => write!(fmt, "{} Hz", self.hertz),
+        }
+    }
+}
+
+impl ops::Add<Frequency> for Frequency {
+    type Output = Frequency;
+
+    #[inline]
+    fn add(self, rhs: Frequency) -> Frequency {
+        Frequency { hertz: self.hertz + rhs.hertz }
+    }
+}
+impl ops::Sub<Frequency> for Frequency {
+    type Output = Frequency;
+
+    #[inline]
+    fn sub(self, rhs: Frequency) -> Frequency {
+        Frequency { hertz: self.hertz - rhs.hertz }
+    }
+}
+impl ops::Mul<f64> for Frequency {
+    type Output = Frequency;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Frequency {
+        Frequency { hertz: self.hertz * rhs }
+    }
+}
+impl ops::Mul<Frequency> for f64 {
+    type Output = Frequency;
+
+    #[inline]
+    fn mul(self, rhs: Frequency) -> Frequency {
+        Frequency { hertz: self * rhs.hertz }
+    }
+}
+impl ops::Div<f64> for Frequency {
+    type Output = Frequency;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Frequency {
+        Frequency { hertz: self.hertz / rhs }
+    }
+}
+impl ops::Div<Frequency> for Frequency {
+    type Output = f64;
+
+    #[inline]
+    fn div(self, rhs: Frequency) -> f64 {
+        self.hertz / rhs.hertz
+    }
+}
+
+impl ops::Mul<FloatDuration> for Frequency {
+    type Output = f64;
+
+    /// The number of cycles that occur within `rhs`.
+    #[inline]
+    fn mul(self, rhs: FloatDuration) -> f64 {
+        self.hertz * rhs.as_seconds()
+    }
+}
+impl ops::Mul<Frequency> for FloatDuration {
+    type Output = f64;
+
+    /// The number of cycles of `rhs` that occur within `self`.
+    #[inline]
+    fn mul(self, rhs: Frequency) -> f64 {
+        self.as_seconds() * rhs.hertz
+    }
+}
+impl ops::Div<FloatDuration> for f64 {
+    type Output = Frequency;
+
+    /// The frequency of `self` events happening over `rhs`.
+    #[inline]
+    fn div(self, rhs: FloatDuration) -> Frequency {
+        Frequency { hertz: self / rhs.as_seconds() }
+    }
+}
+impl ops::Div<Frequency> for f64 {
+    type Output = FloatDuration;
+
+    /// The time it takes for `self` cycles of `rhs` to occur.
+    #[inline]
+    fn div(self, rhs: Frequency) -> FloatDuration {
+        FloatDuration::seconds(self / rhs.hertz)
+    }
+}
+
+impl ops::AddAssign<Frequency> for Frequency {
+    #[inline]
+    fn add_assign(&mut self, rhs: Frequency) {
+        self.hertz += rhs.hertz;
+    }
+}
+impl ops::SubAssign<Frequency> for Frequency {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Frequency) {
+        self.hertz -= rhs.hertz;
+    }
+}
+impl ops::MulAssign<f64> for Frequency {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.hertz *= rhs;
+    }
+}
+impl ops::DivAssign<f64> for Frequency {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.hertz /= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construct() {
+        assert_eq!(Frequency::kilohertz(1.5), Frequency::hertz(1500.0));
+        assert_eq!(Frequency::megahertz(2.0).as_kilohertz(), 2000.0);
+        assert_eq!(Frequency::per_minute(120.0), Frequency::hertz(2.0));
+        assert_eq!(Frequency::hertz(0.5).as_per_minute(), 30.0);
+        assert_eq!(Frequency::hertz(44100.0).as_megahertz(), 0.0441);
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(Frequency::hertz(4.0).period(), FloatDuration::milliseconds(250.0));
+        assert_eq!(FloatDuration::seconds(0.5).recip(), Frequency::hertz(2.0));
+        assert_eq!(FloatDuration::minutes(1.0).recip().recip(), FloatDuration::minutes(1.0));
+        assert!(Frequency::hertz(0.0).period().as_seconds().is_infinite());
+        assert!(FloatDuration::zero().recip().as_hertz().is_infinite());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let rate = Frequency::hertz(10.0);
+        assert_eq!(rate * FloatDuration::seconds(3.0), 30.0);
+        assert_eq!(FloatDuration::seconds(3.0) * rate, 30.0);
+        assert_eq!(30.0 / rate, FloatDuration::seconds(3.0));
+        assert_eq!(30.0 / FloatDuration::seconds(3.0), rate);
+
+        assert_eq!(rate + Frequency::hertz(5.0), Frequency::hertz(15.0));
+        assert_eq!(rate - Frequency::hertz(5.0), Frequency::hertz(5.0));
+        assert_eq!(rate * 2.0, Frequency::hertz(20.0));
+        assert_eq!(2.0 * rate, Frequency::hertz(20.0));
+        assert_eq!(rate / 2.0, Frequency::hertz(5.0));
+        assert_eq!(rate / Frequency::hertz(5.0), 2.0);
+
+        let mut f = rate;
+        f += Frequency::hertz(2.0);
+        f *= 2.0;
+        f -= Frequency::hertz(4.0);
+        f /= 4.0;
+        assert_eq!(f, Frequency::hertz(5.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Frequency::hertz(60.0)), "60 Hz");
+        assert_eq!(format!("{:.1}", Frequency::hertz(2.25)), "2.2 Hz");
+    }
+}
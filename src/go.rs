@@ -0,0 +1,182 @@
+//! Go-style duration formatting and parsing, as used by Go, Prometheus, and
+//! Kubernetes.
+use duration::FloatDuration;
+use error::ParseDurationError;
+use unit::TimeUnit;
+
+impl FloatDuration {
+    /// Format the duration the way Go's `time.Duration` does, such as `"1h30m15.5s"`.
+    ///
+    /// The duration is rounded to the nearest nanosecond. Durations of a second or
+    /// more are written as hours, minutes, and seconds, leaving out leading zero
+    /// components, while shorter durations use a single `ms`, `µs`, or `ns` unit. A
+    /// zero duration is written as `"0s"`.
+    ///
+    /// Non-finite durations and durations too large for an `i128` number of
+    /// nanoseconds have no Go representation; their output is unspecified.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_hms(2.0, 45.0, 10.5).to_go_duration(), "2h45m10.5s");
+    /// assert_eq!(FloatDuration::hours(1.0).to_go_duration(), "1h0m0s");
+    /// assert_eq!(FloatDuration::milliseconds(-150.0).to_go_duration(), "-150ms");
+    /// ```
+    pub fn to_go_duration(&self) -> String {
+        let nanos = match self.as_nanos_i128() {
+            Ok(nanos) => nanos,
+            Err(_) => return format!("{}s", self.as_seconds()),
+        };
+        if nanos == 0 {
+            return String::from("0s");
+        }
+
+        let mut result = String::new();
+        if nanos < 0 {
+            result.push('-');
+        }
+        let nanos = nanos.unsigned_abs();
+        if nanos < 1_000 {
+            result.push_str(&format!("{}ns", nanos));
+        } else if nanos < 1_000_000 {
+            result.push_str(&format!("{}µs", decimal(nanos, 3)));
+        } else if nanos < 1_000_000_000 {
+            result.push_str(&format!("{}ms", decimal(nanos, 6)));
+        } else {
+            let secs = nanos / 1_000_000_000;
+            let hours = secs / 3600;
+            let minutes = secs / 60 % 60;
+            if hours > 0 {
+                result.push_str(&format!("{}h", hours));
+            }
+            if hours > 0 || minutes > 0 {
+                result.push_str(&format!("{}m", minutes));
+            }
+            result.push_str(&format!("{}s", decimal(nanos % 60_000_000_000, 9)));
+        }
+        result
+    }
+
+    /// Parse a Go-style duration string, such as `"1h30m"`, `"150ms"`, or
+    /// `"2h45m10.5s"`.
+    ///
+    /// The string is a sequence of decimal numbers, each with an optional fraction
+    /// and a unit suffix, with an optional leading `-` or `+` sign. The units are
+    /// `ns`, `us` (or `µs`), `ms`, `s`, `m`, and `h`. As in Go, a bare `"0"` is
+    /// also accepted.
+    ///
+    /// # Errors
+    /// Any deviation from the format results in a `ParseDurationError`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_go_duration("1h30m"), Ok(FloatDuration::minutes(90.0)));
+    /// assert_eq!(FloatDuration::from_go_duration("-1.5s"), Ok(FloatDuration::seconds(-1.5)));
+    /// ```
+    pub fn from_go_duration(s: &str) -> Result<FloatDuration, ParseDurationError> {
+        let s = s.trim();
+        let (negative, mut s) = if let Some(rest) = s.strip_prefix('-') {
+            (true, rest)
+        } else if let Some(rest) = s.strip_prefix('+') {
+            (false, rest)
+        } else {
+            (false, s)
+        };
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+        if s == "0" {
+            return Ok(FloatDuration::zero());
+        }
+
+        let mut total = FloatDuration::zero();
+        while !s.is_empty() {
+            let end = s.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(s.len());
+            let (number, rest) = s.split_at(end);
+            if !number.bytes().any(|b| b.is_ascii_digit()) {
+                return Err(ParseDurationError::InvalidNumber);
+            }
+            let value = number.parse::<f64>().map_err(|_| ParseDurationError::InvalidNumber)?;
+
+            let end = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+            let (unit, rest) = rest.split_at(end);
+            let unit = match unit {
+                "" => return Err(ParseDurationError::MissingUnit),
+                "ns" => TimeUnit::Nanosecond,
+                "us" | "µs" | "μs" => TimeUnit::Microsecond,
+                "ms" => TimeUnit::Millisecond,
+                "s" => TimeUnit::Second,
+                "m" => TimeUnit::Minute,
+                "h" => TimeUnit::Hour,
+                _ => return Err(ParseDurationError::UnknownUnit),
+            };
+            total += FloatDuration::from_unit(value, unit);
+            s = rest;
+        }
+
+        if negative { Ok(-total) } else { Ok(total) }
+    }
+}
+
+/// Format `value / 10^digits` as a decimal without trailing zeros.
+pub(crate) fn decimal(value: u128, digits: u32) -> String {
+    let scale = 10u128.pow(digits);
+    let fraction = value % scale;
+    if fraction == 0 {
+        format!("{}", value / scale)
+    } else {
+        let fraction = format!("{:0width$}", fraction, width = digits as usize);
+        format!("{}.{}", value / scale, fraction.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_go_duration() {
+        assert_eq!(FloatDuration::zero().to_go_duration(), "0s");
+        assert_eq!(FloatDuration::nanoseconds(100.0).to_go_duration(), "100ns");
+        assert_eq!(FloatDuration::microseconds(1.5).to_go_duration(), "1.5µs");
+        assert_eq!(FloatDuration::milliseconds(150.0).to_go_duration(), "150ms");
+        assert_eq!(FloatDuration::seconds(1.5).to_go_duration(), "1.5s");
+        assert_eq!(FloatDuration::minutes(90.0).to_go_duration(), "1h30m0s");
+        assert_eq!(FloatDuration::seconds(61.25).to_go_duration(), "1m1.25s");
+        assert_eq!(FloatDuration::days(3.0).to_go_duration(), "72h0m0s");
+        assert_eq!(FloatDuration::seconds(-0.0000000001).to_go_duration(), "0s");
+    }
+
+    #[test]
+    fn test_from_go_duration() {
+        assert_eq!(FloatDuration::from_go_duration("0"), Ok(FloatDuration::zero()));
+        assert_eq!(FloatDuration::from_go_duration("150ms"),
+                   Ok(FloatDuration::milliseconds(150.0)));
+        assert_eq!(FloatDuration::from_go_duration("2h45m10.5s"),
+                   Ok(FloatDuration::from_hms(2.0, 45.0, 10.5)));
+        assert_eq!(FloatDuration::from_go_duration("1.5µs"),
+                   Ok(FloatDuration::microseconds(1.5)));
+        assert_eq!(FloatDuration::from_go_duration("+.5h"), Ok(FloatDuration::minutes(30.0)));
+        assert_eq!(FloatDuration::from_go_duration("1m1m"), Ok(FloatDuration::minutes(2.0)));
+
+        assert_eq!(FloatDuration::from_go_duration(""), Err(ParseDurationError::Empty));
+        assert_eq!(FloatDuration::from_go_duration("-"), Err(ParseDurationError::Empty));
+        assert_eq!(FloatDuration::from_go_duration("10"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(FloatDuration::from_go_duration("1d"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(FloatDuration::from_go_duration("1h 30m"),
+                   Err(ParseDurationError::UnknownUnit));
+        assert_eq!(FloatDuration::from_go_duration(".s"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(FloatDuration::from_go_duration("1.2.3s"),
+                   Err(ParseDurationError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_go_round_trip() {
+        for &secs in &[0.0, 1e-9, 0.000123, 0.5, 59.999, 3723.5, -86400.000001] {
+            let d = FloatDuration::seconds(secs);
+            let parsed = FloatDuration::from_go_duration(&d.to_go_duration()).unwrap();
+            assert!(parsed.approx_eq(d, FloatDuration::nanoseconds(0.5)));
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Histograms of durations with percentile queries.
+use duration::FloatDuration;
+
+/// A histogram counting durations in a fixed set of buckets.
+///
+/// The buckets evenly divide a range of durations either linearly, with
+/// [`linear`](#method.linear), or logarithmically, with
+/// [`logarithmic`](#method.logarithmic). Logarithmic buckets keep the same relative
+/// precision across several orders of magnitude, which usually suits latencies
+/// best. Durations outside of the range are still counted, in an underflow or
+/// overflow bucket extending to the smallest or largest duration recorded.
+///
+/// Percentiles are estimated by interpolating linearly within the bucket
+/// containing the requested rank.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::histogram::DurationHistogram;
+///
+/// let mut histogram = DurationHistogram::linear(FloatDuration::zero(),
+///                                               FloatDuration::milliseconds(100.0),
+///                                               100);
+/// for ms in 0..100 {
+///     histogram.record(FloatDuration::milliseconds(ms as f64 + 0.5));
+/// }
+///
+/// assert_eq!(histogram.count(), 100);
+/// assert_eq!(histogram.p50(), Some(FloatDuration::milliseconds(50.0)));
+/// assert_eq!(histogram.p99(), Some(FloatDuration::milliseconds(99.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DurationHistogram {
+    edges: Vec<FloatDuration>,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+    count: u64,
+    min: Option<FloatDuration>,
+    max: Option<FloatDuration>,
+}
+
+impl DurationHistogram {
+    /// Create a histogram with `buckets` buckets of equal width spanning from `min`
+    /// to `max`.
+    ///
+    /// # Panics
+    /// This function panics if `buckets` is zero, or if `min` and `max` are not
+    /// finite with `min < max`.
+    pub fn linear(min: FloatDuration, max: FloatDuration, buckets: usize) -> DurationHistogram {
+        check_range(min, max, buckets);
+        let width = (max - min) / buckets as f64;
+        let edges = (0..buckets + 1)
+            .map(|i| if i == buckets { max } else { min + width * i as f64 })
+            .collect();
+        DurationHistogram::from_edges(edges)
+    }
+    /// Create a histogram with `buckets` buckets spanning from `min` to `max`, where
+    /// the upper edge of each bucket is a constant factor larger than its lower edge.
+    ///
+    /// # Panics
+    /// This function panics if `buckets` is zero, or if `min` and `max` are not
+    /// finite with `0 < min < max`.
+    pub fn logarithmic(min: FloatDuration,
+                       max: FloatDuration,
+                       buckets: usize)
+                       -> DurationHistogram {
+        check_range(min, max, buckets);
+        assert!(min > FloatDuration::zero(), "logarithmic histogram buckets must start above zero");
+        let ratio = (max / min).powf(1.0 / buckets as f64);
+        let edges = (0..buckets + 1)
+            .map(|i| if i == buckets { max } else { min * ratio.powi(i as i32) })
+            .collect();
+        DurationHistogram::from_edges(edges)
+    }
+
+    fn from_edges(edges: Vec<FloatDuration>) -> DurationHistogram {
+        DurationHistogram {
+            counts: vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+            count: 0,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Record a single duration.
+    ///
+    /// NaN durations are ignored.
+    pub fn record(&mut self, duration: FloatDuration) {
+        if duration.as_seconds().is_nan() {
+            return;
+        }
+        self.record_n(duration, 1);
+    }
+    /// Record `n` occurrences of the same duration.
+    ///
+    /// NaN durations are ignored.
+    pub fn record_n(&mut self, duration: FloatDuration, n: u64) {
+        if n == 0 || duration.as_seconds().is_nan() {
+            return;
+        }
+        let last = self.edges.len() - 1;
+        if duration < self.edges[0] {
+            self.underflow += n;
+        } else if duration >= self.edges[last] {
+            self.overflow += n;
+        } else {
+            let index = self.edges.partition_point(|&edge| edge <= duration) - 1;
+            self.counts[index] += n;
+        }
+        self.count += n;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+    /// Discard all recorded durations, keeping the buckets.
+    pub fn clear(&mut self) {
+        for count in &mut self.counts {
+            *count = 0;
+        }
+        self.underflow = 0;
+        self.overflow = 0;
+        self.count = 0;
+        self.min = None;
+        self.max = None;
+    }
+
+    /// The total number of durations recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+    /// Returns true if no durations have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// The smallest recorded duration, or `None` if there are none.
+    pub fn min(&self) -> Option<FloatDuration> {
+        self.min
+    }
+    /// The largest recorded duration, or `None` if there are none.
+    pub fn max(&self) -> Option<FloatDuration> {
+        self.max
+    }
+    /// The number of durations recorded below the range of the buckets.
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+    /// The number of durations recorded above the range of the buckets.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+    /// Return an iterator over the buckets within the range of the histogram.
+    ///
+    /// Each item is the lower edge, upper edge and count of a bucket. The lower
+    /// edge is inclusive and the upper edge exclusive.
+    pub fn buckets<'a>(&'a self)
+                       -> impl Iterator<Item = (FloatDuration, FloatDuration, u64)> + 'a {
+        self.edges.windows(2).zip(&self.counts).map(|(edges, &count)| (edges[0], edges[1], count))
+    }
+
+    /// Estimate the duration below which `percentile` percent of the recorded
+    /// durations fall.
+    ///
+    /// Returns `None` if no durations have been recorded.
+    ///
+    /// # Panics
+    /// This function panics if `percentile` is not within `[0, 100]`.
+    pub fn percentile(&self, percentile: f64) -> Option<FloatDuration> {
+        assert!((0.0..=100.0).contains(&percentile),
+                "percentile must be between 0 and 100");
+        let (min, max) = match (self.min, self.max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return None,
+        };
+        let rank = percentile / 100.0 * self.count as f64;
+        let last = self.edges.len() - 1;
+
+        let mut cumulative = 0.0;
+        let buckets = Some((min, self.edges[0], self.underflow))
+            .into_iter()
+            .chain(self.buckets())
+            .chain(Some((self.edges[last], max, self.overflow)));
+        for (low, high, count) in buckets {
+            if count == 0 {
+                continue;
+            }
+            let count = count as f64;
+            if cumulative + count >= rank {
+                // Only interpolate across the part of the bucket actually holding
+                // recorded durations.
+                let low = low.max(min);
+                let high = high.min(max);
+                let fraction = (rank - cumulative) / count;
+                return Some(low + (high - low) * fraction);
+            }
+            cumulative += count;
+        }
+        Some(max)
+    }
+    /// Estimate the median of the recorded durations.
+    pub fn p50(&self) -> Option<FloatDuration> {
+        self.percentile(50.0)
+    }
+    /// Estimate the 90th percentile of the recorded durations.
+    pub fn p90(&self) -> Option<FloatDuration> {
+        self.percentile(90.0)
+    }
+    /// Estimate the 99th percentile of the recorded durations.
+    pub fn p99(&self) -> Option<FloatDuration> {
+        self.percentile(99.0)
+    }
+    /// Estimate the 99.9th percentile of the recorded durations.
+    pub fn p999(&self) -> Option<FloatDuration> {
+        self.percentile(99.9)
+    }
+}
+
+impl Extend<FloatDuration> for DurationHistogram {
+    fn extend<I: IntoIterator<Item = FloatDuration>>(&mut self, iter: I) {
+        for duration in iter {
+            self.record(duration);
+        }
+    }
+}
+
+fn check_range(min: FloatDuration, max: FloatDuration, buckets: usize) {
+    assert!(buckets > 0, "a histogram needs at least one bucket");
+    assert!(min.as_seconds().is_finite() && max.as_seconds().is_finite() && min < max,
+            "the histogram range must be finite and non-empty");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_buckets() {
+        let mut histogram = DurationHistogram::linear(FloatDuration::seconds(0.0),
+                                                      FloatDuration::seconds(4.0),
+                                                      4);
+        histogram.extend(vec![FloatDuration::seconds(0.0),
+                              FloatDuration::seconds(1.5),
+                              FloatDuration::seconds(1.9),
+                              FloatDuration::seconds(3.99),
+                              FloatDuration::seconds(4.0),
+                              FloatDuration::seconds(-1.0),
+                              FloatDuration::seconds(f64::NAN)]);
+
+        let counts: Vec<_> = histogram.buckets().map(|(_, _, count)| count).collect();
+        assert_eq!(counts, vec![1, 2, 0, 1]);
+        assert_eq!(histogram.buckets().nth(2).unwrap(),
+                   (FloatDuration::seconds(2.0), FloatDuration::seconds(3.0), 0));
+        assert_eq!(histogram.underflow(), 1);
+        assert_eq!(histogram.overflow(), 1);
+        assert_eq!(histogram.count(), 6);
+        assert_eq!(histogram.min(), Some(FloatDuration::seconds(-1.0)));
+        assert_eq!(histogram.max(), Some(FloatDuration::seconds(4.0)));
+
+        histogram.clear();
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.p50(), None);
+    }
+
+    #[test]
+    fn test_logarithmic_buckets() {
+        let mut histogram = DurationHistogram::logarithmic(FloatDuration::microseconds(1.0),
+                                                           FloatDuration::seconds(1.0),
+                                                           6);
+        let edges: Vec<_> = histogram.buckets().map(|(low, _, _)| low.as_microseconds()).collect();
+        for (edge, expected) in edges.iter().zip(&[1.0, 10.0, 100.0, 1e3, 1e4, 1e5]) {
+            assert!((edge - expected).abs() < 1e-6 * expected);
+        }
+
+        histogram.record(FloatDuration::microseconds(5.0));
+        histogram.record_n(FloatDuration::milliseconds(50.0), 3);
+        let counts: Vec<_> = histogram.buckets().map(|(_, _, count)| count).collect();
+        assert_eq!(counts, vec![1, 0, 0, 0, 3, 0]);
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let mut histogram = DurationHistogram::linear(FloatDuration::zero(),
+                                                      FloatDuration::seconds(10.0),
+                                                      10);
+        for s in 0..10 {
+            histogram.record(FloatDuration::seconds(s as f64 + 0.5));
+        }
+        assert_eq!(histogram.percentile(0.0), Some(FloatDuration::seconds(0.5)));
+        assert_eq!(histogram.p50(), Some(FloatDuration::seconds(5.0)));
+        assert_eq!(histogram.p90(), Some(FloatDuration::seconds(9.0)));
+        assert_eq!(histogram.percentile(100.0), Some(FloatDuration::seconds(9.5)));
+
+        histogram.record_n(FloatDuration::seconds(20.0), 10);
+        assert_eq!(histogram.p99(), Some(FloatDuration::seconds(19.8)));
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_invalid_percentile_panic() {
+        DurationHistogram::linear(FloatDuration::zero(), FloatDuration::seconds(1.0), 1)
+            .percentile(101.0);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_logarithmic_zero_panic() {
+        DurationHistogram::logarithmic(FloatDuration::zero(), FloatDuration::seconds(1.0), 4);
+    }
+}
@@ -0,0 +1,212 @@
+//! A monotonic point in time on a floating-point timeline.
+use core::fmt;
+use core::ops;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use duration::{FloatDuration, TimeOffset, TimePoint};
+#[cfg(feature = "std")]
+use duration::{instant_elapsed, Elapsed};
+
+/// A point in time stored as a floating-point number of seconds since an
+/// arbitrary epoch.
+///
+/// `FloatInstant` is the point-in-time counterpart of `FloatDuration`: subtracting
+/// two instants gives a duration, and a duration can be added to an instant. It is
+/// meant for timelines that are already floating-point, such as the time of a
+/// simulation or the position of an audio engine.
+///
+/// `FloatInstant::now` measures real time from an epoch fixed on its first call in
+/// the process, so instants obtained from it may be compared with each other.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FloatInstant};
+///
+/// let start = FloatInstant::from_epoch(FloatDuration::seconds(10.0));
+/// let end = start + FloatDuration::milliseconds(250.0);
+///
+/// assert!(end > start);
+/// assert_eq!(end - start, FloatDuration::milliseconds(250.0));
+/// assert_eq!(end.since_epoch(), FloatDuration::seconds(10.25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct FloatInstant {
+    secs: f64,
+}
+
+impl FloatInstant {
+    /// The epoch of the timeline.
+    pub const EPOCH: FloatInstant = FloatInstant { secs: 0.0 };
+
+    /// Return the instant `duration` after the epoch.
+    #[inline]
+    pub fn from_epoch(duration: FloatDuration) -> FloatInstant {
+        FloatInstant { secs: duration.as_seconds() }
+    }
+    /// Return the current instant on a monotonic process-wide timeline.
+    ///
+    /// The epoch of this timeline is the first call to `now` in the process.
+    #[cfg(feature = "std")]
+    pub fn now() -> FloatInstant {
+        static ANCHOR: OnceLock<Instant> = OnceLock::new();
+        let anchor = *ANCHOR.get_or_init(Instant::now);
+        FloatInstant::from_epoch(instant_elapsed(anchor))
+    }
+
+    /// Return the amount of time since the epoch.
+    #[inline]
+    pub fn since_epoch(&self) -> FloatDuration {
+        FloatDuration::seconds(self.secs)
+    }
+    /// Return the amount of time from `earlier` to `self`.
+    ///
+    /// This is negative if `earlier` is after `self`.
+    #[inline]
+    pub fn duration_since(&self, earlier: FloatInstant) -> FloatDuration {
+        FloatDuration::seconds(self.secs - earlier.secs)
+    }
+    /// Return the amount of real time passed since `self`.
+    ///
+    /// This is only meaningful for instants on the timeline of `FloatInstant::now`.
+    #[cfg(feature = "std")]
+    pub fn elapsed(&self) -> FloatDuration {
+        FloatInstant::now().duration_since(*self)
+    }
+
+    /// Return the earlier of two instants.
+    #[inline]
+    pub fn min(self, other: FloatInstant) -> FloatInstant {
+        FloatInstant { secs: self.secs.min(other.secs) }
+    }
+    /// Return the later of two instants.
+    #[inline]
+    pub fn max(self, other: FloatInstant) -> FloatInstant {
+        FloatInstant { secs: self.secs.max(other.secs) }
+    }
+}
+
+impl TimePoint for FloatInstant {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: FloatInstant) -> Result<FloatDuration, ()> {
+        Ok(self.duration_since(since))
+    }
+}
+impl TimeOffset for FloatInstant {
+    /// Returns `None` if the resulting instant is not finite.
+    #[inline]
+    fn checked_add_float(&self, duration: FloatDuration) -> Option<FloatInstant> {
+        let secs = self.secs + duration.as_seconds();
+        if secs.is_finite() {
+            Some(FloatInstant { secs })
+        } else {
+            None
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl Elapsed for FloatInstant {
+    #[inline]
+    fn now() -> FloatInstant {
+        FloatInstant::now()
+    }
+}
+
+impl fmt::Display for FloatInstant {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "T+")?;
+        fmt::Display::fmt(&self.since_epoch(), fmt)
+    }
+}
+
+impl ops::Add<FloatDuration> for FloatInstant {
+    type Output = FloatInstant;
+
+    #[inline]
+    fn add(self, rhs: FloatDuration) -> FloatInstant {
+        FloatInstant { secs: self.secs + rhs.as_seconds() }
+    }
+}
+impl ops::Sub<FloatDuration> for FloatInstant {
+    type Output = FloatInstant;
+
+    #[inline]
+    fn sub(self, rhs: FloatDuration) -> FloatInstant {
+        FloatInstant { secs: self.secs - rhs.as_seconds() }
+    }
+}
+impl ops::Sub<FloatInstant> for FloatInstant {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn sub(self, rhs: FloatInstant) -> FloatDuration {
+        self.duration_since(rhs)
+    }
+}
+impl ops::AddAssign<FloatDuration> for FloatInstant {
+    #[inline]
+    fn add_assign(&mut self, rhs: FloatDuration) {
+        self.secs += rhs.as_seconds();
+    }
+}
+impl ops::SubAssign<FloatDuration> for FloatInstant {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FloatDuration) {
+        self.secs -= rhs.as_seconds();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let start = FloatInstant::EPOCH + FloatDuration::seconds(1.5);
+        assert_eq!(start, FloatInstant::from_epoch(FloatDuration::seconds(1.5)));
+        assert_eq!(start - FloatDuration::seconds(0.5),
+                   FloatInstant::from_epoch(FloatDuration::seconds(1.0)));
+        assert_eq!(start - FloatInstant::EPOCH, FloatDuration::seconds(1.5));
+        assert_eq!(FloatInstant::EPOCH.duration_since(start), FloatDuration::seconds(-1.5));
+        assert_eq!(start.float_duration_since(FloatInstant::EPOCH),
+                   Ok(FloatDuration::seconds(1.5)));
+
+        let mut t = start;
+        t += FloatDuration::seconds(2.0);
+        t -= FloatDuration::seconds(0.5);
+        assert_eq!(t.since_epoch(), FloatDuration::seconds(3.0));
+
+        assert_eq!(start.checked_add_float(FloatDuration::seconds(f64::INFINITY)), None);
+        assert_eq!(start.checked_sub_float(FloatDuration::seconds(1.5)),
+                   Some(FloatInstant::EPOCH));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let early = FloatInstant::from_epoch(FloatDuration::seconds(1.0));
+        let late = FloatInstant::from_epoch(FloatDuration::seconds(2.0));
+        assert!(early < late);
+        assert_eq!(early.max(late), late);
+        assert_eq!(early.min(late), early);
+        assert_eq!(FloatInstant::default(), FloatInstant::EPOCH);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_now() {
+        let start = FloatInstant::now();
+        ::std::thread::sleep(::std::time::Duration::from_millis(2));
+        let end = FloatInstant::now();
+        assert!(end - start >= FloatDuration::milliseconds(2.0));
+        assert!(start.elapsed() >= FloatDuration::milliseconds(2.0));
+        assert!(start.float_elapsed().unwrap() >= FloatDuration::milliseconds(2.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", FloatInstant::from_epoch(FloatDuration::minutes(1.5))),
+                   "T+1.5 minutes");
+    }
+}
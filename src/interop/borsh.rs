@@ -0,0 +1,42 @@
+//! Binary encoding of `FloatDuration` with `borsh`.
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::Float;
+
+use duration::GenericFloatDuration;
+
+/// Encodes the number of seconds as a little-endian float.
+///
+/// Like every float in `borsh`, a NaN duration cannot be serialized.
+impl<F: BorshSerialize + Float> BorshSerialize for GenericFloatDuration<F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_seconds().serialize(writer)
+    }
+}
+
+impl<F: BorshDeserialize + Float> BorshDeserialize for GenericFloatDuration<F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        F::deserialize_reader(reader).map(GenericFloatDuration::seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_borsh_round_trip() {
+        let duration = FloatDuration::seconds(1.5);
+        let bytes = ::borsh::to_vec(&duration).unwrap();
+        assert_eq!(bytes, 1.5f64.to_le_bytes());
+        assert_eq!(::borsh::from_slice::<FloatDuration>(&bytes).unwrap(), duration);
+
+        let duration = FloatDuration32::minutes(-2.0);
+        let bytes = ::borsh::to_vec(&duration).unwrap();
+        assert_eq!(bytes, (-120.0f32).to_le_bytes());
+        assert_eq!(::borsh::from_slice::<FloatDuration32>(&bytes).unwrap(), duration);
+
+        assert!(::borsh::to_vec(&FloatDuration::seconds(f64::NAN)).is_err());
+        assert!(::borsh::from_slice::<FloatDuration>(&[0; 4]).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+//! Diesel mappings of `FloatDuration` to PostgreSQL columns.
+use diesel::data_types::PgInterval;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Double, Interval};
+
+use duration::FloatDuration;
+use super::interval;
+
+/// An `INTERVAL` column, rounded to the nearest microsecond and written without days
+/// or months. Non-finite durations, and durations beyond about 292,000 years,
+/// cannot be written.
+impl ToSql<Interval, Pg> for FloatDuration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let interval = PgInterval::from_microseconds(interval::to_micros(*self)?);
+        ToSql::<Interval, Pg>::to_sql(&interval, &mut out.reborrow())
+    }
+}
+
+/// An `INTERVAL` column, converting days and months like `EXTRACT(EPOCH FROM ...)`:
+/// a day is 24 hours, a month is 30 days and a year is 365.25 days.
+impl FromSql<Interval, Pg> for FloatDuration {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<FloatDuration> {
+        let interval: PgInterval = FromSql::<Interval, Pg>::from_sql(value)?;
+        Ok(interval::from_parts(interval.microseconds, interval.days, interval.months))
+    }
+}
+
+/// A `double precision` column holding a number of seconds.
+impl ToSql<Double, Pg> for FloatDuration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Double, Pg>::to_sql(&self.as_seconds(), &mut out.reborrow())
+    }
+}
+
+/// A `double precision` column holding a number of seconds.
+impl FromSql<Double, Pg> for FloatDuration {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<FloatDuration> {
+        FromSql::<Double, Pg>::from_sql(value).map(FloatDuration::seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::dsl::sql;
+    use diesel::pg::Pg;
+    use diesel::sql_types::{Double, Interval};
+    use diesel::{debug_query, select, ExpressionMethods, IntoSql};
+
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_as_expression() {
+        let query = select(FloatDuration::seconds(1.5).into_sql::<Interval>());
+        assert_eq!(debug_query::<Pg, _>(&query).to_string(),
+                   "SELECT $1 -- binds: [GenericFloatDuration { secs: 1.5 }]");
+        let query = select(sql::<Double>("1").eq(FloatDuration::seconds(1.0)));
+        assert_eq!(debug_query::<Pg, _>(&query).to_string(),
+                   "SELECT (1 = $1) -- binds: [GenericFloatDuration { secs: 1.0 }]");
+    }
+}
@@ -0,0 +1,94 @@
+//! Interoperability with the `hifitime` crate.
+use num_traits::Float;
+use hifitime::{Duration, Epoch};
+
+use duration::{float, FloatDuration, FromDuration, GenericFloatDuration, TimePoint};
+use error::OutOfRangeError;
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Create a `hifitime::Duration` object from a `FloatDuration`.
+    ///
+    /// # Errors
+    /// `hifitime::Duration` cannot represent non-finite values or durations longer
+    /// than about 32768 centuries, in which case this function returns an
+    /// `OutOfRangeError`.
+    pub fn to_hifitime(&self) -> Result<Duration, OutOfRangeError> {
+        let secs = self.as_seconds().to_f64().ok_or_else(OutOfRangeError::new)?;
+        if !secs.is_finite() || secs > Duration::MAX.to_seconds() ||
+           secs < Duration::MIN.to_seconds() {
+            Err(OutOfRangeError::new())
+        } else {
+            Ok(Duration::from_seconds(secs))
+        }
+    }
+    /// Create a `FloatDuration` object from a `hifitime::Duration`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_hifitime(duration: Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(float(duration.to_seconds()))
+    }
+}
+
+impl TimePoint for Epoch {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: Epoch) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_hifitime(self - since))
+    }
+}
+
+impl<F: Float> FromDuration<Duration> for GenericFloatDuration<F> {
+    type Error = ();
+    #[inline]
+    fn from_duration(from: Duration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_hifitime(from))
+    }
+}
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for Duration {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: GenericFloatDuration<F>) -> Result<Duration, OutOfRangeError> {
+        from.to_hifitime()
+    }
+}
+impl<F: Float> From<Duration> for GenericFloatDuration<F> {
+    #[inline]
+    fn from(from: Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_hifitime(from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hifitime::{TimeScale, Unit};
+
+    #[test]
+    fn test_hifitime_conversion() {
+        assert_eq!(FloatDuration::minutes(2.5).to_hifitime(),
+                   Ok(Duration::from_f64(150.0, Unit::Second)));
+        assert_eq!(FloatDuration::seconds(-0.25).to_hifitime(),
+                   Ok(Duration::from_f64(-250.0, Unit::Millisecond)));
+        assert!(FloatDuration::years(1e7).to_hifitime().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_hifitime().is_err());
+
+        assert_eq!(FloatDuration::from_hifitime(Duration::from_f64(90.5, Unit::Second)),
+                   FloatDuration::seconds(90.5));
+        assert_eq!(FloatDuration::from(Duration::from_f64(-1.5, Unit::Day)),
+                   FloatDuration::days(-1.5));
+    }
+
+    #[test]
+    fn test_time_point() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 5, 25);
+        let end = Epoch::from_gregorian_utc(2017, 5, 26, 2, 0, 0, 500_000_000);
+        assert_eq!(end.float_duration_since(start),
+                   Ok(FloatDuration::hours(26.0) + FloatDuration::milliseconds(500.0)));
+
+        // Differences are computed on the underlying TAI time scale, so epochs in
+        // different time scales can be compared directly.
+        let tai = Epoch::from_gregorian_hms(2017, 5, 25, 0, 0, 37, TimeScale::TAI);
+        assert_eq!(tai.float_duration_since(start), Ok(FloatDuration::zero()));
+    }
+}
@@ -0,0 +1,153 @@
+//! Interoperability with the `humantime` crate.
+use core::fmt;
+use std::time;
+
+use num_traits::Float;
+use humantime::Duration;
+
+use duration::{FromDuration, GenericFloatDuration};
+use error::OutOfRangeError;
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Create a `humantime::Duration` object from a `FloatDuration`.
+    ///
+    /// # Errors
+    /// `humantime::Duration` wraps a `std::time::Duration`, and so has the same
+    /// range. This function returns an `OutOfRangeError` for negative, non-finite,
+    /// and too large durations.
+    pub fn to_humantime(&self) -> Result<Duration, OutOfRangeError> {
+        self.to_std().map(Duration::from)
+    }
+    /// Create a `FloatDuration` object from a `humantime::Duration`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_humantime(duration: Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_std(duration.into())
+    }
+}
+
+impl<F: Float> FromDuration<Duration> for GenericFloatDuration<F> {
+    type Error = ();
+    #[inline]
+    fn from_duration(from: Duration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_humantime(from))
+    }
+}
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for Duration {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: GenericFloatDuration<F>) -> Result<Duration, OutOfRangeError> {
+        from.to_humantime()
+    }
+}
+impl<F: Float> From<Duration> for GenericFloatDuration<F> {
+    #[inline]
+    fn from(from: Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_humantime(from)
+    }
+}
+
+/// Serialize and deserialize a `FloatDuration` as a `humantime` string, such as
+/// `"1h 30m 15s 500ms"`.
+///
+/// This module is meant to be used with `#[serde(with = "...")]`, and accepts the
+/// same strings as the `humantime-serde` crate.
+///
+/// ```rust,ignore
+/// use float_duration::FloatDuration;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "float_duration::humantime_serde")]
+///     timeout: FloatDuration,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"timeout": "1m 30s"}"#).unwrap();
+/// assert_eq!(config.timeout, FloatDuration::seconds(90.0));
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde {
+    use super::*;
+    use serde_crate::{de, Deserializer, Serializer};
+
+    /// Serialize a `FloatDuration` as a `humantime` string.
+    ///
+    /// # Errors
+    /// Negative, non-finite, and too large durations cannot be formatted by
+    /// `humantime` and result in an error.
+    pub fn serialize<F, S>(duration: &GenericFloatDuration<F>, serializer: S) -> Result<S::Ok, S::Error>
+        where F: Float,
+              S: Serializer
+    {
+        let duration = duration.to_std().map_err(::serde_crate::ser::Error::custom)?;
+        serializer.collect_str(&::humantime::format_duration(duration))
+    }
+
+    /// Deserialize a `FloatDuration` from a `humantime` string.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<GenericFloatDuration<F>, D::Error>
+        where F: Float,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(HumantimeVisitor)
+            .map(GenericFloatDuration::from_std)
+    }
+
+    /// The JSON schema of this representation, for `#[schemars(schema_with = "...")]`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        ::schemars::json_schema!({
+            "type": "string",
+            "description": "A duration such as \"1h 30m\"."
+        })
+    }
+
+    struct HumantimeVisitor;
+
+    impl<'de> de::Visitor<'de> for HumantimeVisitor {
+        type Value = time::Duration;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a duration string such as \"1h 30m\"")
+        }
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<time::Duration, E> {
+            ::humantime::parse_duration(value).map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_humantime_conversion() {
+        let d = FloatDuration::minutes(90.0) + FloatDuration::milliseconds(500.0);
+        let h = d.to_humantime().unwrap();
+        assert_eq!(h.to_string(), "1h 30m 500ms");
+        assert_eq!(FloatDuration::from_humantime(h), d);
+        assert_eq!(FloatDuration::from("2days".parse::<Duration>().unwrap()),
+                   FloatDuration::days(2.0));
+        assert_eq!(FloatDuration::seconds(-1.0).to_humantime(), Err(OutOfRangeError::new()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_humantime_serde() {
+        use serde_test::{Token, assert_de_tokens, assert_ser_tokens, assert_de_tokens_error};
+
+        #[derive(Debug, PartialEq, ::serde_crate::Serialize, ::serde_crate::Deserialize)]
+        struct Timeout(#[serde(with = "super::serde")] FloatDuration);
+
+        let timeout = Timeout(FloatDuration::seconds(61.5));
+        let tokens = [Token::NewtypeStruct { name: "Timeout" }, Token::Str("1m 1s 500ms")];
+        assert_ser_tokens(&timeout, &tokens);
+        assert_de_tokens(&timeout, &tokens);
+        assert_de_tokens(&Timeout(FloatDuration::hours(2.0)),
+                         &[Token::NewtypeStruct { name: "Timeout" }, Token::Str("2h")]);
+        assert_de_tokens_error::<Timeout>(&[Token::NewtypeStruct { name: "Timeout" },
+                                            Token::Str("soon")],
+                                          "expected number at 0");
+    }
+}
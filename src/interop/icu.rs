@@ -0,0 +1,258 @@
+//! Locale-aware formatting of durations with the ICU4X crates.
+use core::fmt;
+use std::error::Error;
+
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_decimal::DecimalFormatter;
+use icu_locale_core::Locale;
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::DataError;
+
+use duration::FloatDuration;
+use unit::TimeUnit;
+
+/// The singular and plural names of each `TimeUnit`, in the order of its variants.
+type UnitNames = [[&'static str; 2]; 10];
+
+const EN: UnitNames = [["nanosecond", "nanoseconds"],
+                       ["microsecond", "microseconds"],
+                       ["millisecond", "milliseconds"],
+                       ["second", "seconds"],
+                       ["minute", "minutes"],
+                       ["hour", "hours"],
+                       ["day", "days"],
+                       ["week", "weeks"],
+                       ["month", "months"],
+                       ["year", "years"]];
+const DE: UnitNames = [["Nanosekunde", "Nanosekunden"],
+                       ["Mikrosekunde", "Mikrosekunden"],
+                       ["Millisekunde", "Millisekunden"],
+                       ["Sekunde", "Sekunden"],
+                       ["Minute", "Minuten"],
+                       ["Stunde", "Stunden"],
+                       ["Tag", "Tage"],
+                       ["Woche", "Wochen"],
+                       ["Monat", "Monate"],
+                       ["Jahr", "Jahre"]];
+const ES: UnitNames = [["nanosegundo", "nanosegundos"],
+                       ["microsegundo", "microsegundos"],
+                       ["milisegundo", "milisegundos"],
+                       ["segundo", "segundos"],
+                       ["minuto", "minutos"],
+                       ["hora", "horas"],
+                       ["día", "días"],
+                       ["semana", "semanas"],
+                       ["mes", "meses"],
+                       ["año", "años"]];
+const FR: UnitNames = [["nanoseconde", "nanosecondes"],
+                       ["microseconde", "microsecondes"],
+                       ["milliseconde", "millisecondes"],
+                       ["seconde", "secondes"],
+                       ["minute", "minutes"],
+                       ["heure", "heures"],
+                       ["jour", "jours"],
+                       ["semaine", "semaines"],
+                       ["mois", "mois"],
+                       ["an", "ans"]];
+const IT: UnitNames = [["nanosecondo", "nanosecondi"],
+                       ["microsecondo", "microsecondi"],
+                       ["millisecondo", "millisecondi"],
+                       ["secondo", "secondi"],
+                       ["minuto", "minuti"],
+                       ["ora", "ore"],
+                       ["giorno", "giorni"],
+                       ["settimana", "settimane"],
+                       ["mese", "mesi"],
+                       ["anno", "anni"]];
+const NL: UnitNames = [["nanoseconde", "nanoseconden"],
+                       ["microseconde", "microseconden"],
+                       ["milliseconde", "milliseconden"],
+                       ["seconde", "seconden"],
+                       ["minuut", "minuten"],
+                       ["uur", "uur"],
+                       ["dag", "dagen"],
+                       ["week", "weken"],
+                       ["maand", "maanden"],
+                       ["jaar", "jaar"]];
+const PT: UnitNames = [["nanossegundo", "nanossegundos"],
+                       ["microssegundo", "microssegundos"],
+                       ["milissegundo", "milissegundos"],
+                       ["segundo", "segundos"],
+                       ["minuto", "minutos"],
+                       ["hora", "horas"],
+                       ["dia", "dias"],
+                       ["semana", "semanas"],
+                       ["mês", "meses"],
+                       ["ano", "anos"]];
+
+/// An error returned when a `LocalizedFormatter` cannot be created for a locale.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocaleError {
+    /// There are no unit names for the language of the locale.
+    UnsupportedLanguage,
+    /// The ICU data for the locale could not be loaded.
+    Data(DataError),
+}
+
+impl Error for LocaleError {
+    fn description(&self) -> &str {
+        match *self {
+            LocaleError::UnsupportedLanguage => "The language of the locale is not supported.",
+            LocaleError::Data(_) => "The locale data could not be loaded.",
+        }
+    }
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LocaleError::UnsupportedLanguage => {
+                write!(f, "The language of the locale is not supported.")
+            }
+            LocaleError::Data(ref err) => write!(f, "The locale data could not be loaded: {}", err),
+        }
+    }
+}
+
+impl From<DataError> for LocaleError {
+    fn from(err: DataError) -> LocaleError {
+        LocaleError::Data(err)
+    }
+}
+
+/// Formats durations with the unit names, plural rules and number format of a
+/// locale.
+///
+/// Unit names are available for English, German, Spanish, French, Italian, Dutch
+/// and Portuguese. Plural rules and the number format, such as the decimal
+/// separator, come from the ICU data of the full locale.
+///
+/// ```rust
+/// # extern crate icu_locale_core;
+/// # extern crate float_duration;
+/// use float_duration::{FloatDuration, LocalizedFormatter, TimeUnit};
+/// use icu_locale_core::Locale;
+///
+/// # fn main() {
+/// let german = LocalizedFormatter::try_new(&"de-DE".parse::<Locale>().unwrap()).unwrap();
+/// assert_eq!(german.format(FloatDuration::seconds(210.0)).to_string(), "3,5 Minuten");
+/// assert_eq!(german.format_in(FloatDuration::days(1.0), TimeUnit::Day).to_string(), "1 Tag");
+/// assert_eq!(format!("{:.2}", german.format(FloatDuration::hours(2.0))), "2,00 Stunden");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LocalizedFormatter {
+    plurals: PluralRules,
+    decimals: DecimalFormatter,
+    names: &'static UnitNames,
+}
+
+impl LocalizedFormatter {
+    /// Create a formatter for `locale`.
+    ///
+    /// # Errors
+    /// Returns `LocaleError::UnsupportedLanguage` if there are no unit names for the
+    /// language of `locale`.
+    pub fn try_new(locale: &Locale) -> Result<LocalizedFormatter, LocaleError> {
+        let names = match locale.id.language.as_str() {
+            "en" => &EN,
+            "de" => &DE,
+            "es" => &ES,
+            "fr" => &FR,
+            "it" => &IT,
+            "nl" => &NL,
+            "pt" => &PT,
+            _ => return Err(LocaleError::UnsupportedLanguage),
+        };
+        Ok(LocalizedFormatter {
+            plurals: PluralRules::try_new_cardinal(locale.into())?,
+            decimals: DecimalFormatter::try_new(locale.into(), Default::default())?,
+            names,
+        })
+    }
+
+    /// Return an object that displays `duration` in the unit chosen by
+    /// `FloatDuration::appropriate_unit`.
+    ///
+    /// The precision flag of the formatter sets the number of decimal places.
+    /// Non-finite durations are shown as by `Display`.
+    pub fn format(&self, duration: FloatDuration) -> Localized<'_> {
+        self.format_in(duration, duration.appropriate_unit())
+    }
+    /// Return an object that displays `duration` in `unit`.
+    pub fn format_in(&self, duration: FloatDuration, unit: TimeUnit) -> Localized<'_> {
+        Localized {
+            formatter: self,
+            duration,
+            unit,
+        }
+    }
+}
+
+/// A `Display` adapter that shows a duration formatted for a locale.
+///
+/// This type is returned by `LocalizedFormatter::format` and
+/// `LocalizedFormatter::format_in`.
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<'a> {
+    formatter: &'a LocalizedFormatter,
+    duration: FloatDuration,
+    unit: TimeUnit,
+}
+
+impl<'a> fmt::Display for Localized<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.duration.in_unit(self.unit);
+        let mut number = match Decimal::try_from_f64(value, FloatPrecision::RoundTrip) {
+            Ok(number) => number,
+            Err(_) => return fmt::Display::fmt(&self.duration, fmt),
+        };
+        if let Some(decimals) = fmt.precision() {
+            let position = -(decimals.min(i16::MAX as usize) as i16);
+            number.round(position);
+            number.absolute.pad_end(position);
+        }
+
+        let names = &self.formatter.names[self.unit as usize];
+        let name = match self.formatter.plurals.category_for(&number) {
+            PluralCategory::One => names[0],
+            _ => names[1],
+        };
+        write!(fmt, "{} {}", self.formatter.decimals.format(&number), name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formatter(locale: &str) -> LocalizedFormatter {
+        LocalizedFormatter::try_new(&locale.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_localized() {
+        let french = formatter("fr");
+        assert_eq!(french.format(FloatDuration::hours(1.5)).to_string(), "1,5 heure");
+        assert_eq!(french.format(FloatDuration::days(-3.0)).to_string(), "-3 jours");
+        assert_eq!(french.format_in(FloatDuration::days(1500.0), TimeUnit::Day).to_string(),
+                   "1\u{202f}500 jours");
+
+        let english = formatter("en-GB");
+        assert_eq!(english.format(FloatDuration::minutes(1.0)).to_string(), "60 seconds");
+        assert_eq!(format!("{:.1}", english.format(FloatDuration::minutes(2.0))),
+                   "2.0 minutes");
+        assert_eq!(english.format_in(FloatDuration::weeks(1.0), TimeUnit::Week).to_string(),
+                   "1 week");
+
+        assert_eq!(formatter("pt-BR").format(FloatDuration::zero()).to_string(), "0 segundo");
+        assert_eq!(formatter("es").format(FloatDuration::seconds(1.0) / 0.0).to_string(),
+                   "inf seconds");
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(LocalizedFormatter::try_new(&"ja-JP".parse().unwrap()).unwrap_err(),
+                   LocaleError::UnsupportedLanguage);
+    }
+}
@@ -0,0 +1,38 @@
+//! Conversions between `FloatDuration` and the parts of a PostgreSQL `INTERVAL`.
+use duration::FloatDuration;
+use error::OutOfRangeError;
+
+const MICROS_PER_SEC: f64 = 1_000_000.0;
+const SECS_PER_DAY: f64 = 86_400.0;
+
+/// Return the duration as a whole number of microseconds, the time part of an
+/// interval.
+pub(crate) fn to_micros(duration: FloatDuration) -> Result<i64, OutOfRangeError> {
+    let micros = (duration.as_seconds() * MICROS_PER_SEC).round();
+    if micros >= i64::MIN as f64 && micros < i64::MAX as f64 {
+        Ok(micros as i64)
+    } else {
+        Err(OutOfRangeError::new())
+    }
+}
+
+/// Return the duration of an interval, converting days and months the same way as
+/// PostgreSQL's `EXTRACT(EPOCH FROM ...)`.
+pub(crate) fn from_parts(micros: i64, days: i32, months: i32) -> FloatDuration {
+    let days = f64::from(days) + f64::from(months / 12) * 365.25 + f64::from(months % 12) * 30.0;
+    FloatDuration::seconds(days * SECS_PER_DAY) + FloatDuration::microseconds(micros as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_parts() {
+        assert_eq!(to_micros(FloatDuration::days(1.0)), Ok(86_400_000_000));
+        assert_eq!(to_micros(FloatDuration::microseconds(-1.6)), Ok(-2));
+        assert_eq!(to_micros(FloatDuration::seconds(f64::NAN)), Err(OutOfRangeError::new()));
+        assert_eq!(from_parts(500_000, 2, 14),
+                   FloatDuration::days(2.0 + 365.25 + 60.0) + FloatDuration::seconds(0.5));
+    }
+}
@@ -0,0 +1,157 @@
+//! Interoperability with the `jiff` crate.
+use core::convert::TryFrom;
+
+use num_traits::{Float, ToPrimitive};
+use jiff::{SignedDuration, Span, Timestamp};
+
+use duration::{float, FloatDuration, FromDuration, GenericFloatDuration, TimePoint,
+               NANOS_PER_SEC};
+use error::OutOfRangeError;
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Create a `jiff::SignedDuration` object from a `FloatDuration`.
+    ///
+    /// The sub-nanosecond part of the duration is truncated.
+    ///
+    /// # Errors
+    /// `jiff::SignedDuration` cannot represent non-finite values or more than
+    /// `i64::MAX` seconds. This function will return an `OutOfRangeError` if the
+    /// `FloatDuration` value is outside of those bounds.
+    pub fn to_jiff(&self) -> Result<SignedDuration, OutOfRangeError> {
+        let secs = self.as_seconds().to_f64().ok_or_else(OutOfRangeError::new)?;
+        let seconds = secs.trunc().to_i64().ok_or_else(OutOfRangeError::new)?;
+        let nanos = secs.fract() * NANOS_PER_SEC;
+        Ok(SignedDuration::new(seconds, nanos as i32))
+    }
+    /// Create a `FloatDuration` object from a `jiff::SignedDuration`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_jiff(duration: SignedDuration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(float(duration.as_secs() as f64 +
+                                            duration.subsec_nanos() as f64 / NANOS_PER_SEC))
+    }
+
+    /// Create a `jiff::Span` object from a `FloatDuration`.
+    ///
+    /// The span only has non-zero units of seconds or smaller. It can be balanced
+    /// into larger units with `Span::round`.
+    ///
+    /// # Errors
+    /// Returns an `OutOfRangeError` if the duration cannot be represented as a
+    /// `jiff::SignedDuration` or exceeds the range of a `jiff::Span`.
+    pub fn to_jiff_span(&self) -> Result<Span, OutOfRangeError> {
+        Span::try_from(self.to_jiff()?).map_err(|_| OutOfRangeError::new())
+    }
+    /// Create a `FloatDuration` object from a `jiff::Span`.
+    ///
+    /// # Errors
+    /// Days, weeks, months and years do not have a fixed length, so this function
+    /// returns an `OutOfRangeError` if `span` has any non-zero units of days or
+    /// larger. Use `Span::to_duration` with a relative date to convert such spans.
+    pub fn from_jiff_span(span: Span) -> Result<GenericFloatDuration<F>, OutOfRangeError> {
+        SignedDuration::try_from(span)
+            .map(GenericFloatDuration::from_jiff)
+            .map_err(|_| OutOfRangeError::new())
+    }
+}
+
+impl TimePoint for Timestamp {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: Timestamp) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_jiff(self.duration_since(since)))
+    }
+}
+#[cfg(feature = "std")]
+impl TimePoint for jiff::Zoned {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: jiff::Zoned) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_jiff(self.duration_since(&since)))
+    }
+}
+
+impl<F: Float> FromDuration<SignedDuration> for GenericFloatDuration<F> {
+    type Error = ();
+    #[inline]
+    fn from_duration(from: SignedDuration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_jiff(from))
+    }
+}
+impl<F: Float> FromDuration<Span> for GenericFloatDuration<F> {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: Span) -> Result<GenericFloatDuration<F>, OutOfRangeError> {
+        GenericFloatDuration::from_jiff_span(from)
+    }
+}
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for SignedDuration {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: GenericFloatDuration<F>) -> Result<SignedDuration, OutOfRangeError> {
+        from.to_jiff()
+    }
+}
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for Span {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: GenericFloatDuration<F>) -> Result<Span, OutOfRangeError> {
+        from.to_jiff_span()
+    }
+}
+impl<F: Float> From<SignedDuration> for GenericFloatDuration<F> {
+    #[inline]
+    fn from(from: SignedDuration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_jiff(from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::ToSpan;
+    use duration::FloatDuration32;
+
+    #[test]
+    fn test_jiff_conversion() {
+        assert_eq!(FloatDuration::minutes(2.5).to_jiff(),
+                   Ok(SignedDuration::from_secs(150)));
+        assert_eq!(FloatDuration::seconds(-0.25).to_jiff(),
+                   Ok(SignedDuration::from_millis(-250)));
+        assert!(FloatDuration::years(1e12).to_jiff().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_jiff().is_err());
+
+        assert_eq!(FloatDuration::from_jiff(SignedDuration::new(90, 500_000_000)),
+                   FloatDuration::seconds(90.5));
+        assert_eq!(FloatDuration::from(SignedDuration::from_millis(-1500)),
+                   FloatDuration::seconds(-1.5));
+        assert_eq!(FloatDuration32::from_jiff(SignedDuration::from_hours(2)),
+                   FloatDuration32::hours(2.0));
+    }
+
+    #[test]
+    fn test_jiff_span() {
+        let span = FloatDuration::seconds(90.5).to_jiff_span().unwrap();
+        assert_eq!(span.fieldwise(), 90.seconds().milliseconds(500).fieldwise());
+
+        assert_eq!(FloatDuration::from_jiff_span(1.hour().minutes(30)),
+                   Ok(FloatDuration::minutes(90.0)));
+        assert_eq!(FloatDuration::from_jiff_span(-2.seconds()),
+                   Ok(FloatDuration::seconds(-2.0)));
+        assert!(FloatDuration::from_jiff_span(1.day()).is_err());
+        assert!(FloatDuration::from_jiff_span(1.month()).is_err());
+    }
+
+    #[test]
+    fn test_time_point() {
+        let start = Timestamp::from_second(1_495_706_400).unwrap();
+        let end = Timestamp::new(1_495_800_000, 500_000_000).unwrap();
+        assert_eq!(end.float_duration_since(start),
+                   Ok(FloatDuration::hours(26.0) + FloatDuration::milliseconds(500.0)));
+
+        let zone = jiff::tz::TimeZone::UTC;
+        assert_eq!(end.to_zoned(zone.clone()).float_duration_since(start.to_zoned(zone)),
+                   Ok(FloatDuration::hours(26.0) + FloatDuration::milliseconds(500.0)));
+    }
+}
@@ -0,0 +1,155 @@
+//! Recording durations with the `metrics` crate.
+use std::time::Instant;
+
+use metrics::{Gauge, Histogram, Unit};
+
+use duration::{instant_elapsed, FloatDuration};
+use unit::TimeUnit;
+
+impl TimeUnit {
+    /// Return the `metrics::Unit` describing values in this unit.
+    ///
+    /// `metrics` has no units longer than a second, so this returns `None` for
+    /// minutes and longer units.
+    pub fn to_metrics_unit(&self) -> Option<Unit> {
+        match *self {
+            TimeUnit::Nanosecond => Some(Unit::Nanoseconds),
+            TimeUnit::Microsecond => Some(Unit::Microseconds),
+            TimeUnit::Millisecond => Some(Unit::Milliseconds),
+            TimeUnit::Second => Some(Unit::Seconds),
+            _ => None,
+        }
+    }
+}
+
+/// Record durations into `metrics` handles as a number of `unit`:
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # #[macro_use]
+/// # extern crate metrics;
+/// use float_duration::{FloatDuration, TimeUnit};
+///
+/// # fn main() {
+/// let latency = FloatDuration::milliseconds(42.0);
+/// latency.record_histogram(&histogram!("request.latency_ms"), TimeUnit::Millisecond);
+/// latency.set_gauge(&gauge!("request.last_latency_seconds"), TimeUnit::Second);
+/// # }
+/// ```
+impl FloatDuration {
+    /// Record the duration into `histogram`, as a number of `unit`.
+    #[inline]
+    pub fn record_histogram(&self, histogram: &Histogram, unit: TimeUnit) {
+        histogram.record(self.in_unit(unit));
+    }
+    /// Set `gauge` to the duration, as a number of `unit`.
+    #[inline]
+    pub fn set_gauge(&self, gauge: &Gauge, unit: TimeUnit) {
+        gauge.set(self.in_unit(unit));
+    }
+}
+
+/// A guard recording the time elapsed since its creation into a histogram when it
+/// is dropped.
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # #[macro_use]
+/// # extern crate metrics;
+/// use float_duration::{HistogramTimer, TimeUnit};
+///
+/// # fn main() {
+/// {
+///     let _timer = HistogramTimer::new(histogram!("db.query_seconds"), TimeUnit::Second);
+///     // Run the query...
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HistogramTimer {
+    histogram: Histogram,
+    unit: TimeUnit,
+    start: Instant,
+}
+
+impl HistogramTimer {
+    /// Start timing, to record the elapsed time into `histogram` as a number of
+    /// `unit`.
+    pub fn new(histogram: Histogram, unit: TimeUnit) -> HistogramTimer {
+        HistogramTimer {
+            histogram,
+            unit,
+            start: Instant::now(),
+        }
+    }
+
+    /// Return the unit the elapsed time is recorded in.
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+    /// Return the time elapsed since the timer was created.
+    pub fn elapsed(&self) -> FloatDuration {
+        instant_elapsed(self.start)
+    }
+}
+
+impl Drop for HistogramTimer {
+    fn drop(&mut self) {
+        self.elapsed().record_histogram(&self.histogram, self.unit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use metrics::{GaugeFn, HistogramFn};
+
+    #[derive(Default)]
+    struct Values(Mutex<Vec<f64>>);
+
+    impl HistogramFn for Values {
+        fn record(&self, value: f64) {
+            self.0.lock().unwrap().push(value);
+        }
+    }
+    impl GaugeFn for Values {
+        fn increment(&self, value: f64) {
+            self.0.lock().unwrap().push(value);
+        }
+        fn decrement(&self, value: f64) {
+            self.0.lock().unwrap().push(-value);
+        }
+        fn set(&self, value: f64) {
+            self.0.lock().unwrap().push(value);
+        }
+    }
+
+    #[test]
+    fn test_record_in_unit() {
+        let values = Arc::new(Values::default());
+        let histogram = Histogram::from_arc(values.clone());
+        let gauge = Gauge::from_arc(values.clone());
+        let d = FloatDuration::milliseconds(1500.0);
+        d.record_histogram(&histogram, TimeUnit::Millisecond);
+        d.record_histogram(&histogram, TimeUnit::Second);
+        d.set_gauge(&gauge, TimeUnit::Microsecond);
+        assert_eq!(*values.0.lock().unwrap(), vec![1500.0, 1.5, 1.5e6]);
+
+        assert_eq!(TimeUnit::Millisecond.to_metrics_unit(), Some(Unit::Milliseconds));
+        assert_eq!(TimeUnit::Hour.to_metrics_unit(), None);
+    }
+
+    #[test]
+    fn test_histogram_timer() {
+        let values = Arc::new(Values::default());
+        {
+            let _timer = HistogramTimer::new(Histogram::from_arc(values.clone()),
+                                             TimeUnit::Millisecond);
+            ::std::thread::sleep(::std::time::Duration::from_millis(5));
+        }
+        let recorded = values.0.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert!(recorded[0] >= 5.0);
+    }
+}
@@ -0,0 +1,44 @@
+//! Conversions between `FloatDuration` and the types of other time libraries, and
+//! implementations of traits from other crates.
+mod num_traits;
+#[cfg(feature = "time03")]
+mod time03;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "hifitime")]
+mod hifitime;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "humantime")]
+pub mod humantime;
+#[cfg(feature = "icu")]
+pub mod icu;
+#[cfg(any(feature = "postgres", feature = "diesel", feature = "sqlx"))]
+mod interval;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(feature = "tracing")]
+#[macro_use]
+pub mod tracing;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,93 @@
+//! Implementations of the `num-traits` traits that make sense for durations.
+//!
+//! Durations form an additive group but not a ring, since the product of two
+//! durations is not a duration, so `Num`, `One`, and `Signed` cannot be
+//! implemented.
+use num_traits::{Bounded, CheckedAdd, CheckedSub, ConstZero, Float, Zero};
+use num_traits::ops::saturating::{SaturatingAdd, SaturatingSub};
+
+use duration::GenericFloatDuration;
+
+impl<F: Float> Zero for GenericFloatDuration<F> {
+    #[inline]
+    fn zero() -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(F::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        GenericFloatDuration::is_zero(self)
+    }
+}
+
+impl<F: Float + ConstZero> ConstZero for GenericFloatDuration<F> {
+    const ZERO: GenericFloatDuration<F> = GenericFloatDuration::seconds(F::ZERO);
+}
+
+impl<F: Float> Bounded for GenericFloatDuration<F> {
+    #[inline]
+    fn min_value() -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(F::min_value())
+    }
+    #[inline]
+    fn max_value() -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(F::max_value())
+    }
+}
+
+impl<F: Float> CheckedAdd for GenericFloatDuration<F> {
+    #[inline]
+    fn checked_add(&self, rhs: &GenericFloatDuration<F>) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration::checked_add(*self, *rhs)
+    }
+}
+
+impl<F: Float> CheckedSub for GenericFloatDuration<F> {
+    #[inline]
+    fn checked_sub(&self, rhs: &GenericFloatDuration<F>) -> Option<GenericFloatDuration<F>> {
+        GenericFloatDuration::checked_sub(*self, *rhs)
+    }
+}
+
+impl<F: Float> SaturatingAdd for GenericFloatDuration<F> {
+    #[inline]
+    fn saturating_add(&self, rhs: &GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration::saturating_add(*self, *rhs)
+    }
+}
+
+impl<F: Float> SaturatingSub for GenericFloatDuration<F> {
+    #[inline]
+    fn saturating_sub(&self, rhs: &GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        GenericFloatDuration::saturating_sub(*self, *rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::{FloatDuration, FloatDuration32};
+
+    fn sum_generic<T: Zero + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |a, &b| a + b)
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(sum_generic(&[FloatDuration::seconds(1.0), FloatDuration::minutes(1.0)]),
+                   FloatDuration::seconds(61.0));
+        assert!(Zero::is_zero(&<FloatDuration32 as Zero>::zero()));
+        assert_eq!(<FloatDuration as ConstZero>::ZERO, FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_bounded_and_checked() {
+        assert_eq!(<FloatDuration as Bounded>::max_value(), FloatDuration::max_value());
+        assert_eq!(<FloatDuration as Bounded>::min_value(), FloatDuration::min_value());
+
+        let max = FloatDuration::max_value();
+        assert_eq!(CheckedAdd::checked_add(&max, &max), None);
+        assert_eq!(CheckedSub::checked_sub(&max, &max), Some(FloatDuration::zero()));
+        assert_eq!(SaturatingAdd::saturating_add(&max, &max), max);
+        assert_eq!(SaturatingSub::saturating_sub(&-max, &max), FloatDuration::min_value());
+    }
+}
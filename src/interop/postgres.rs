@@ -0,0 +1,127 @@
+//! Conversions between `FloatDuration` and PostgreSQL values with `postgres-types`.
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+use duration::FloatDuration;
+use super::interval;
+
+/// A `FloatDuration` is read from an `INTERVAL`, or from a `double precision` or
+/// `real` number of seconds.
+///
+/// The days and months of an interval are converted the same way as PostgreSQL's
+/// `EXTRACT(EPOCH FROM ...)`: a day is 24 hours, a month is 30 days and a year is
+/// 365.25 days.
+impl<'a> FromSql<'a> for FloatDuration {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<FloatDuration, Box<dyn Error + Sync + Send>> {
+        match *ty {
+            Type::INTERVAL => {
+                if raw.len() != 16 {
+                    return Err("invalid message length: interval size mismatch".into());
+                }
+                let mut micros = [0; 8];
+                let mut days = [0; 4];
+                let mut months = [0; 4];
+                micros.copy_from_slice(&raw[..8]);
+                days.copy_from_slice(&raw[8..12]);
+                months.copy_from_slice(&raw[12..]);
+                Ok(interval::from_parts(i64::from_be_bytes(micros),
+                                        i32::from_be_bytes(days),
+                                        i32::from_be_bytes(months)))
+            }
+            Type::FLOAT4 => f32::from_sql(ty, raw).map(|secs| FloatDuration::seconds(secs.into())),
+            _ => f64::from_sql(ty, raw).map(FloatDuration::seconds),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INTERVAL | Type::FLOAT8 | Type::FLOAT4)
+    }
+}
+
+/// A `FloatDuration` is written to an `INTERVAL`, or to a `double precision` or
+/// `real` number of seconds.
+///
+/// An interval is rounded to the nearest microsecond, the resolution of PostgreSQL,
+/// and is stored as a time without days or months, so that `1 day` is stored as
+/// `24:00:00`. Non-finite durations, and durations beyond about 292,000 years, cannot
+/// be written to an interval.
+impl ToSql for FloatDuration {
+    fn to_sql(&self,
+              ty: &Type,
+              out: &mut BytesMut)
+              -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match *ty {
+            Type::INTERVAL => {
+                out.put_i64(interval::to_micros(*self)?);
+                out.put_i32(0);
+                out.put_i32(0);
+                Ok(IsNull::No)
+            }
+            Type::FLOAT4 => (self.as_seconds() as f32).to_sql(ty, out),
+            _ => self.as_seconds().to_sql(ty, out),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <FloatDuration as FromSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    use duration::FloatDuration;
+
+    fn interval(micros: i64, days: i32, months: i32) -> Vec<u8> {
+        let mut raw = micros.to_be_bytes().to_vec();
+        raw.extend_from_slice(&days.to_be_bytes());
+        raw.extend_from_slice(&months.to_be_bytes());
+        raw
+    }
+
+    #[test]
+    fn test_interval() {
+        let mut out = BytesMut::new();
+        FloatDuration::days(1.0).to_sql(&Type::INTERVAL, &mut out).unwrap();
+        assert_eq!(&out[..], &interval(86_400_000_000, 0, 0)[..]);
+        assert_eq!(FloatDuration::from_sql(&Type::INTERVAL, &interval(500_000, 2, 14)).unwrap(),
+                   FloatDuration::days(2.0 + 365.25 + 60.0) + FloatDuration::seconds(0.5));
+        assert!(FloatDuration::seconds(f64::INFINITY)
+            .to_sql(&Type::INTERVAL, &mut BytesMut::new())
+            .is_err());
+
+        for &duration in &[FloatDuration::days(1.0),
+                           FloatDuration::seconds(-90.5),
+                           FloatDuration::microseconds(3.0),
+                           FloatDuration::zero()] {
+            let mut out = BytesMut::new();
+            duration.to_sql(&Type::INTERVAL, &mut out).unwrap();
+            assert_eq!(out.len(), 16);
+            assert_eq!(FloatDuration::from_sql(&Type::INTERVAL, &out).unwrap(), duration);
+        }
+        assert!(FloatDuration::from_sql(&Type::INTERVAL, &[0; 8]).is_err());
+    }
+
+    #[test]
+    fn test_float_seconds() {
+        let mut out = BytesMut::new();
+        FloatDuration::minutes(1.5).to_sql(&Type::FLOAT8, &mut out).unwrap();
+        assert_eq!(&out[..], &90.0f64.to_be_bytes()[..]);
+        assert_eq!(FloatDuration::from_sql(&Type::FLOAT8, &out).unwrap(),
+                   FloatDuration::minutes(1.5));
+
+        let mut out = BytesMut::new();
+        FloatDuration::seconds(0.25).to_sql(&Type::FLOAT4, &mut out).unwrap();
+        assert_eq!(FloatDuration::from_sql(&Type::FLOAT4, &out).unwrap(),
+                   FloatDuration::seconds(0.25));
+
+        assert!(<FloatDuration as ToSql>::accepts(&Type::INTERVAL));
+        assert!(!<FloatDuration as ToSql>::accepts(&Type::INT8));
+    }
+}
@@ -0,0 +1,95 @@
+//! Strategies for generating `FloatDuration` values with `proptest`.
+//!
+//! `FloatDuration` implements `Arbitrary`, generating any finite duration, so
+//! `any::<FloatDuration>()` can be used directly. The functions in this module
+//! return strategies for narrower ranges of durations.
+//!
+//! ```rust
+//! # extern crate proptest;
+//! # extern crate float_duration;
+//! use float_duration::{strategy, FloatDuration};
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//!
+//! # fn main() {
+//! let mut runner = TestRunner::default();
+//! let (min, max) = (FloatDuration::milliseconds(10.0), FloatDuration::seconds(5.0));
+//! let timeout = strategy::bounded(min, max).new_tree(&mut runner).unwrap().current();
+//! assert!(timeout >= min && timeout <= max);
+//! # }
+//! ```
+use proptest::arbitrary::Arbitrary;
+use proptest::num::f64;
+use proptest::strategy::{Map, Strategy};
+
+use duration::FloatDuration;
+
+/// The strategy returned by `finite` and used by the `Arbitrary` impl.
+pub type Finite = Map<f64::Any, fn(f64) -> FloatDuration>;
+
+/// Generate any finite duration, positive, negative, or zero.
+pub fn finite() -> Finite {
+    let values = f64::POSITIVE | f64::NEGATIVE | f64::NORMAL | f64::SUBNORMAL | f64::ZERO;
+    values.prop_map(FloatDuration::seconds)
+}
+
+/// Generate finite durations that are zero or positive.
+pub fn positive() -> impl Strategy<Value = FloatDuration> {
+    let values = f64::POSITIVE | f64::NORMAL | f64::SUBNORMAL | f64::ZERO;
+    values.prop_map(FloatDuration::seconds)
+}
+
+/// Generate durations from `min` to `max`, inclusive.
+///
+/// # Panics
+/// Panics if `min` is greater than `max`, or either is not finite.
+pub fn bounded(min: FloatDuration, max: FloatDuration) -> impl Strategy<Value = FloatDuration> {
+    assert!(min.as_seconds().is_finite() && max.as_seconds().is_finite(),
+            "bounds must be finite");
+    assert!(min <= max, "min must not be greater than max");
+    (min.as_seconds()..=max.as_seconds()).prop_map(FloatDuration::seconds)
+}
+
+impl Arbitrary for FloatDuration {
+    type Parameters = ();
+    type Strategy = Finite;
+
+    fn arbitrary_with(_: ()) -> Finite {
+        finite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::arbitrary::any;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_strategies() {
+        let mut runner = TestRunner::default();
+        runner.run(&any::<FloatDuration>(), |d| {
+                assert!(d.as_seconds().is_finite());
+                Ok(())
+            })
+            .unwrap();
+        runner.run(&positive(), |d| {
+                assert!(d.as_seconds().is_finite() && d.is_positive());
+                Ok(())
+            })
+            .unwrap();
+
+        let (min, max) = (FloatDuration::seconds(-1.0), FloatDuration::minutes(1.0));
+        runner.run(&bounded(min, max), |d| {
+                assert!(d >= min && d <= max);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bounded_reversed() {
+        let _ = bounded(FloatDuration::seconds(1.0), FloatDuration::zero());
+    }
+}
@@ -0,0 +1,91 @@
+//! Conversions between `FloatDuration` and Python's `datetime.timedelta` with `pyo3`.
+use core::convert::TryFrom;
+
+use pyo3::exceptions::PyOverflowError;
+use pyo3::types::{PyAnyMethods, PyDelta};
+use pyo3::{Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, Python};
+
+use duration::FloatDuration;
+
+const MICROS_PER_DAY: i128 = 86_400_000_000;
+
+/// A `FloatDuration` is extracted from a `datetime.timedelta`, or from an `int` or
+/// `float` number of seconds.
+impl FromPyObject<'_, '_> for FloatDuration {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> Result<FloatDuration, PyErr> {
+        if let Ok(delta) = obj.cast::<PyDelta>() {
+            delta.call_method0("total_seconds")?.extract().map(FloatDuration::seconds)
+        } else {
+            obj.extract().map(FloatDuration::seconds)
+        }
+    }
+}
+
+/// A `FloatDuration` is converted to a `datetime.timedelta`, rounded to the nearest
+/// microsecond.
+///
+/// Non-finite durations, and durations beyond the 999,999,999 days of a
+/// `timedelta`, raise an `OverflowError`. Use `as_seconds` to pass a duration as a
+/// `float` instead.
+impl<'py> IntoPyObject<'py> for FloatDuration {
+    type Target = PyDelta;
+    type Output = Bound<'py, PyDelta>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Bound<'py, PyDelta>, PyErr> {
+        let micros = self.as_microseconds().round();
+        if !micros.is_finite() || micros.abs() > 1e20 {
+            return Err(PyOverflowError::new_err("duration out of range for a timedelta"));
+        }
+        let micros = micros as i128;
+        let days = i32::try_from(micros.div_euclid(MICROS_PER_DAY))
+            .map_err(|_| PyOverflowError::new_err("duration out of range for a timedelta"))?;
+        let rest = micros.rem_euclid(MICROS_PER_DAY);
+        PyDelta::new(py, days, (rest / 1_000_000) as i32, (rest % 1_000_000) as i32, false)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &FloatDuration {
+    type Target = PyDelta;
+    type Output = Bound<'py, PyDelta>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Bound<'py, PyDelta>, PyErr> {
+        (*self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::{PyAnyMethods, PyDelta};
+    use pyo3::{IntoPyObject, Python};
+
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_timedelta() {
+        Python::initialize();
+        Python::attach(|py| {
+            let delta = PyDelta::new(py, -1, 3600, 500_000, false).unwrap();
+            assert_eq!(delta.extract::<FloatDuration>().unwrap(),
+                       FloatDuration::hours(-23.0) + FloatDuration::milliseconds(500.0));
+            assert_eq!(1.5f64.into_pyobject(py).unwrap().extract::<FloatDuration>().unwrap(),
+                       FloatDuration::seconds(1.5));
+            assert_eq!(90i64.into_pyobject(py).unwrap().extract::<FloatDuration>().unwrap(),
+                       FloatDuration::minutes(1.5));
+            assert!("1s".into_pyobject(py).unwrap().extract::<FloatDuration>().is_err());
+
+            let duration = FloatDuration::days(-1.5) + FloatDuration::microseconds(1.4);
+            let delta = duration.into_pyobject(py).unwrap();
+            assert_eq!(delta.str().unwrap().to_string(), "-2 days, 12:00:00.000001");
+            assert_eq!(delta.extract::<FloatDuration>().unwrap(),
+                       FloatDuration::days(-1.5) + FloatDuration::microseconds(1.0));
+
+            assert!(FloatDuration::seconds(f64::INFINITY).into_pyobject(py).is_err());
+            assert!(FloatDuration::days(1e9).into_pyobject(py).is_err());
+        });
+    }
+}
@@ -0,0 +1,92 @@
+//! Generation and shrinking of `FloatDuration` values with `quickcheck`.
+use quickcheck::{Arbitrary, Gen};
+
+use duration::FloatDuration;
+use unit::TimeUnit;
+
+/// The units durations are generated in.
+const UNITS: [TimeUnit; 7] = [TimeUnit::Nanosecond,
+                              TimeUnit::Microsecond,
+                              TimeUnit::Millisecond,
+                              TimeUnit::Second,
+                              TimeUnit::Minute,
+                              TimeUnit::Hour,
+                              TimeUnit::Day];
+
+/// Generates finite durations of any sign, mostly a number of nanoseconds to days
+/// below 32768 with a fraction, and sometimes zero or an extreme value.
+///
+/// Shrinking moves towards zero: a failing duration is first replaced by zero,
+/// then by its absolute value, by its whole number of seconds, and by half of
+/// itself.
+impl Arbitrary for FloatDuration {
+    fn arbitrary(g: &mut Gen) -> FloatDuration {
+        if u8::arbitrary(g) % 10 == 0 {
+            let extremes = [FloatDuration::zero(),
+                            FloatDuration::MIN,
+                            FloatDuration::MAX,
+                            FloatDuration::seconds(f64::MIN_POSITIVE),
+                            FloatDuration::seconds(-f64::MIN_POSITIVE)];
+            return *g.choose(&extremes).unwrap();
+        }
+        let unit = *g.choose(&UNITS).unwrap();
+        let value = f64::from(i16::arbitrary(g)) + f64::from(u8::arbitrary(g)) / 256.0;
+        FloatDuration::from_unit(value, unit)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = FloatDuration>> {
+        let current = *self;
+        let mut candidates = Vec::new();
+        for candidate in [FloatDuration::zero(),
+                          current.abs(),
+                          current.trunc_to(TimeUnit::Second),
+                          current / 2.0] {
+            if candidate.abs() <= current.abs() && candidate != current &&
+               !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+        Box::new(candidates.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::QuickCheck;
+
+    #[test]
+    fn test_arbitrary() {
+        fn finite(d: FloatDuration) -> bool {
+            d.as_seconds().is_finite()
+        }
+        QuickCheck::new().quickcheck(finite as fn(FloatDuration) -> bool);
+
+        let mut g = Gen::new(100);
+        for _ in 0..1000 {
+            let d = FloatDuration::arbitrary(&mut g);
+            assert!(d.shrink().all(|s| s.abs() <= d.abs() && s != d));
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let shrunk: Vec<_> = FloatDuration::seconds(-2.5).shrink().collect();
+        assert_eq!(shrunk,
+                   vec![FloatDuration::zero(),
+                        FloatDuration::seconds(2.5),
+                        FloatDuration::seconds(-2.0),
+                        FloatDuration::seconds(-1.25)]);
+        assert_eq!(FloatDuration::zero().shrink().count(), 0);
+
+        // A property failing above a second shrinks to a value close to a second.
+        fn short(d: FloatDuration) -> bool {
+            d.abs() < FloatDuration::seconds(1.0)
+        }
+        let mut d = FloatDuration::days(3.7);
+        while let Some(smaller) = d.shrink().find(|&s| !short(s)) {
+            d = smaller;
+        }
+        assert!(d >= FloatDuration::seconds(1.0) && d < FloatDuration::seconds(2.0));
+    }
+}
@@ -0,0 +1,304 @@
+//! Random durations with `rand`.
+//!
+//! Besides uniform durations, this module provides the distributions commonly used
+//! for the time between events in simulations: `Exponential` for the arrivals of a
+//! Poisson process, `Pareto` for heavy-tailed delays, and `Normal`.
+use core::f64::consts::PI;
+
+use num_traits::Float;
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::{Distribution, OpenClosed01};
+use rand::Rng;
+
+use duration::{FloatDuration, GenericFloatDuration};
+use frequency::Frequency;
+
+/// A uniform distribution of durations, used by `Uniform<FloatDuration>` and by
+/// `Rng::random_range`.
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # extern crate rand;
+/// use float_duration::FloatDuration;
+/// use rand::distr::{Distribution, Uniform};
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// # fn main() {
+/// let mut rng = SmallRng::seed_from_u64(1);
+/// let delay = rng.random_range(FloatDuration::seconds(1.0)..FloatDuration::seconds(5.0));
+/// assert!(delay >= FloatDuration::seconds(1.0) && delay < FloatDuration::seconds(5.0));
+///
+/// let spawn = Uniform::new_inclusive(FloatDuration::zero(), FloatDuration::minutes(1.0))
+///     .unwrap();
+/// assert!(spawn.sample(&mut rng) <= FloatDuration::minutes(1.0));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformDuration<F: SampleUniform> {
+    seconds: F::Sampler,
+}
+
+impl<F: Float + SampleUniform> SampleUniform for GenericFloatDuration<F> {
+    type Sampler = UniformDuration<F>;
+}
+
+impl<F: Float + SampleUniform> UniformSampler for UniformDuration<F> {
+    type X = GenericFloatDuration<F>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<UniformDuration<F>, Error>
+        where B1: SampleBorrow<GenericFloatDuration<F>> + Sized,
+              B2: SampleBorrow<GenericFloatDuration<F>> + Sized
+    {
+        let seconds = F::Sampler::new(low.borrow().as_seconds(), high.borrow().as_seconds())?;
+        Ok(UniformDuration { seconds })
+    }
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<UniformDuration<F>, Error>
+        where B1: SampleBorrow<GenericFloatDuration<F>> + Sized,
+              B2: SampleBorrow<GenericFloatDuration<F>> + Sized
+    {
+        let seconds = F::Sampler::new_inclusive(low.borrow().as_seconds(),
+                                                high.borrow().as_seconds())?;
+        Ok(UniformDuration { seconds })
+    }
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(self.seconds.sample(rng))
+    }
+}
+
+/// An exponential distribution of durations, the time between events that happen
+/// independently at a constant average rate.
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # extern crate rand;
+/// use float_duration::{FloatDuration, Frequency};
+/// use float_duration::distr::Exponential;
+/// use rand::rngs::SmallRng;
+/// use rand::SeedableRng;
+///
+/// # fn main() {
+/// let requests = Exponential::from_rate(Frequency::hertz(20.0));
+/// assert_eq!(requests.mean(), FloatDuration::milliseconds(50.0));
+///
+/// let arrivals: Vec<FloatDuration> =
+///     requests.arrivals(SmallRng::seed_from_u64(1)).take(100).collect();
+/// assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+    mean: FloatDuration,
+}
+
+impl Exponential {
+    /// Create an exponential distribution with the given mean duration.
+    ///
+    /// # Panics
+    /// This function panics if `mean` is not positive and finite.
+    pub fn new(mean: FloatDuration) -> Exponential {
+        assert!(mean > FloatDuration::zero() && mean.as_seconds().is_finite(),
+                "the mean of an exponential distribution must be positive and finite");
+        Exponential { mean }
+    }
+    /// Create an exponential distribution of the time between events that happen
+    /// `rate` times per second on average.
+    ///
+    /// # Panics
+    /// This function panics if `rate` is not positive and finite.
+    pub fn from_rate(rate: Frequency) -> Exponential {
+        Exponential::new(rate.period())
+    }
+
+    /// Return the mean duration.
+    #[inline]
+    pub fn mean(&self) -> FloatDuration {
+        self.mean
+    }
+    /// Return the average number of events per second.
+    #[inline]
+    pub fn rate(&self) -> Frequency {
+        self.mean.recip()
+    }
+    /// Return an iterator over the times of the events of a Poisson process, from a
+    /// start at zero.
+    pub fn arrivals<R: Rng>(self, rng: R) -> Arrivals<R> {
+        Arrivals {
+            distribution: self,
+            rng,
+            time: FloatDuration::zero(),
+        }
+    }
+}
+
+impl Distribution<FloatDuration> for Exponential {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FloatDuration {
+        let u: f64 = rng.sample(OpenClosed01);
+        self.mean * -Float::ln(u)
+    }
+}
+
+/// An iterator over the times of the events of a Poisson process.
+///
+/// This `struct` is created by `Exponential::arrivals`.
+#[derive(Debug, Clone)]
+pub struct Arrivals<R> {
+    distribution: Exponential,
+    rng: R,
+    time: FloatDuration,
+}
+
+impl<R: Rng> Iterator for Arrivals<R> {
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        self.time += self.distribution.sample(&mut self.rng);
+        Some(self.time)
+    }
+}
+
+/// A Pareto distribution of durations, with a minimum duration and a heavy tail of
+/// much longer durations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pareto {
+    scale: FloatDuration,
+    shape: f64,
+}
+
+impl Pareto {
+    /// Create a Pareto distribution of durations of at least `scale`.
+    ///
+    /// The lower the `shape`, the heavier the tail; the mean is infinite if `shape`
+    /// is 1 or less.
+    ///
+    /// # Panics
+    /// This function panics if `scale` or `shape` is not positive and finite.
+    pub fn new(scale: FloatDuration, shape: f64) -> Pareto {
+        assert!(scale > FloatDuration::zero() && scale.as_seconds().is_finite(),
+                "the scale of a Pareto distribution must be positive and finite");
+        assert!(shape > 0.0 && shape.is_finite(),
+                "the shape of a Pareto distribution must be positive and finite");
+        Pareto { scale, shape }
+    }
+}
+
+impl Distribution<FloatDuration> for Pareto {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FloatDuration {
+        let u: f64 = rng.sample(OpenClosed01);
+        self.scale * Float::powf(u, -1.0 / self.shape)
+    }
+}
+
+/// A normal distribution of durations.
+///
+/// Unlike the other distributions, samples may be negative if the standard
+/// deviation is not small compared to the mean.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: FloatDuration,
+    std_dev: FloatDuration,
+}
+
+impl Normal {
+    /// Create a normal distribution from a mean and a standard deviation.
+    ///
+    /// # Panics
+    /// This function panics if `mean` is not finite, or `std_dev` is negative or not
+    /// finite.
+    pub fn new(mean: FloatDuration, std_dev: FloatDuration) -> Normal {
+        assert!(mean.as_seconds().is_finite(),
+                "the mean of a normal distribution must be finite");
+        assert!(std_dev >= FloatDuration::zero() && std_dev.as_seconds().is_finite(),
+                "the standard deviation of a normal distribution must be non-negative and finite");
+        Normal { mean, std_dev }
+    }
+}
+
+impl Distribution<FloatDuration> for Normal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FloatDuration {
+        // Box-Muller transform, using one of the two values it produces.
+        let u: f64 = rng.sample(OpenClosed01);
+        let v: f64 = rng.random();
+        let z = Float::sqrt(-2.0 * Float::ln(u)) * Float::cos(2.0 * PI * v);
+        self.mean + self.std_dev * z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distr::{Distribution, Uniform};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use duration::{FloatDuration, FloatDuration32};
+    use frequency::Frequency;
+    use super::{Exponential, Normal, Pareto};
+
+    /// Return the mean and standard deviation of `count` samples, in seconds.
+    fn stats<D: Distribution<FloatDuration>>(distribution: D, count: usize) -> (f64, f64) {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let samples: Vec<f64> =
+            distribution.sample_iter(&mut rng).take(count).map(|d| d.as_seconds()).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count as f64;
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn test_uniform() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let low = FloatDuration::milliseconds(100.0);
+        let high = FloatDuration::milliseconds(200.0);
+        for _ in 0..1000 {
+            let d = rng.random_range(low..high);
+            assert!(d >= low && d < high);
+            let d = rng.random_range(low..=low);
+            assert_eq!(d, low);
+        }
+
+        let uniform = Uniform::new(FloatDuration32::seconds(-1.0), FloatDuration32::seconds(1.0))
+            .unwrap();
+        let sum = uniform.sample_iter(&mut rng).take(10_000).map(|d| d.as_seconds()).sum::<f32>();
+        assert!((sum / 10_000.0).abs() < 0.05);
+
+        assert!(Uniform::new(high, low).is_err());
+        assert!(Uniform::new(low, FloatDuration::seconds(f64::INFINITY)).is_err());
+    }
+
+    #[test]
+    fn test_exponential() {
+        let exponential = Exponential::new(FloatDuration::seconds(2.0));
+        assert_eq!(exponential.rate(), Frequency::hertz(0.5));
+        let (mean, std_dev) = stats(exponential, 100_000);
+        assert!((mean - 2.0).abs() < 0.05);
+        assert!((std_dev - 2.0).abs() < 0.05);
+
+        let arrivals = Exponential::from_rate(Frequency::hertz(10.0))
+            .arrivals(SmallRng::seed_from_u64(3))
+            .take_while(|&t| t < FloatDuration::seconds(1000.0))
+            .count();
+        assert!(arrivals > 9_700 && arrivals < 10_300);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponential_invalid() {
+        let _ = Exponential::new(FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_pareto_and_normal() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        let pareto = Pareto::new(FloatDuration::milliseconds(10.0), 3.0);
+        let minimum = FloatDuration::milliseconds(10.0);
+        assert!(pareto.sample_iter(&mut rng).take(1000).all(|d| d >= minimum));
+        let (mean, _) = stats(pareto, 100_000);
+        assert!((mean - 0.015).abs() < 0.001);
+
+        let (mean, std_dev) = stats(Normal::new(FloatDuration::seconds(5.0),
+                                                FloatDuration::seconds(0.5)),
+                                    100_000);
+        assert!((mean - 5.0).abs() < 0.01);
+        assert!((std_dev - 0.5).abs() < 0.01);
+    }
+}
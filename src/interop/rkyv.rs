@@ -0,0 +1,78 @@
+//! Zero-copy access to archived `FloatDuration` values with `rkyv`.
+use core::cmp::Ordering;
+use core::fmt;
+
+use rkyv::rancor::Panic;
+
+use duration::{ArchivedGenericFloatDuration, GenericFloatDuration};
+
+macro_rules! impl_archived {
+    ($float:ty) => {
+        impl ArchivedGenericFloatDuration<$float> {
+            /// Return the archived duration as a native `GenericFloatDuration`.
+            #[inline]
+            pub fn to_native(&self) -> GenericFloatDuration<$float> {
+                match ::rkyv::deserialize::<_, Panic>(self) {
+                    Ok(duration) => duration,
+                    Err(never) => match never {},
+                }
+            }
+        }
+
+        impl<'a> From<&'a ArchivedGenericFloatDuration<$float>> for GenericFloatDuration<$float> {
+            #[inline]
+            fn from(archived: &'a ArchivedGenericFloatDuration<$float>) -> Self {
+                archived.to_native()
+            }
+        }
+
+        impl PartialEq<GenericFloatDuration<$float>> for ArchivedGenericFloatDuration<$float> {
+            #[inline]
+            fn eq(&self, other: &GenericFloatDuration<$float>) -> bool {
+                self.to_native() == *other
+            }
+        }
+
+        impl PartialOrd<GenericFloatDuration<$float>> for ArchivedGenericFloatDuration<$float> {
+            #[inline]
+            fn partial_cmp(&self, other: &GenericFloatDuration<$float>) -> Option<Ordering> {
+                self.to_native().partial_cmp(other)
+            }
+        }
+
+        impl fmt::Debug for ArchivedGenericFloatDuration<$float> {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_native(), fmt)
+            }
+        }
+    }
+}
+
+impl_archived!(f32);
+impl_archived!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use duration::{ArchivedGenericFloatDuration, FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_archive_round_trip() {
+        let duration = FloatDuration::minutes(1.5);
+        let bytes = ::rkyv::to_bytes::<Error>(&duration).unwrap();
+        let archived = ::rkyv::access::<ArchivedGenericFloatDuration<f64>, Error>(&bytes)
+            .unwrap();
+        assert_eq!(*archived, duration);
+        assert_eq!(archived.to_native(), duration);
+        assert_eq!(FloatDuration::from(archived), duration);
+        assert!(*archived < FloatDuration::minutes(2.0));
+        assert_eq!(::rkyv::from_bytes::<FloatDuration, Error>(&bytes).unwrap(), duration);
+
+        let duration = FloatDuration32::milliseconds(-250.0);
+        let bytes = ::rkyv::to_bytes::<Error>(&duration).unwrap();
+        let archived = ::rkyv::access::<ArchivedGenericFloatDuration<f32>, Error>(&bytes)
+            .unwrap();
+        assert_eq!(format!("{:?}", archived), format!("{:?}", duration));
+    }
+}
@@ -0,0 +1,53 @@
+//! JSON schemas for `FloatDuration` with `schemars`.
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use duration::GenericFloatDuration;
+
+/// The schema of the default `serde` representation: a number of seconds.
+///
+/// The `serde` module has a `json_schema` function for each of its alternative
+/// representations, to be used with `#[schemars(schema_with = "...")]`.
+impl<F: JsonSchema> JsonSchema for GenericFloatDuration<F> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("FloatDuration_{}", F::schema_name()).into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        format!("float_duration::GenericFloatDuration<{}>", F::schema_id()).into()
+    }
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = F::json_schema(generator);
+        schema.insert("description".into(), "A duration in seconds.".into());
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{json_schema, schema_for};
+
+    use duration::{FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_schema() {
+        let mut expected = json_schema!({
+            "type": "number",
+            "format": "double",
+            "description": "A duration in seconds."
+        });
+        let mut schema = schema_for!(FloatDuration);
+        schema.remove("$schema");
+        schema.remove("title");
+        assert_eq!(schema, expected);
+
+        expected.insert("format".into(), "float".into());
+        let mut schema = schema_for!(FloatDuration32);
+        schema.remove("$schema");
+        schema.remove("title");
+        assert_eq!(schema, expected);
+    }
+}
@@ -0,0 +1,118 @@
+//! `sqlx` encoding and decoding of `FloatDuration` for PostgreSQL, MySQL and SQLite.
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::mysql::MySql;
+use sqlx::postgres::types::PgInterval;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use sqlx::sqlite::Sqlite;
+use sqlx::{Database, Decode, Encode, Type, ValueRef};
+
+use duration::FloatDuration;
+use super::interval;
+
+/// In PostgreSQL, a `FloatDuration` is an `INTERVAL`, and can also be read from a
+/// `double precision` or `real` number of seconds.
+///
+/// Intervals are converted the same way as with the `postgres` feature.
+impl Type<Postgres> for FloatDuration {
+    fn type_info() -> PgTypeInfo {
+        <PgInterval as Type<Postgres>>::type_info()
+    }
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <PgInterval as Type<Postgres>>::compatible(ty) ||
+        <f64 as Type<Postgres>>::compatible(ty) || <f32 as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for FloatDuration {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let interval = PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: interval::to_micros(*self)?,
+        };
+        Encode::<Postgres>::encode_by_ref(&interval, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for FloatDuration {
+    fn decode(value: PgValueRef<'r>) -> Result<FloatDuration, BoxDynError> {
+        let ty = value.type_info().into_owned();
+        if <f64 as Type<Postgres>>::compatible(&ty) {
+            <f64 as Decode<Postgres>>::decode(value).map(FloatDuration::seconds)
+        } else if <f32 as Type<Postgres>>::compatible(&ty) {
+            <f32 as Decode<Postgres>>::decode(value).map(|secs| FloatDuration::seconds(secs.into()))
+        } else {
+            let interval = <PgInterval as Decode<Postgres>>::decode(value)?;
+            Ok(interval::from_parts(interval.microseconds, interval.days, interval.months))
+        }
+    }
+}
+
+/// Implement the `sqlx` traits for a database, storing a number of seconds as a
+/// double.
+macro_rules! impl_float_seconds {
+    ($db:ty) => {
+        impl Type<$db> for FloatDuration {
+            fn type_info() -> <$db as Database>::TypeInfo {
+                <f64 as Type<$db>>::type_info()
+            }
+            fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                <f64 as Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'q> Encode<'q, $db> for FloatDuration {
+            fn encode_by_ref(&self,
+                             buf: &mut <$db as Database>::ArgumentBuffer<'q>)
+                             -> Result<IsNull, BoxDynError> {
+                Encode::<$db>::encode_by_ref(&self.as_seconds(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, $db> for FloatDuration {
+            fn decode(value: <$db as Database>::ValueRef<'r>)
+                      -> Result<FloatDuration, BoxDynError> {
+                <f64 as Decode<$db>>::decode(value).map(FloatDuration::seconds)
+            }
+        }
+    }
+}
+
+// MySQL has no interval type, and its `TIME` is limited to 838 hours.
+impl_float_seconds!(MySql);
+impl_float_seconds!(Sqlite);
+
+#[cfg(test)]
+mod tests {
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+    use sqlx::sqlite::{Sqlite, SqliteArgumentValue};
+    use sqlx::encode::IsNull;
+    use sqlx::{Encode, Type};
+
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_postgres() {
+        let mut buf = PgArgumentBuffer::default();
+        let encoded = Encode::<Postgres>::encode_by_ref(&FloatDuration::seconds(1.5), &mut buf);
+        assert!(matches!(encoded, Ok(IsNull::No)));
+        assert_eq!(&buf[..], &[0, 0, 0, 0, 0, 0x16, 0xe3, 0x60, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(Encode::<Postgres>::encode_by_ref(&FloatDuration::seconds(f64::NAN), &mut buf)
+            .is_err());
+
+        assert!(<FloatDuration as Type<Postgres>>::compatible(&PgTypeInfo::with_name("float8")));
+        assert!(!<FloatDuration as Type<Postgres>>::compatible(&PgTypeInfo::with_name("int8")));
+    }
+
+    #[test]
+    fn test_sqlite() {
+        let mut args = Vec::new();
+        let encoded = Encode::<Sqlite>::encode_by_ref(&FloatDuration::minutes(1.5), &mut args);
+        assert!(matches!(encoded, Ok(IsNull::No)));
+        match args[0] {
+            SqliteArgumentValue::Double(secs) => assert_eq!(secs, 90.0),
+            ref other => panic!("unexpected argument {:?}", other),
+        }
+    }
+}
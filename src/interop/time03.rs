@@ -0,0 +1,141 @@
+//! Interoperability with version 0.3 of the `time` crate.
+use num_traits::{Float, ToPrimitive};
+use time03 as time;
+use time03::{OffsetDateTime, PrimitiveDateTime};
+
+use duration::{float, FloatDuration, FromDuration, GenericFloatDuration, TimePoint,
+               NANOS_PER_SEC};
+use error::OutOfRangeError;
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Create a `time::Duration` object from a `FloatDuration`.
+    ///
+    /// The sub-nanosecond part of the duration is truncated.
+    ///
+    /// # Errors
+    /// `time::Duration` cannot represent non-finite values or more than
+    /// `i64::MAX` seconds. This function will return an `OutOfRangeError` if the
+    /// `FloatDuration` value is outside of those bounds.
+    ///
+    /// ```rust
+    /// # extern crate time03 as time;
+    /// # extern crate float_duration;
+    /// use float_duration::FloatDuration;
+    ///
+    /// # fn main() {
+    /// assert_eq!(FloatDuration::seconds(-1.5).to_time(),
+    ///            Ok(time::Duration::milliseconds(-1500)));
+    /// # }
+    /// ```
+    pub fn to_time(&self) -> Result<time::Duration, OutOfRangeError> {
+        let secs = self.as_seconds().to_f64().ok_or_else(OutOfRangeError::new)?;
+        let seconds = secs.trunc().to_i64().ok_or_else(OutOfRangeError::new)?;
+        let nanos = secs.fract() * NANOS_PER_SEC;
+        Ok(time::Duration::new(seconds, nanos as i32))
+    }
+
+    /// Create a `FloatDuration` object from a `time::Duration`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_time(duration: time::Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(float(duration.whole_seconds() as f64 +
+                                            duration.subsec_nanoseconds() as f64 /
+                                            NANOS_PER_SEC))
+    }
+}
+
+impl TimePoint for OffsetDateTime {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: OffsetDateTime) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_time(self - since))
+    }
+}
+impl TimePoint for PrimitiveDateTime {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: PrimitiveDateTime) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_time(self - since))
+    }
+}
+// `time::Instant` is deprecated in favor of `std::time::Instant`, but is still
+// supported for code that has not migrated yet.
+#[cfg(feature = "std")]
+#[allow(deprecated)]
+impl TimePoint for time::Instant {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: time::Instant) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::from_time(self - since))
+    }
+}
+
+impl<F: Float> FromDuration<time::Duration> for GenericFloatDuration<F> {
+    type Error = ();
+    #[inline]
+    fn from_duration(from: time::Duration) -> Result<GenericFloatDuration<F>, ()> {
+        Ok(GenericFloatDuration::from_time(from))
+    }
+}
+impl<F: Float> FromDuration<GenericFloatDuration<F>> for time::Duration {
+    type Error = OutOfRangeError;
+    #[inline]
+    fn from_duration(from: GenericFloatDuration<F>) -> Result<time::Duration, OutOfRangeError> {
+        from.to_time()
+    }
+}
+impl<F: Float> From<time::Duration> for GenericFloatDuration<F> {
+    #[inline]
+    fn from(from: time::Duration) -> GenericFloatDuration<F> {
+        GenericFloatDuration::from_time(from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time03::{Date, Month};
+    use duration::FloatDuration32;
+
+    fn datetime(day: u8, hour: u8, minute: u8, second: u8, milli: u16) -> PrimitiveDateTime {
+        Date::from_calendar_date(2017, Month::May, day)
+            .unwrap()
+            .with_hms_milli(hour, minute, second, milli)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_time_conversion() {
+        assert_eq!(FloatDuration::minutes(2.5).to_time(),
+                   Ok(time::Duration::seconds(150)));
+        assert_eq!(FloatDuration::seconds(-0.25).to_time(),
+                   Ok(time::Duration::milliseconds(-250)));
+        assert_eq!(FloatDuration::nanoseconds(1.0).to_time(),
+                   Ok(time::Duration::nanoseconds(1)));
+        assert!(FloatDuration::years(1e12).to_time().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_time().is_err());
+        assert!(FloatDuration::seconds(f64::INFINITY).to_time().is_err());
+
+        assert_eq!(FloatDuration::from_time(time::Duration::new(90, 500_000_000)),
+                   FloatDuration::seconds(90.5));
+        assert_eq!(FloatDuration::from(time::Duration::milliseconds(-1500)),
+                   FloatDuration::seconds(-1.5));
+        assert_eq!(FloatDuration32::from_time(time::Duration::hours(2)),
+                   FloatDuration32::hours(2.0));
+    }
+
+    #[test]
+    fn test_time_point() {
+        let start = datetime(25, 10, 0, 0, 0).assume_utc();
+        let end = datetime(26, 12, 0, 0, 500).assume_utc();
+        assert_eq!(end.float_duration_since(start),
+                   Ok(FloatDuration::hours(26.0) + FloatDuration::milliseconds(500.0)));
+        assert_eq!(start.float_duration_since(end),
+                   Ok(-FloatDuration::hours(26.0) - FloatDuration::milliseconds(500.0)));
+
+        let start = datetime(25, 10, 0, 0, 0);
+        let end = datetime(25, 10, 1, 30, 0);
+        assert_eq!(end.float_duration_since(start), Ok(FloatDuration::seconds(90.0)));
+    }
+}
@@ -0,0 +1,85 @@
+//! Sleeping and timeouts on the `tokio` runtime.
+use core::future::Future;
+
+use tokio::time::{self, Sleep, Timeout};
+
+use duration::FloatDuration;
+
+/// Wait until `duration` has elapsed on the `tokio` timer.
+///
+/// Unlike converting with `to_std`, this never fails: negative, zero and NaN
+/// durations complete immediately, and durations too large to represent, such as
+/// infinity, wait indefinitely. See `FloatDuration::to_std_saturating`.
+///
+/// # Panics
+/// Like `tokio::time::sleep`, the returned future panics if polled outside of a
+/// `tokio` runtime with the timer enabled.
+///
+/// ```rust,no_run,edition2018
+/// # extern crate tokio;
+/// # extern crate float_duration;
+/// use float_duration::{FloatDuration, sleep_for};
+///
+/// # fn main() {
+/// # let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+/// # runtime.block_on(async {
+/// let backoff = FloatDuration::milliseconds(125.0) * 1.5;
+/// sleep_for(backoff).await;
+/// # });
+/// # }
+/// ```
+pub fn sleep_for(duration: FloatDuration) -> Sleep {
+    time::sleep(duration.to_std_saturating())
+}
+
+/// Require `future` to complete within `duration` on the `tokio` timer.
+///
+/// The returned future resolves to `Err(Elapsed)` if the timeout expires first.
+/// Durations are converted as with `sleep_for`, so negative, zero and NaN
+/// timeouts only let `future` complete if it is immediately ready.
+pub fn timeout_for<Fut: Future>(duration: FloatDuration, future: Fut) -> Timeout<Fut> {
+    time::timeout(duration.to_std_saturating(), future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::future;
+    use tokio::runtime::{Builder, Runtime};
+    use std::time::Instant;
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().enable_time().build().unwrap()
+    }
+
+    #[test]
+    fn test_sleep_for() {
+        let runtime = runtime();
+        let _guard = runtime.enter();
+        let start = Instant::now();
+        runtime.block_on(sleep_for(FloatDuration::milliseconds(5.0)));
+        assert!(start.elapsed().as_millis() >= 5);
+
+        runtime.block_on(sleep_for(FloatDuration::seconds(-1.0)));
+        runtime.block_on(sleep_for(FloatDuration::seconds(f64::NAN)));
+        assert!(start.elapsed().as_secs() < 1);
+    }
+
+    #[test]
+    fn test_timeout_for() {
+        let runtime = runtime();
+        let _guard = runtime.enter();
+        assert_eq!(runtime.block_on(timeout_for(FloatDuration::seconds(1.0),
+                                                 future::ready(3))).ok(),
+                   Some(3));
+        assert!(runtime.block_on(timeout_for(FloatDuration::milliseconds(1.0),
+                                              future::pending::<()>()))
+                    .is_err());
+        assert!(runtime.block_on(timeout_for(FloatDuration::seconds(-1.0),
+                                              future::pending::<()>()))
+                    .is_err());
+        assert_eq!(runtime.block_on(timeout_for(FloatDuration::seconds(f64::INFINITY),
+                                                 future::ready(()))).ok(),
+                   Some(()));
+    }
+}
@@ -0,0 +1,191 @@
+//! Recording durations in `tracing` spans and events.
+use std::time::Instant;
+
+use tracing::Span;
+use tracing::field::{self, DisplayValue};
+use tracing::span::Entered;
+
+use duration::{instant_elapsed, FloatDuration};
+
+/// `tracing::Value` is sealed, so it cannot be implemented for `FloatDuration`.
+/// These methods return values that record a duration in a span or event field:
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # #[macro_use]
+/// # extern crate tracing;
+/// use float_duration::FloatDuration;
+///
+/// # fn main() {
+/// let timeout = FloatDuration::milliseconds(250.0);
+/// info!(timeout = timeout.as_seconds_value(), "request started");
+/// info!(timeout = timeout.as_display_value(), "request started");
+/// # }
+/// ```
+impl FloatDuration {
+    /// Return a field value recording the duration as a number of seconds.
+    #[inline]
+    pub fn as_seconds_value(&self) -> f64 {
+        self.as_seconds()
+    }
+    /// Return a field value recording the duration as a string, such as
+    /// `"250 milliseconds"`.
+    #[inline]
+    pub fn as_display_value(&self) -> DisplayValue<FloatDuration> {
+        field::display(*self)
+    }
+}
+
+/// A guard recording the time elapsed since its creation into a field of a span
+/// when it is dropped.
+///
+/// The field must have been declared when creating the span, usually as
+/// `tracing::field::Empty`. It is recorded as a number of seconds. The
+/// [`span_timer!`](../macro.span_timer.html) macro creates a span with an `elapsed`
+/// field and its timer at once.
+///
+/// ```rust
+/// # extern crate float_duration;
+/// # #[macro_use]
+/// # extern crate tracing;
+/// use float_duration::SpanTimer;
+///
+/// # fn main() {
+/// let span = info_span!("query", elapsed = tracing::field::Empty);
+/// {
+///     let timer = SpanTimer::new(span, "elapsed");
+///     let _entered = timer.enter();
+///     // Run the query...
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SpanTimer {
+    span: Span,
+    field: &'static str,
+    start: Instant,
+}
+
+impl SpanTimer {
+    /// Start timing, to record the elapsed time into `field` of `span`.
+    pub fn new(span: Span, field: &'static str) -> SpanTimer {
+        SpanTimer {
+            span,
+            field,
+            start: Instant::now(),
+        }
+    }
+
+    /// Return the span the elapsed time is recorded into.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+    /// Enter the span, returning a guard that exits it when dropped.
+    pub fn enter(&self) -> Entered<'_> {
+        self.span.enter()
+    }
+    /// Return the time elapsed since the timer was created.
+    pub fn elapsed(&self) -> FloatDuration {
+        instant_elapsed(self.start)
+    }
+}
+
+impl Drop for SpanTimer {
+    fn drop(&mut self) {
+        self.span.record(self.field, self.elapsed().as_seconds());
+    }
+}
+
+/// Create a span with an `elapsed` field, and a `SpanTimer` recording into it.
+///
+/// The arguments are a level, a span name and optionally more fields, as for
+/// `tracing::span!`. The calling crate must depend on `tracing`.
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate float_duration;
+/// # extern crate tracing;
+/// use tracing::Level;
+///
+/// # fn main() {
+/// let timer = span_timer!(Level::INFO, "compile", file = "main.rs");
+/// let _entered = timer.enter();
+/// // Compile the file...
+/// # }
+/// ```
+#[macro_export]
+macro_rules! span_timer {
+    ($level:expr, $name:expr) => {
+        $crate::SpanTimer::new(::tracing::span!($level, $name, elapsed = ::tracing::field::Empty),
+                               "elapsed")
+    };
+    ($level:expr, $name:expr, $($fields:tt)+) => {
+        $crate::SpanTimer::new(::tracing::span!($level, $name,
+                                                elapsed = ::tracing::field::Empty,
+                                                $($fields)+),
+                               "elapsed")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::{Event, Id, Level, Metadata, Subscriber};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Record};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, f64)>>>);
+
+    impl Visit for Recorder {
+        fn record_f64(&mut self, field: &Field, value: f64) {
+            self.0.lock().unwrap().push((field.name().to_string(), value));
+        }
+        fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+        fn new_span(&self, _span: &Attributes) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _span: &Id, values: &Record) {
+            values.record(&mut self.clone());
+        }
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, event: &Event) {
+            event.record(&mut self.clone());
+        }
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_span_timer() {
+        let recorder = Recorder::default();
+        ::tracing::subscriber::with_default(recorder.clone(), || {
+            let timer = span_timer!(Level::INFO, "work", step = 1);
+            let _entered = timer.enter();
+            ::std::thread::sleep(::std::time::Duration::from_millis(5));
+        });
+        let recorded = recorder.0.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].0, "elapsed");
+        assert!(recorded[0].1 >= 0.005);
+    }
+
+    #[test]
+    fn test_field_values() {
+        let recorder = Recorder::default();
+        ::tracing::subscriber::with_default(recorder.clone(), || {
+            let timeout = FloatDuration::milliseconds(250.0);
+            ::tracing::info!(timeout = timeout.as_seconds_value());
+            assert_eq!(timeout.as_display_value().to_string(), timeout.to_string());
+        });
+        assert_eq!(*recorder.0.lock().unwrap(), vec![("timeout".to_string(), 0.25)]);
+    }
+}
@@ -0,0 +1,76 @@
+//! Browser and JavaScript timing with `wasm-bindgen` and `js-sys`.
+//!
+//! JavaScript measures time in floating-point milliseconds, both for
+//! `performance.now()` and for `Date`, so these values convert to a `FloatDuration`
+//! without loss.
+use js_sys::{Date, Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+use duration::{FloatDuration, TimePoint};
+
+impl FloatDuration {
+    /// Create a `FloatDuration` from a number of JavaScript milliseconds.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_js_millis(1500.0), FloatDuration::seconds(1.5));
+    /// ```
+    #[inline]
+    pub fn from_js_millis(millis: f64) -> FloatDuration {
+        FloatDuration::milliseconds(millis)
+    }
+    /// Return the duration as a number of JavaScript milliseconds, such as for
+    /// `setTimeout`.
+    #[inline]
+    pub fn to_js_millis(&self) -> f64 {
+        self.as_milliseconds()
+    }
+    /// Return the amount of time from the `start` date to the `end` date.
+    ///
+    /// Equivalent to `FloatDuration::from_js_millis(end.getTime() - start.getTime())`.
+    #[inline]
+    pub fn between_dates(start: &Date, end: &Date) -> FloatDuration {
+        FloatDuration::from_js_millis(end.get_time() - start.get_time())
+    }
+}
+
+impl TimePoint for Date {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: Date) -> Result<FloatDuration, ()> {
+        Ok(FloatDuration::between_dates(&since, &self))
+    }
+}
+
+impl From<FloatDuration> for JsValue {
+    /// Convert to a JavaScript number of milliseconds.
+    #[inline]
+    fn from(duration: FloatDuration) -> JsValue {
+        JsValue::from_f64(duration.to_js_millis())
+    }
+}
+
+/// Return the value of `performance.now()`: the high-resolution time since the time
+/// origin of the page or worker.
+///
+/// The difference of two values gives the time elapsed between them. Returns `None`
+/// if the global object has no `performance.now` function.
+pub fn performance_now() -> Option<FloatDuration> {
+    let performance = Reflect::get(&js_sys::global(), &JsValue::from_str("performance")).ok()?;
+    let now = Reflect::get(&performance, &JsValue::from_str("now")).ok()?;
+    let millis = now.dyn_into::<Function>().ok()?.call0(&performance).ok()?.as_f64()?;
+    Some(FloatDuration::from_js_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_js_millis() {
+        assert_eq!(FloatDuration::from_js_millis(0.125), FloatDuration::microseconds(125.0));
+        assert_eq!(FloatDuration::minutes(1.5).to_js_millis(), 90_000.0);
+        assert_eq!(FloatDuration::from_js_millis(-16.5).to_js_millis(), -16.5);
+    }
+}
@@ -0,0 +1,169 @@
+//! Intervals of time on a `FloatInstant` timeline.
+use duration::FloatDuration;
+use instant::FloatInstant;
+
+/// A half-open interval of time, from an inclusive start to an exclusive end.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FloatInstant};
+/// use float_duration::interval::TimeInterval;
+///
+/// let at = |secs| FloatInstant::from_epoch(FloatDuration::seconds(secs));
+/// let clip = TimeInterval::new(at(1.0), at(4.0));
+/// let window = TimeInterval::with_duration(at(3.0), FloatDuration::seconds(2.0));
+///
+/// assert!(clip.contains(at(1.0)));
+/// assert!(!clip.contains(at(4.0)));
+/// assert!(clip.overlaps(&window));
+/// assert_eq!(clip.intersection(&window), Some(TimeInterval::new(at(3.0), at(4.0))));
+/// assert_eq!(clip.duration(), FloatDuration::seconds(3.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeInterval {
+    start: FloatInstant,
+    end: FloatInstant,
+}
+
+impl TimeInterval {
+    /// Create an interval from `start` up to `end`.
+    ///
+    /// # Panics
+    /// This function panics if `end` is before `start`, or if either is NaN.
+    pub fn new(start: FloatInstant, end: FloatInstant) -> TimeInterval {
+        assert!(start <= end, "the end of an interval must not be before its start");
+        TimeInterval { start, end }
+    }
+    /// Create an interval from `start` lasting for `duration`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is negative or NaN.
+    pub fn with_duration(start: FloatInstant, duration: FloatDuration) -> TimeInterval {
+        TimeInterval::new(start, start + duration)
+    }
+
+    /// The inclusive start of the interval.
+    pub fn start(&self) -> FloatInstant {
+        self.start
+    }
+    /// The exclusive end of the interval.
+    pub fn end(&self) -> FloatInstant {
+        self.end
+    }
+    /// The length of the interval.
+    pub fn duration(&self) -> FloatDuration {
+        self.end - self.start
+    }
+    /// Returns true if the interval has a zero length.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if `time` lies within the interval.
+    pub fn contains(&self, time: FloatInstant) -> bool {
+        self.start <= time && time < self.end
+    }
+    /// Returns true if `other` lies entirely within the interval.
+    pub fn contains_interval(&self, other: &TimeInterval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+    /// Returns true if the two intervals share any point in time.
+    pub fn overlaps(&self, other: &TimeInterval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+    /// Return the interval of time shared by both intervals, or `None` if they do
+    /// not overlap.
+    pub fn intersection(&self, other: &TimeInterval) -> Option<TimeInterval> {
+        if self.overlaps(other) {
+            Some(TimeInterval {
+                start: self.start.max(other.start),
+                end: self.end.min(other.end),
+            })
+        } else {
+            None
+        }
+    }
+    /// Return the smallest interval containing both intervals.
+    pub fn hull(&self, other: &TimeInterval) -> TimeInterval {
+        TimeInterval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Return the interval moved later in time by `offset`.
+    ///
+    /// A negative `offset` moves the interval earlier.
+    pub fn shift_by(&self, offset: FloatDuration) -> TimeInterval {
+        TimeInterval {
+            start: self.start + offset,
+            end: self.end + offset,
+        }
+    }
+    /// Return the point within the interval closest to `time`.
+    ///
+    /// Times at or after the end are clamped to the end, even though it is not
+    /// contained in the interval.
+    pub fn clamp(&self, time: FloatInstant) -> FloatInstant {
+        time.max(self.start).min(self.end)
+    }
+    /// Return the part of this interval which lies within `bounds`, or `None` if it
+    /// lies entirely outside of them.
+    ///
+    /// This is equivalent to `self.intersection(bounds)`.
+    pub fn clamp_to(&self, bounds: &TimeInterval) -> Option<TimeInterval> {
+        self.intersection(bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: f64) -> FloatInstant {
+        FloatInstant::from_epoch(FloatDuration::seconds(secs))
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = TimeInterval::with_duration(at(1.0), FloatDuration::seconds(2.0));
+        assert_eq!(interval, TimeInterval::new(at(1.0), at(3.0)));
+        assert_eq!(interval.start(), at(1.0));
+        assert_eq!(interval.end(), at(3.0));
+        assert_eq!(interval.duration(), FloatDuration::seconds(2.0));
+        assert!(!interval.is_empty());
+        assert!(TimeInterval::new(at(1.0), at(1.0)).is_empty());
+
+        assert!(interval.contains(at(1.0)));
+        assert!(interval.contains(at(2.999)));
+        assert!(!interval.contains(at(3.0)));
+        assert!(!interval.contains(at(0.5)));
+
+        assert_eq!(interval.shift_by(FloatDuration::seconds(-1.0)),
+                   TimeInterval::new(at(0.0), at(2.0)));
+        assert_eq!(interval.clamp(at(0.0)), at(1.0));
+        assert_eq!(interval.clamp(at(2.0)), at(2.0));
+        assert_eq!(interval.clamp(at(5.0)), at(3.0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = TimeInterval::new(at(0.0), at(2.0));
+        let b = TimeInterval::new(at(1.0), at(3.0));
+        let c = TimeInterval::new(at(2.0), at(4.0));
+
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&c));
+        assert_eq!(a.intersection(&b), Some(TimeInterval::new(at(1.0), at(2.0))));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(b.clamp_to(&c), Some(TimeInterval::new(at(2.0), at(3.0))));
+        assert_eq!(a.hull(&c), TimeInterval::new(at(0.0), at(4.0)));
+        assert!(a.hull(&c).contains_interval(&b));
+        assert!(!a.contains_interval(&b));
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_reversed_panic() {
+        TimeInterval::new(at(1.0), at(0.0));
+    }
+}
@@ -0,0 +1,260 @@
+//! ISO 8601 duration formatting and parsing.
+use duration::{FloatDuration, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE};
+use error::ParseDurationError;
+
+impl FloatDuration {
+    /// Format the duration as an ISO 8601 duration string, such as `"PT1H30M15.5S"`.
+    ///
+    /// Days are emitted as a `D` component when the duration spans at least
+    /// one day, and components that are zero are omitted. Negative durations are
+    /// prefixed with a `-` sign and a zero duration is written as `"PT0S"`.
+    ///
+    /// Non-finite durations have no ISO 8601 representation; their output is
+    /// unspecified and will not round-trip through `from_iso8601`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let duration = FloatDuration::hours(1.0) + FloatDuration::minutes(30.0) +
+    ///                FloatDuration::seconds(15.5);
+    /// assert_eq!(duration.to_iso8601(), "PT1H30M15.5S");
+    /// assert_eq!((-FloatDuration::days(2.0)).to_iso8601(), "-P2D");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let total = self.as_seconds();
+        if total == 0.0 {
+            return String::from("PT0S");
+        }
+
+        let mut result = String::new();
+        if total.is_sign_negative() {
+            result.push('-');
+        }
+        result.push('P');
+
+        let total = total.abs();
+        let rem_days = total % SECS_PER_DAY;
+        let days = (total - rem_days) / SECS_PER_DAY;
+        let rem_hours = rem_days % SECS_PER_HOUR;
+        let hours = (rem_days - rem_hours) / SECS_PER_HOUR;
+        let seconds = rem_hours % SECS_PER_MINUTE;
+        let minutes = (rem_hours - seconds) / SECS_PER_MINUTE;
+
+        if days != 0.0 {
+            result.push_str(&format!("{}D", days));
+        }
+        if hours != 0.0 || minutes != 0.0 || seconds != 0.0 {
+            result.push('T');
+            if hours != 0.0 {
+                result.push_str(&format!("{}H", hours));
+            }
+            if minutes != 0.0 {
+                result.push_str(&format!("{}M", minutes));
+            }
+            if seconds != 0.0 {
+                result.push_str(&format!("{}S", seconds));
+            }
+        }
+        result
+    }
+
+    /// Parse an ISO 8601 duration string, such as `"PT1H30M15.5S"`.
+    ///
+    /// Week (`W`), day (`D`), hour (`H`), minute (`M`) and second (`S`) components
+    /// are supported, and any of them may be fractional using either `.` or `,`
+    /// as the decimal separator. A leading `-` or `+` sign is accepted.
+    /// Components must appear in the standard order and each designator may
+    /// only be given once.
+    ///
+    /// # Errors
+    /// Calendar-dependent year and month components have no fixed length and
+    /// are rejected with `ParseDurationError::UnknownUnit`. Any other deviation
+    /// from the format results in a `ParseDurationError`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_iso8601("PT1M30.5S"),
+    ///            Ok(FloatDuration::seconds(90.5)));
+    /// assert_eq!(FloatDuration::from_iso8601("-P1DT12H"),
+    ///            Ok(-FloatDuration::hours(36.0)));
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<FloatDuration, ParseDurationError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+
+        let (negative, s) = if let Some(rest) = s.strip_prefix('-') {
+            (true, rest)
+        } else if let Some(rest) = s.strip_prefix('+') {
+            (false, rest)
+        } else {
+            (false, s)
+        };
+        let s = match s.strip_prefix('P') {
+            Some(rest) => rest,
+            None => return Err(ParseDurationError::InvalidFormat),
+        };
+
+        let (date, time) = match s.find('T') {
+            Some(index) => (&s[..index], Some(&s[index + 1..])),
+            None => (s, None),
+        };
+
+        let mut total = FloatDuration::zero();
+        let mut components = 0;
+        let mut last = None;
+        for (value, designator) in Components::new(date) {
+            let value = value?;
+            last = check_order("YMWD", designator, last)?;
+            total += match designator {
+                'W' => FloatDuration::days(value * 7.0),
+                'D' => FloatDuration::days(value),
+                'Y' | 'M' => return Err(ParseDurationError::UnknownUnit),
+                _ => return Err(ParseDurationError::InvalidFormat),
+            };
+            components += 1;
+        }
+        if let Some(time) = time {
+            if time.is_empty() {
+                return Err(ParseDurationError::InvalidFormat);
+            }
+            let mut last = None;
+            for (value, designator) in Components::new(time) {
+                let value = value?;
+                last = check_order("HMS", designator, last)?;
+                total += match designator {
+                    'H' => FloatDuration::hours(value),
+                    'M' => FloatDuration::minutes(value),
+                    'S' => FloatDuration::seconds(value),
+                    _ => return Err(ParseDurationError::InvalidFormat),
+                };
+                components += 1;
+            }
+        }
+        if components == 0 {
+            return Err(ParseDurationError::InvalidFormat);
+        }
+
+        if negative { Ok(-total) } else { Ok(total) }
+    }
+}
+
+/// Check that `designator` comes strictly after the previously seen one in
+/// `order`, returning its position. Unknown designators are passed through and
+/// rejected by the caller.
+fn check_order(order: &str, designator: char, last: Option<usize>)
+               -> Result<Option<usize>, ParseDurationError> {
+    match (order.find(designator), last) {
+        (Some(index), Some(last)) if index <= last => Err(ParseDurationError::InvalidFormat),
+        (Some(index), _) => Ok(Some(index)),
+        (None, _) => Ok(last),
+    }
+}
+
+/// Iterator over the `<number><designator>` pairs of an ISO 8601 duration part.
+struct Components<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Components<'a> {
+    fn new(s: &'a str) -> Components<'a> {
+        Components { remaining: s }
+    }
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = (Result<f64, ParseDurationError>, char);
+
+    fn next(&mut self) -> Option<(Result<f64, ParseDurationError>, char)> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let end = self.remaining
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(self.remaining.len());
+        let (number, rest) = self.remaining.split_at(end);
+        let designator = match rest.chars().next() {
+            Some(c) => c,
+            None => {
+                self.remaining = "";
+                return Some((Err(ParseDurationError::MissingUnit), ' '));
+            }
+        };
+        self.remaining = &rest[designator.len_utf8()..];
+
+        let value = if number.is_empty() {
+            Err(ParseDurationError::InvalidNumber)
+        } else {
+            number.replace(',', ".").parse::<f64>().map_err(|_| ParseDurationError::InvalidNumber)
+        };
+        Some((value, designator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_iso8601() {
+        assert_eq!(FloatDuration::zero().to_iso8601(), "PT0S");
+        assert_eq!(FloatDuration::seconds(15.5).to_iso8601(), "PT15.5S");
+        assert_eq!(FloatDuration::minutes(90.0).to_iso8601(), "PT1H30M");
+        assert_eq!((FloatDuration::days(3.0) + FloatDuration::seconds(1.0)).to_iso8601(),
+                   "P3DT1S");
+        assert_eq!(FloatDuration::days(2.0).to_iso8601(), "P2D");
+        assert_eq!((-FloatDuration::minutes(2.5)).to_iso8601(), "-PT2M30S");
+        assert_eq!(FloatDuration::milliseconds(250.0).to_iso8601(), "PT0.25S");
+    }
+
+    #[test]
+    fn test_from_iso8601() {
+        assert_eq!(FloatDuration::from_iso8601("PT1H30M15.5S"),
+                   Ok(FloatDuration::hours(1.0) + FloatDuration::minutes(30.0) +
+                      FloatDuration::seconds(15.5)));
+        assert_eq!(FloatDuration::from_iso8601("P2W"), Ok(FloatDuration::days(14.0)));
+        assert_eq!(FloatDuration::from_iso8601("P1DT1S"),
+                   Ok(FloatDuration::days(1.0) + FloatDuration::seconds(1.0)));
+        assert_eq!(FloatDuration::from_iso8601("PT0,5S"), Ok(FloatDuration::seconds(0.5)));
+        assert_eq!(FloatDuration::from_iso8601("PT1.5H"), Ok(FloatDuration::minutes(90.0)));
+        assert_eq!(FloatDuration::from_iso8601("-PT10S"), Ok(FloatDuration::seconds(-10.0)));
+        assert_eq!(FloatDuration::from_iso8601("+PT0S"), Ok(FloatDuration::zero()));
+
+        assert_eq!(FloatDuration::from_iso8601(""), Err(ParseDurationError::Empty));
+        assert_eq!(FloatDuration::from_iso8601("P"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("PT"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("1H"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("P1Y"),
+                   Err(ParseDurationError::UnknownUnit));
+        assert_eq!(FloatDuration::from_iso8601("P1H"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("PTH"),
+                   Err(ParseDurationError::InvalidNumber));
+        assert_eq!(FloatDuration::from_iso8601("PT10"),
+                   Err(ParseDurationError::MissingUnit));
+        assert_eq!(FloatDuration::from_iso8601("PT1S1H"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("PT1H1H"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_iso8601("P1D2W"),
+                   Err(ParseDurationError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_iso8601_round_trip() {
+        let durations = [FloatDuration::seconds(1.25),
+                         FloatDuration::hours(49.5),
+                         -FloatDuration::minutes(61.0),
+                         FloatDuration::days(400.0) + FloatDuration::milliseconds(5.0)];
+        for duration in durations.iter() {
+            assert_eq!(FloatDuration::from_iso8601(&duration.to_iso8601()),
+                       Ok(*duration));
+        }
+    }
+}
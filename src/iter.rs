@@ -1,7 +1,9 @@
 //! Utilities for iteration with duration objects.
 
+use num_traits::Float;
+
 use duration::FloatDuration;
-use std::iter;
+use core::iter;
 
 /// An iterator over an evenly spaced lattice of `FloatDuration`s.
 ///
@@ -21,8 +23,8 @@ impl Subdivide {
         let step_size = (end - start) / (steps - 1) as f64;
 
         Subdivide {
-            start: start,
-            step_size: step_size,
+            start,
+            step_size,
             len: steps,
             index: 0,
         }
@@ -158,6 +160,182 @@ pub fn subdivide_with_step(begin: FloatDuration,
     sub.zip(iter::repeat(step_size))
 }
 
+/// An iterator over durations from a start up to an end in fixed steps.
+///
+/// This type is returned by `step_range` and `FloatDuration::step_range`, and
+/// it not meant to be instantiated directly.
+#[derive(Debug, Clone)]
+pub struct DurationRange {
+    start: FloatDuration,
+    step: FloatDuration,
+    len: usize,
+    index: usize,
+}
+
+impl DurationRange {
+    fn new(start: FloatDuration, end: FloatDuration, step: FloatDuration) -> DurationRange {
+        assert!(!step.is_zero() && step.as_seconds().is_finite(),
+                "step_range requires a non-zero, finite step");
+        let steps = Float::ceil((end - start) / step);
+        // A NaN or non-positive number of steps leaves the range empty.
+        let len = if steps > 0.0 {
+            if steps >= usize::MAX as f64 {
+                usize::MAX
+            } else {
+                steps as usize
+            }
+        } else {
+            0
+        };
+
+        DurationRange {
+            start,
+            step,
+            len,
+            index: 0,
+        }
+    }
+
+    /// The distance between steps in the iteration.
+    pub fn step_size(&self) -> FloatDuration {
+        self.step
+    }
+}
+
+impl Iterator for DurationRange {
+    type Item = FloatDuration;
+
+    #[inline]
+    fn next(&mut self) -> Option<FloatDuration> {
+        if self.index >= self.len {
+            None
+        } else {
+            let index = self.index;
+            self.index += 1;
+            Some(self.start + self.step * (index as f64))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.len - self.index;
+        (left, Some(left))
+    }
+}
+
+impl DoubleEndedIterator for DurationRange {
+    fn next_back(&mut self) -> Option<FloatDuration> {
+        if self.index >= self.len {
+            None
+        } else {
+            self.len -= 1;
+            let index = self.len;
+            Some(self.start + self.step * (index as f64))
+        }
+    }
+}
+
+impl ExactSizeIterator for DurationRange {}
+
+/// Step from `start` towards `end` in increments of `step`.
+///
+/// `step_range` returns an iterator that lazily computes `start`, `start + step`,
+/// `start + 2*step`, and so on, for as long as the value is before `end`. Like
+/// `start..end` for integers, the range includes `start` but never `end`. A
+/// negative `step` counts down from `start` to `end` instead.
+///
+/// Each value is computed from `start` directly instead of by repeated addition,
+/// so rounding errors do not accumulate over long ranges.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::step_range;
+///
+/// let timeouts: Vec<_> = step_range(FloatDuration::milliseconds(10.0),
+///                                   FloatDuration::milliseconds(50.0),
+///                                   FloatDuration::milliseconds(10.0))
+///     .collect();
+/// assert_eq!(timeouts, vec![FloatDuration::milliseconds(10.0),
+///                           FloatDuration::milliseconds(20.0),
+///                           FloatDuration::milliseconds(30.0),
+///                           FloatDuration::milliseconds(40.0)]);
+/// ```
+///
+/// # Panics
+/// This function panics if `step` is zero or not finite.
+pub fn step_range(start: FloatDuration, end: FloatDuration, step: FloatDuration) -> DurationRange {
+    DurationRange::new(start, end, step)
+}
+
+impl FloatDuration {
+    /// Step from `start` towards `end` in increments of `step`.
+    ///
+    /// This is equivalent to [`iter::step_range`](iter/fn.step_range.html).
+    ///
+    /// # Panics
+    /// This function panics if `step` is zero or not finite.
+    pub fn step_range(start: FloatDuration,
+                      end: FloatDuration,
+                      step: FloatDuration)
+                      -> DurationRange {
+        DurationRange::new(start, end, step)
+    }
+
+    /// Return `points` evenly spaced offsets from zero to this duration, inclusive.
+    ///
+    /// The first offset is always zero and the last is always `self`, so the
+    /// distance between offsets is `self / (points - 1)`. This is equivalent to
+    /// `subdivide(FloatDuration::zero(), self, points)`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let keys: Vec<_> = FloatDuration::seconds(1.0).linspace(5).collect();
+    /// assert_eq!(keys, vec![FloatDuration::seconds(0.0),
+    ///                       FloatDuration::seconds(0.25),
+    ///                       FloatDuration::seconds(0.5),
+    ///                       FloatDuration::seconds(0.75),
+    ///                       FloatDuration::seconds(1.0)]);
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if `points < 2`.
+    pub fn linspace(self, points: usize) -> Subdivide {
+        Subdivide::new(FloatDuration::zero(), self, points)
+    }
+
+    /// Split this duration into `parts` equal parts and return the offset at which
+    /// each part starts.
+    ///
+    /// The first offset is zero and `self` itself is never returned, so there are
+    /// exactly `parts` offsets, each `self / parts` apart. This is the usual shape
+    /// of a loop over frames or simulation substeps.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let substeps = FloatDuration::milliseconds(16.0).subdivide(4);
+    /// assert_eq!(substeps.step_size(), FloatDuration::milliseconds(4.0));
+    /// assert_eq!(substeps.collect::<Vec<_>>(),
+    ///            vec![FloatDuration::milliseconds(0.0),
+    ///                 FloatDuration::milliseconds(4.0),
+    ///                 FloatDuration::milliseconds(8.0),
+    ///                 FloatDuration::milliseconds(12.0)]);
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if `parts` is zero.
+    pub fn subdivide(self, parts: usize) -> Subdivide {
+        assert!(parts >= 1, "subdivide requires at least one part");
+        Subdivide {
+            start: FloatDuration::zero(),
+            step_size: self / parts as f64,
+            len: parts,
+            index: 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +360,68 @@ mod tests {
                         FloatDuration::zero()]);
     }
 
+    #[test]
+    fn test_step_range() {
+        let r = FloatDuration::step_range(FloatDuration::zero(),
+                                          FloatDuration::seconds(1.0),
+                                          FloatDuration::seconds(0.25));
+        assert_eq!(r.len(), 4);
+        assert_eq!(r.clone().next_back(), Some(FloatDuration::seconds(0.75)));
+        assert_eq!(r.rev().collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(0.75),
+                        FloatDuration::seconds(0.5),
+                        FloatDuration::seconds(0.25),
+                        FloatDuration::zero()]);
+
+        let r = step_range(FloatDuration::seconds(1.0),
+                           FloatDuration::zero(),
+                           FloatDuration::seconds(-0.4));
+        assert_eq!(r.collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(1.0),
+                        FloatDuration::seconds(0.6),
+                        FloatDuration::seconds(1.0) - FloatDuration::seconds(0.8)]);
+
+        assert_eq!(step_range(FloatDuration::seconds(1.0),
+                              FloatDuration::zero(),
+                              FloatDuration::seconds(1.0))
+                       .count(),
+                   0);
+        assert_eq!(step_range(FloatDuration::zero(),
+                              FloatDuration::seconds(1.0),
+                              FloatDuration::seconds(0.3))
+                       .count(),
+                   4);
+    }
+
+    #[test]
+    fn test_linspace() {
+        let total = FloatDuration::minutes(1.0);
+        assert_eq!(total.linspace(3).collect::<Vec<_>>(),
+                   subdivide(FloatDuration::zero(), total, 3).collect::<Vec<_>>());
+        assert_eq!(total.linspace(2).len(), 2);
+
+        let parts = total.subdivide(3);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.step_size(), FloatDuration::seconds(20.0));
+        assert_eq!(parts.rev().collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(40.0),
+                        FloatDuration::seconds(20.0),
+                        FloatDuration::zero()]);
+        assert_eq!(total.subdivide(1).collect::<Vec<_>>(), vec![FloatDuration::zero()]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_subdivide_zero_parts_panic() {
+        FloatDuration::seconds(1.0).subdivide(0);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_step_range_panic() {
+        step_range(FloatDuration::zero(), FloatDuration::seconds(1.0), FloatDuration::zero());
+    }
+
     #[should_panic]
     #[test]
     fn test_subdivide_panic() {
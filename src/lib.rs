@@ -23,12 +23,12 @@
 //!
 //! ```ignore
 //! [dependencies]
-//! float_duration = "0.3.3"
+//! float_duration = "0.4.0"
 //! ```
 //! # Overview
 //!
 //! This crate provides a single primary type:
-//! [`FloatDuration`](duration/struct.FloatDuration.html) which represents an
+//! [`FloatDuration`](duration/type.FloatDuration.html) which represents an
 //! arbitrary distance in time with no defined start or end point.
 //! Internally, it stores a single `f64` holding the number of seconds the duration
 //! represents, which can be negative for a "backward" duration. It provides accessors
@@ -41,6 +41,37 @@
 //! assert_eq!(timespan, FloatDuration::seconds(9030.0));
 //! assert_eq!(timespan, FloatDuration::minutes(150.5));
 //! ```
+//!
+//! The unit constructors are `const fn`, and common durations are available as
+//! associated constants, so durations can be used in `const` and `static` items:
+//!
+//! ```rust
+//! # use float_duration::*;
+//! const POLL_INTERVAL: FloatDuration = FloatDuration::milliseconds(250.0);
+//! assert_eq!(POLL_INTERVAL * 4.0, FloatDuration::SECOND);
+//! ```
+//!
+//! `FloatDuration` is an alias for
+//! [`GenericFloatDuration<f64>`](duration/struct.GenericFloatDuration.html). Code
+//! working in single precision can use `FloatDuration32` instead, which
+//! supports the same operations backed by an `f32`:
+//!
+//! ```rust
+//! # use float_duration::*;
+//! let frame_time = FloatDuration32::milliseconds(16.0);
+//! assert_eq!(frame_time * 2.0, FloatDuration32::milliseconds(32.0));
+//! assert_eq!(FloatDuration32::seconds(0.5).to_f64(), FloatDuration::seconds(0.5));
+//! ```
+//!
+//! Durations can also be parsed from strings such as `"1.5h"` or `"250 ms"`
+//! via `FromStr`:
+//!
+//! ```rust
+//! # use float_duration::*;
+//! let timeout: FloatDuration = "250 ms".parse().unwrap();
+//! assert_eq!(timeout, FloatDuration::milliseconds(250.0));
+//! ```
+//!
 //! Additionally, a [`TimePoint`](duration/trait.TimePoint.html) trait is provided
 //! for computing a `FloatDuration` between two objects representing a point in time.
 //!
@@ -121,11 +152,33 @@
 //! ```
 //!
 //! `FloatDuration` may also be converted to/from `std::time::Duration` via the
-//! `to_std` and `from_std` methods.
+//! `to_std` and `from_std` methods, and a `std::time::Duration` can be added to or
+//! subtracted from a `FloatDuration` directly:
+//!
+//! ```rust
+//! # use float_duration::FloatDuration;
+//! use std::time::Duration;
+//!
+//! let total = FloatDuration::seconds(0.5) + Duration::from_millis(1500);
+//! assert_eq!(total, FloatDuration::seconds(2.0));
+//! ```
+//!
+//! Closures can be timed with `FloatDuration::measure`, and with the `async`
+//! feature futures can be timed with `FloatDuration::measure_async`. A `ScopeTimer`
+//! passes the time spent in a scope to a callback when dropped, and
+//! `FloatDuration::bench` reports the min, mean, median and max time of a closure
+//! over many runs.
+//!
+//! ## [tokio](https://crates.io/crates/tokio)
+//!
+//! With the `tokio` feature, the `sleep_for` and `timeout_for` functions wrap
+//! `tokio::time::sleep` and `tokio::time::timeout`, accepting a `FloatDuration`
+//! and treating negative, zero and NaN durations as already elapsed.
 //!
 //! ## [approx](https://crates.io/crates/approx)
-//! `FloatDuration` provides an implementation of `approx::ApproxEq`
-//! for near-equality comparisons of `FloatDuration` if the `approx` feature is enabled.
+//! `FloatDuration` implements `approx::AbsDiffEq`, `approx::RelativeEq` and
+//! `approx::UlpsEq` for near-equality comparisons of `FloatDuration` if the `approx`
+//! feature is enabled, so macros such as `assert_relative_eq!` accept durations.
 //! Since `FloatDuration` uses floating point values, this should be the
 //! preferred way to establish equality between two duration objects.
 //!
@@ -136,7 +189,9 @@
 //! is supported via `TimePoint` trait impls, assuming the feature
 //! "chrono" is enabled.
 //! Additionally, `FloatDuration` objects can be converted to/from
-//! `chrono::Duration` objects via the `to_chrono` and `from_chrono` methods.
+//! `chrono::Duration` objects via the `to_chrono` and `from_chrono` methods, and
+//! `DateTime`, `NaiveDateTime` and `NaiveTime` can be offset by a `FloatDuration`
+//! with `+` and `-`, or with the fallible methods of the `TimeOffset` trait.
 //!
 //! Note: if the `chrono` feature is enabled, the `time` feature must also be enabled
 //! as `chrono` directly relies on some types defined in `time`.
@@ -152,28 +207,355 @@
 //!     FloatDuration::hours(2.0));
 //! ```
 //!
+//! ## [time](https://crates.io/crates/time) 0.3
+//!
+//! The `time` feature still refers to time 0.1, which `chrono` relies on. With the
+//! `time03` feature, `FloatDuration` can be converted to/from time 0.3's
+//! `time::Duration` via the `to_time` and `from_time` methods, and `TimePoint` is
+//! implemented for `OffsetDateTime`, `PrimitiveDateTime` and `time::Instant`.
+//!
+//! ```rust,ignore
+//! # extern crate time03 as time;
+//! # extern crate float_duration;
+//! use float_duration::{FloatDuration, TimePoint};
+//! use time::{Date, Month};
+//!
+//! # fn main() {
+//! let date1 = Date::from_calendar_date(2017, Month::May, 25).unwrap().midnight();
+//! let date2 = date1 + time::Duration::hours(26);
+//!
+//! assert_eq!(date2.float_duration_since(date1).unwrap(), FloatDuration::days(1.0) +
+//!     FloatDuration::hours(2.0));
+//! # }
+//! ```
+//!
+//! ## [jiff](https://crates.io/crates/jiff)
+//!
+//! With the `jiff` feature, `FloatDuration` can be converted to/from
+//! `jiff::SignedDuration` via the `to_jiff` and `from_jiff` methods, and to/from
+//! `jiff::Span` via `to_jiff_span` and `from_jiff_span`. `TimePoint` is implemented
+//! for `jiff::Timestamp` and `jiff::Zoned`.
+//!
+//! ## [hifitime](https://crates.io/crates/hifitime)
+//!
+//! With the `hifitime` feature, `FloatDuration` can be converted to/from
+//! `hifitime::Duration` via the `to_hifitime` and `from_hifitime` methods, and
+//! `TimePoint` is implemented for `hifitime::Epoch`.
+//!
+//! ## [humantime](https://crates.io/crates/humantime)
+//!
+//! With the `humantime` feature, `FloatDuration` can be converted to/from
+//! `humantime::Duration` via the `to_humantime` and `from_humantime` methods. If
+//! `serde` is enabled too, the `humantime_serde` module can be used with
+//! `#[serde(with = "float_duration::humantime_serde")]` to (de)serialize durations
+//! as strings such as `"1h 30m"`, compatible with the `humantime-serde` crate.
+//!
+//! ## [ICU4X](https://crates.io/crates/icu)
+//!
+//! With the `icu` feature, a `LocalizedFormatter` shows durations with the unit
+//! names, plural rules and decimal separator of a locale, such as `3,5 Minuten`.
+//!
+//! ## [proptest](https://crates.io/crates/proptest)
+//!
+//! With the `proptest` feature, `FloatDuration` implements `Arbitrary`, and the
+//! [`strategy`](strategy/index.html) module provides strategies for positive and
+//! bounded durations.
+//!
+//! ## [quickcheck](https://crates.io/crates/quickcheck)
+//!
+//! With the `quickcheck` feature, `FloatDuration` implements `quickcheck::Arbitrary`,
+//! generating finite durations and shrinking them towards zero.
+//!
+//! ## [num-traits](https://crates.io/crates/num-traits)
+//!
+//! `FloatDuration` implements `Zero`, `ConstZero`, `Bounded`, `CheckedAdd`,
+//! `CheckedSub`, `SaturatingAdd`, and `SaturatingSub`, so it can be used in generic
+//! code bounded by those traits. Since the product of two durations is not a
+//! duration, `Num` and `Signed` are not implemented.
+//!
+//! ## no_std
+//!
+//! `float_duration` depends on the standard library through the default `std`
+//! feature. Disabling it makes the crate `#![no_std]`, in which case the `libm`
+//! feature must be enabled to provide the floating-point math normally supplied
+//! by `std`:
+//!
+//! ```ignore
+//! [dependencies]
+//! float_duration = { version = "0.4.0", default-features = false, features = ["libm"] }
+//! ```
+//!
+//! Without `std`, the `TimePoint` impls for `Instant` and `SystemTime`, the ISO 8601,
+//! Go and systemd duration helpers and the `std::error::Error` impls are
+//! unavailable. Conversions to and from `core::time::Duration` remain available.
+//!
 //! ## [serde](https://crates.io/crates/serde)
 //!
 //! `FloatDuration` supports serialization with `serde`. Presently, a `FloatDuration`
 //! is serialized to a single `f64` value representing the number of seconds in the
-//! duration.
+//! duration. The error types in the [`error`](error/index.html) module can be
+//! serialized as well.
+//!
+//! Other encodings can be chosen per field with the modules in
+//! [`serde`](serde/index.html), such as
+//! `#[serde(with = "float_duration::serde::milliseconds_u64")]`.
+//!
+//! ## [schemars](https://crates.io/crates/schemars)
+//!
+//! With the `schemars` feature, `FloatDuration` implements `JsonSchema` as a number
+//! of seconds. Each module in [`serde`](serde/index.html) has a `json_schema`
+//! function describing its representation, to be used with
+//! `#[schemars(schema_with = "float_duration::serde::iso8601::json_schema")]`.
+//!
+//! ## [rkyv](https://crates.io/crates/rkyv)
+//!
+//! With the `rkyv` feature, `GenericFloatDuration` implements `Archive`, `Serialize`
+//! and `Deserialize`. The archived form, `ArchivedGenericFloatDuration`, stores the
+//! seconds as a little-endian float, and can be read in place with `to_native` or
+//! compared directly with a `FloatDuration`.
+//!
+//! ## [borsh](https://crates.io/crates/borsh)
+//!
+//! With the `borsh` feature, `GenericFloatDuration` implements `BorshSerialize` and
+//! `BorshDeserialize`, encoded as the little-endian float number of seconds. As with
+//! any float in `borsh`, NaN durations cannot be serialized.
+//!
+//! ## [postgres](https://crates.io/crates/postgres)
+//!
+//! With the `postgres` feature, `FloatDuration` implements `ToSql` and `FromSql` for
+//! `INTERVAL` columns, and for `double precision` and `real` columns holding a
+//! number of seconds. Intervals have a resolution of one microsecond; see the
+//! implementations for how days and months are converted.
+//!
+//! ## [diesel](https://crates.io/crates/diesel)
+//!
+//! With the `diesel` feature, `FloatDuration` can be used in Diesel models for
+//! PostgreSQL `Interval` and `Double` columns, the latter holding a number of
+//! seconds. Intervals are converted the same way as with the `postgres` feature.
+//!
+//! ## [sqlx](https://crates.io/crates/sqlx)
+//!
+//! With the `sqlx` feature, `FloatDuration` implements `Type`, `Encode` and `Decode`.
+//! In PostgreSQL it is an `INTERVAL` that can also be read from a number of seconds,
+//! while in MySQL and SQLite it is a double holding a number of seconds.
+//!
+//! ## [PyO3](https://crates.io/crates/pyo3)
+//!
+//! With the `pyo3` feature, `FloatDuration` is converted to a Python
+//! `datetime.timedelta`, rounded to the microsecond, and can be extracted from a
+//! `timedelta` or from a number of seconds.
+//!
+//! ## [wasm-bindgen](https://crates.io/crates/wasm-bindgen)
+//!
+//! With the `wasm` feature, `FloatDuration` converts to and from JavaScript
+//! milliseconds with `from_js_millis` and `to_js_millis`, and `js_sys::Date`
+//! implements `TimePoint`. `performance_now` reads the high-resolution browser clock
+//! as a duration since the time origin.
+//!
+//! ## C
+//!
+//! `GenericFloatDuration<F>` is `#[repr(transparent)]`, so a `FloatDuration` has the
+//! same layout and ABI as an `f64`. With the `ffi` feature, the [`ffi`](ffi/index.html)
+//! module exports C functions to create, convert, format and parse durations,
+//! declared in `include/float_duration.h`.
+//!
+//! ## [rand](https://crates.io/crates/rand)
+//!
+//! With the `rand` feature, `GenericFloatDuration` implements `SampleUniform`, so
+//! random durations can be drawn from a `Uniform` distribution or with
+//! `rng.random_range(FloatDuration::seconds(1.0)..FloatDuration::seconds(5.0))`.
+//! The [`distr`](distr/index.html) module also provides exponential, Pareto and
+//! normal distributions of durations for simulations. To spread out retries,
+//! `jitter_full` and `jitter_equal` randomize a delay, and
+//! [`DecorrelatedJitter`](backoff/struct.DecorrelatedJitter.html) generates delays
+//! that grow like those of a `Backoff`.
+//!
+//! ## [tracing](https://crates.io/crates/tracing)
+//!
+//! With the `tracing` feature, `as_seconds_value` and `as_display_value` record a
+//! `FloatDuration` in span and event fields, as a number of seconds or as text. A
+//! `SpanTimer` records the time elapsed into a span field when it is dropped, and
+//! the `span_timer!` macro creates a span along with its timer.
+//!
+//! ## [metrics](https://crates.io/crates/metrics)
+//!
+//! With the `metrics` feature, `record_histogram` and `set_gauge` record a
+//! `FloatDuration` into `metrics` handles as a number of a chosen `TimeUnit`, and a
+//! `HistogramTimer` records the time elapsed into a histogram when it is dropped.
+
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 
+#[cfg(any(feature = "std", test))]
+extern crate core;
+extern crate num_traits;
 #[cfg(feature = "chrono")]
 extern crate chrono;
 #[cfg(feature = "time")]
 extern crate time;
+#[cfg(feature = "time03")]
+extern crate time03;
+#[cfg(feature = "jiff")]
+extern crate jiff;
+#[cfg(feature = "hifitime")]
+extern crate hifitime;
+#[cfg(feature = "tokio")]
+extern crate tokio;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "metrics")]
+extern crate metrics;
+#[cfg(feature = "humantime")]
+extern crate humantime;
+#[cfg(feature = "icu")]
+extern crate fixed_decimal;
+#[cfg(feature = "icu")]
+extern crate icu_decimal;
+#[cfg(feature = "icu")]
+extern crate icu_locale_core;
+#[cfg(feature = "icu")]
+extern crate icu_plurals;
+#[cfg(feature = "icu")]
+extern crate icu_provider;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
 #[cfg(feature = "approx")]
 extern crate approx;
+#[cfg(feature = "schemars")]
+extern crate schemars;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "borsh")]
+extern crate borsh;
+#[cfg(feature = "diesel")]
+extern crate diesel;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "wasm")]
+extern crate js_sys;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "postgres")]
+extern crate bytes;
+#[cfg(feature = "postgres")]
+#[macro_use]
+extern crate postgres_types;
 #[cfg(feature = "serde")]
-extern crate serde;
+extern crate serde as serde_crate;
 #[cfg(all(test, feature = "serde"))]
 extern crate serde_test;
 
 
+pub mod backoff;
+#[cfg(feature = "std")]
+pub mod clock;
+pub mod components;
+pub mod debounce;
+#[cfg(feature = "std")]
+pub mod delay_queue;
 pub mod duration;
+pub mod easing;
 pub mod error;
+pub mod ext;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod format;
+#[cfg(feature = "std")]
+pub mod frame;
+pub mod frequency;
+#[cfg(feature = "std")]
+pub mod histogram;
+pub mod instant;
+pub mod interval;
 pub mod iter;
+#[doc(hidden)]
+#[macro_use]
+pub mod macros;
+pub mod parse;
+pub mod retry;
+pub mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod stopwatch;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "std")]
+pub mod ticker;
+pub mod timer;
+pub mod timestep;
+pub mod tween;
+pub mod unit;
+mod interop;
+#[cfg(feature = "std")]
+mod go;
+#[cfg(feature = "std")]
+mod systemd;
+#[cfg(feature = "std")]
+mod iso8601;
+#[cfg(feature = "std")]
+mod timing;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("float_duration requires either the `std` or the `libm` feature to be enabled");
 
-pub use duration::{FloatDuration, TimePoint, FromDuration, IntoDuration};
-pub use error::OutOfRangeError;
-pub use iter::{subdivide, subdivide_with_step};
+pub use duration::{FloatDuration, FloatDuration32, FloatDuration64, GenericFloatDuration,
+                   TimePoint, TimeOffset, Elapsed, FromDuration, IntoDuration,
+                   float_elapsed_since};
+pub use error::{OutOfRangeError, ParseDurationError};
+#[cfg(feature = "std")]
+pub use error::EnvDurationError;
+pub use ext::DurationNumExt;
+pub use frequency::Frequency;
+pub use scale::TimeScale;
+pub use instant::FloatInstant;
+pub use iter::{step_range, subdivide, subdivide_with_step};
+pub use unit::TimeUnit;
+pub use backoff::Backoff;
+pub use debounce::{Debouncer, Throttler};
+pub use stats::{DurationStats, DurationSum};
+pub use timer::{Cooldown, Timer};
+pub use timestep::FixedTimestep;
+pub use tween::Tween;
+#[cfg(feature = "std")]
+pub use parse::DurationParser;
+#[cfg(feature = "std")]
+pub use delay_queue::DelayQueue;
+#[cfg(feature = "std")]
+pub use frame::{FpsCounter, FrameLimiter};
+#[cfg(feature = "std")]
+pub use histogram::DurationHistogram;
+#[cfg(feature = "std")]
+pub use stopwatch::Stopwatch;
+#[cfg(feature = "std")]
+pub use ticker::Ticker;
+#[cfg(feature = "std")]
+pub use timing::{precise_sleep, BenchReport, ScopeTimer};
+#[cfg(feature = "async")]
+pub use timing::Measure;
+#[cfg(feature = "tokio")]
+pub use interop::tokio::{sleep_for, timeout_for};
+#[cfg(feature = "tracing")]
+pub use interop::tracing::SpanTimer;
+#[cfg(feature = "metrics")]
+pub use interop::metrics::HistogramTimer;
+#[cfg(all(feature = "humantime", feature = "serde"))]
+pub use interop::humantime::serde as humantime_serde;
+#[cfg(feature = "icu")]
+pub use interop::icu::{LocaleError, Localized, LocalizedFormatter};
+#[cfg(feature = "proptest")]
+pub use interop::proptest as strategy;
+#[cfg(feature = "rkyv")]
+pub use duration::ArchivedGenericFloatDuration;
+#[cfg(feature = "wasm")]
+pub use interop::wasm::performance_now;
+#[cfg(feature = "rand")]
+pub use interop::rand::UniformDuration;
+#[cfg(feature = "rand")]
+pub use interop::rand as distr;
@@ -0,0 +1,171 @@
+//! The `dur!` macro for duration literals that are checked at compile time.
+use duration::FloatDuration;
+use unit::TimeUnit;
+
+/// Create a `FloatDuration` from a literal, parsed and checked at compile time.
+///
+/// The macro takes either a string in the syntax of `FloatDuration::from_str`,
+/// or one or more numbers each followed by a unit name. Numbers in the string may
+/// have a fraction but no exponent, and at most 15 significant digits. A malformed
+/// literal or an unknown unit is a compile error, and the result is a constant
+/// equal to what parsing the same text at run time gives.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate float_duration;
+/// use float_duration::FloatDuration;
+///
+/// const FRAME: FloatDuration = dur!(16.67 ms);
+///
+/// # fn main() {
+/// assert_eq!(FRAME, FloatDuration::milliseconds(16.67));
+/// assert_eq!(dur!("1h 30m"), FloatDuration::minutes(90.0));
+/// assert_eq!(dur!(1 h 30 min), FloatDuration::minutes(90.0));
+/// assert_eq!(dur!("-250 ms"), "-250 ms".parse().unwrap());
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate float_duration;
+///
+/// # fn main() {
+/// let timeout = dur!("5 fortnights");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dur {
+    ($($value:literal $unit:ident)+) => {{
+        const DURATION: $crate::FloatDuration =
+            $crate::macros::sum_terms(&[$(($value as f64, stringify!($unit))),+]);
+        DURATION
+    }};
+    ($text:literal) => {{
+        const DURATION: $crate::FloatDuration = $crate::macros::parse_literal($text);
+        DURATION
+    }};
+}
+
+/// Convert `value` of the unit called `name` to seconds, the same way
+/// `FloatDuration::from_unit` does.
+const fn term(value: f64, name: &[u8]) -> f64 {
+    let unit = match TimeUnit::from_name(name) {
+        Some(unit) => unit,
+        None => panic!("unknown unit in duration literal"),
+    };
+    match unit.units_per_sec() {
+        Some(units_per_sec) => value / units_per_sec,
+        None => value * unit.secs_per_unit(),
+    }
+}
+
+/// Add up `terms` of a number and a unit name. Used by `dur!`.
+#[doc(hidden)]
+pub const fn sum_terms(terms: &[(f64, &str)]) -> FloatDuration {
+    let mut total = 0.0;
+    let mut index = 0;
+    while index < terms.len() {
+        let secs = term(terms[index].0, terms[index].1.as_bytes());
+        total = if index == 0 { secs } else { total + secs };
+        index += 1;
+    }
+    FloatDuration::seconds(total)
+}
+
+/// Parse `text` like `FloatDuration::from_str`, in a `const fn`. Used by `dur!`.
+#[doc(hidden)]
+pub const fn parse_literal(text: &str) -> FloatDuration {
+    let bytes = text.as_bytes();
+    let mut index = skip_whitespace(bytes, 0);
+    let negative = index < bytes.len() && bytes[index] == b'-';
+    if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+        index += 1;
+    }
+
+    let mut total = 0.0;
+    let mut terms = 0;
+    while index < bytes.len() {
+        let mut mantissa: u64 = 0;
+        let mut digits = 0;
+        let mut decimals = 0;
+        let mut fraction = false;
+        while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'.') {
+            if bytes[index] == b'.' {
+                if fraction {
+                    panic!("invalid number in duration literal");
+                }
+                fraction = true;
+            } else {
+                if mantissa >= 100_000_000_000_000 {
+                    panic!("too many digits in duration literal");
+                }
+                mantissa = mantissa * 10 + (bytes[index] - b'0') as u64;
+                digits += 1;
+                if fraction {
+                    decimals += 1;
+                }
+            }
+            index += 1;
+        }
+        if digits == 0 {
+            panic!("invalid number in duration literal");
+        }
+        let mut scale = 1.0;
+        while decimals > 0 {
+            scale *= 10.0;
+            decimals -= 1;
+        }
+
+        index = skip_whitespace(bytes, index);
+        let start = index;
+        while index < bytes.len() && !bytes[index].is_ascii_whitespace() &&
+              !bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if start == index {
+            panic!("missing unit in duration literal");
+        }
+        let (_, rest) = bytes.split_at(start);
+        let (name, _) = rest.split_at(index - start);
+
+        let secs = term(mantissa as f64 / scale, name);
+        total = if terms == 0 { secs } else { total + secs };
+        terms += 1;
+        index = skip_whitespace(bytes, index);
+    }
+    if terms == 0 {
+        panic!("empty duration literal");
+    }
+    FloatDuration::seconds(if negative { -total } else { total })
+}
+
+/// Return the index of the first byte at or after `index` that is not whitespace.
+const fn skip_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::FloatDuration;
+
+    #[test]
+    fn test_dur() {
+        const TIMEOUT: FloatDuration = dur!("2.5 seconds");
+        assert_eq!(TIMEOUT, FloatDuration::seconds(2.5));
+        assert_eq!(dur!(3 days), FloatDuration::days(3.0));
+        assert_eq!(dur!(1 h 30 min 15.5 s), "1h 30min 15.5s".parse().unwrap());
+        assert_eq!(dur!(-2 wk), FloatDuration::weeks(-2.0));
+
+        for &(literal, parsed) in &[(dur!("0.1s"), "0.1s"),
+                                    (dur!(" +1.5h "), "+1.5h"),
+                                    (dur!("3 d 0.001 ms"), "3 d 0.001 ms"),
+                                    (dur!("123456789.123456 µs"), "123456789.123456 µs"),
+                                    (dur!("-1y2mo"), "-1y2mo"),
+                                    (dur!(".5 minutes"), ".5 minutes")] {
+            assert_eq!(Ok(literal), parsed.parse::<FloatDuration>());
+        }
+    }
+}
@@ -0,0 +1,639 @@
+//! Parsing of `FloatDuration` values from strings.
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::env::{self, VarError};
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use num_traits::Float;
+
+use duration::{float, GenericFloatDuration};
+#[cfg(feature = "std")]
+use error::EnvDurationError;
+use error::ParseDurationError;
+use unit::TimeUnit;
+
+/// Parse a duration from a number followed by a unit, or from a sum of several
+/// of these separated by whitespace.
+///
+/// The number may be any floating-point literal accepted by the `FromStr` impl
+/// of the underlying float type, including `inf` and `NaN`, and may be separated
+/// from the unit by whitespace. A sign in front of the first number applies to
+/// the whole sum, so `-1h 30m` is minus an hour and a half. Recognized units are:
+///
+/// - `ns`, `nanos`, `nanosecond`, `nanoseconds`
+/// - `us`, `µs`, `micros`, `microsecond`, `microseconds`
+/// - `ms`, `millis`, `millisecond`, `milliseconds`
+/// - `s`, `sec`, `secs`, `second`, `seconds`
+/// - `m`, `min`, `mins`, `minute`, `minutes`
+/// - `h`, `hr`, `hrs`, `hour`, `hours`
+/// - `d`, `day`, `days`
+/// - `w`, `wk`, `wks`, `week`, `weeks`
+/// - `mo`, `month`, `months`
+/// - `y`, `yr`, `yrs`, `year`, `years`
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// assert_eq!("1.5h".parse::<FloatDuration>(), Ok(FloatDuration::hours(1.5)));
+/// assert_eq!("90 minutes".parse::<FloatDuration>(), Ok(FloatDuration::minutes(90.0)));
+/// assert_eq!("-250ms".parse::<FloatDuration>(), Ok(FloatDuration::milliseconds(-250.0)));
+/// assert_eq!("-1h 30m".parse::<FloatDuration>(), Ok(FloatDuration::minutes(-90.0)));
+/// ```
+///
+/// The output of `Display` without a precision parses back into exactly the same
+/// duration, as does the output of `format_compound` up to the nanosecond it is
+/// rounded to:
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// let d = FloatDuration::seconds(0.1) * 7.0;
+/// assert_eq!(d.to_string().parse::<FloatDuration>(), Ok(d));
+/// ```
+impl<F: Float + FromStr> FromStr for GenericFloatDuration<F> {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<GenericFloatDuration<F>, ParseDurationError> {
+        parse_terms(s,
+                    '.',
+                    None,
+                    |number| number.parse().map_err(|_| ParseDurationError::InvalidNumber),
+                    |unit| unit.parse())
+    }
+}
+
+/// A configurable parser for durations typed by users.
+///
+/// By default, `DurationParser` accepts the same input as the `FromStr` impl of
+/// `FloatDuration`. Additional unit names, a unit for bare numbers, case-insensitive
+/// units and a different decimal separator can be configured.
+///
+/// ```rust
+/// use float_duration::{DurationParser, FloatDuration, TimeUnit};
+///
+/// let parser = DurationParser::new()
+///     .alias("Minuten", TimeUnit::Minute)
+///     .default_unit(TimeUnit::Second)
+///     .case_sensitive(false)
+///     .decimal_separator(',');
+///
+/// assert_eq!(parser.parse("1,5 minuten"), Ok(FloatDuration::minutes(1.5)));
+/// assert_eq!(parser.parse("90"), Ok(FloatDuration::seconds(90.0)));
+/// assert_eq!(parser.parse("2 MS"), Ok(FloatDuration::milliseconds(2.0)));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct DurationParser {
+    aliases: Vec<(String, TimeUnit)>,
+    default_unit: Option<TimeUnit>,
+    case_sensitive: bool,
+    decimal_separator: char,
+}
+
+#[cfg(feature = "std")]
+impl DurationParser {
+    /// Create a parser accepting the same input as `FloatDuration::from_str`.
+    pub fn new() -> DurationParser {
+        DurationParser {
+            aliases: Vec::new(),
+            default_unit: None,
+            case_sensitive: true,
+            decimal_separator: '.',
+        }
+    }
+
+    /// Accept `alias` as a name of `unit`, in addition to the built-in names.
+    ///
+    /// Aliases are checked before the built-in names, so they may also redefine
+    /// them.
+    pub fn alias(mut self, alias: &str, unit: TimeUnit) -> DurationParser {
+        self.aliases.push((alias.to_owned(), unit));
+        self
+    }
+    /// Interpret numbers without a unit as a number of `unit`.
+    ///
+    /// Without a default unit, such input is rejected with
+    /// `ParseDurationError::MissingUnit`.
+    pub fn default_unit(mut self, unit: TimeUnit) -> DurationParser {
+        self.default_unit = Some(unit);
+        self
+    }
+    /// Set whether unit names must match case exactly. The default is `true`.
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> DurationParser {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+    /// Use `separator` between the integer and fractional part of numbers instead
+    /// of `.`.
+    pub fn decimal_separator(mut self, separator: char) -> DurationParser {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Parse a duration from a number followed by a unit, or from a sum of several
+    /// of these.
+    ///
+    /// A number without a unit is only accepted as the last term.
+    ///
+    /// # Errors
+    /// Returns the same errors as `FloatDuration::from_str`.
+    pub fn parse<F>(&self, s: &str) -> Result<GenericFloatDuration<F>, ParseDurationError>
+        where F: Float + FromStr
+    {
+        let separator = self.decimal_separator;
+        parse_terms(s,
+                    separator,
+                    self.default_unit,
+                    |number| {
+            let value = if separator == '.' {
+                number.parse()
+            } else {
+                number.replace(separator, ".").parse()
+            };
+            value.map_err(|_| ParseDurationError::InvalidNumber)
+        },
+                    |unit| self.unit(unit))
+    }
+
+    /// Look up the unit called `name`.
+    fn unit(&self, name: &str) -> Result<TimeUnit, ParseDurationError> {
+        let matches = |alias: &str| if self.case_sensitive {
+            alias == name
+        } else {
+            alias.chars().flat_map(char::to_lowercase).eq(name.chars().flat_map(char::to_lowercase))
+        };
+        if let Some(&(_, unit)) = self.aliases.iter().rev().find(|alias| matches(&alias.0)) {
+            return Ok(unit);
+        }
+        if self.case_sensitive {
+            name.parse()
+        } else {
+            name.to_lowercase().parse()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DurationParser {
+    fn default() -> DurationParser {
+        DurationParser::new()
+    }
+}
+
+/// The meaning of a clock-style duration with only two fields, such as `05:30`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TwoFieldClock {
+    /// Minutes and seconds, as in media players: `05:30` is five and a half
+    /// minutes.
+    #[default]
+    MinutesSeconds,
+    /// Hours and minutes, as on a wall clock: `05:30` is five and a half hours.
+    HoursMinutes,
+}
+
+impl<F: Float + FromStr> GenericFloatDuration<F> {
+    /// Parse a duration written like a clock, with fields separated by colons.
+    ///
+    /// Three fields are hours, minutes, and seconds, as in `1:30:15.5`, and four
+    /// fields are days, hours, minutes, and seconds, as in `3:12:05:00`. Two fields
+    /// are minutes and seconds; use `from_clock_as` to read them as hours and
+    /// minutes instead. This accepts the output of `FloatDuration::format_clock`.
+    ///
+    /// # Errors
+    /// Returns `ParseDurationError::InvalidFormat` if there are not two to four
+    /// fields, or if a field after the first is out of range, such as 60 minutes.
+    /// Only the last field may have a fraction.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_clock("1:30:15.5"),
+    ///            Ok(FloatDuration::from_hms(1.0, 30.0, 15.5)));
+    /// assert_eq!(FloatDuration::from_clock("05:30"), Ok(FloatDuration::seconds(330.0)));
+    /// assert_eq!(FloatDuration::from_clock("3:12:05:00"),
+    ///            Ok(FloatDuration::days(3.0) + FloatDuration::from_hms(12.0, 5.0, 0.0)));
+    /// ```
+    pub fn from_clock(s: &str) -> Result<GenericFloatDuration<F>, ParseDurationError> {
+        GenericFloatDuration::from_clock_as(s, TwoFieldClock::MinutesSeconds)
+    }
+
+    /// Parse a duration written like a clock, reading two fields as `two_fields`
+    /// says.
+    ///
+    /// # Errors
+    /// Returns the same errors as `from_clock`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use float_duration::parse::TwoFieldClock;
+    ///
+    /// assert_eq!(FloatDuration::from_clock_as("05:30", TwoFieldClock::HoursMinutes),
+    ///            Ok(FloatDuration::hours(5.5)));
+    /// ```
+    pub fn from_clock_as(s: &str,
+                         two_fields: TwoFieldClock)
+                         -> Result<GenericFloatDuration<F>, ParseDurationError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+        let (negative, s) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let count = s.split(':').count();
+        let units: &[(TimeUnit, f64)] = match (count, two_fields) {
+            (2, TwoFieldClock::MinutesSeconds) => &[(TimeUnit::Minute, 0.0),
+                                                   (TimeUnit::Second, 60.0)],
+            (2, TwoFieldClock::HoursMinutes) => &[(TimeUnit::Hour, 0.0), (TimeUnit::Minute, 60.0)],
+            (3, _) => &[(TimeUnit::Hour, 0.0), (TimeUnit::Minute, 60.0), (TimeUnit::Second, 60.0)],
+            (4, _) => {
+                &[(TimeUnit::Day, 0.0),
+                  (TimeUnit::Hour, 24.0),
+                  (TimeUnit::Minute, 60.0),
+                  (TimeUnit::Second, 60.0)]
+            }
+            _ => return Err(ParseDurationError::InvalidFormat),
+        };
+
+        let mut total: Option<GenericFloatDuration<F>> = None;
+        for (index, (field, &(unit, limit))) in s.split(':').zip(units).enumerate() {
+            let last = index + 1 == count;
+            let valid = !field.is_empty() &&
+                        field.bytes().all(|b| b.is_ascii_digit() || (last && b == b'.'));
+            if !valid {
+                return Err(ParseDurationError::InvalidNumber);
+            }
+            let value = field.parse::<F>().map_err(|_| ParseDurationError::InvalidNumber)?;
+            if index > 0 && value >= float(limit) {
+                return Err(ParseDurationError::InvalidFormat);
+            }
+            let term = GenericFloatDuration::from_unit(value, unit);
+            total = Some(total.map_or(term, |total| total + term));
+        }
+
+        let total = total.ok_or(ParseDurationError::InvalidFormat)?;
+        Ok(if negative { -total } else { total })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + FromStr> GenericFloatDuration<F> {
+    /// Read a duration from the environment variable `name`, in the format accepted
+    /// by the `FromStr` impl, such as `TIMEOUT=1m 30s`.
+    ///
+    /// # Errors
+    /// Returns an `EnvDurationError` naming the variable if it is not set, is not
+    /// valid Unicode, or does not contain a valid duration.
+    ///
+    /// ```rust
+    /// use float_duration::{EnvDurationError, FloatDuration, ParseDurationError};
+    ///
+    /// std::env::set_var("REQUEST_TIMEOUT", "1m 30s");
+    /// assert_eq!(FloatDuration::from_env("REQUEST_TIMEOUT"), Ok(FloatDuration::seconds(90.0)));
+    ///
+    /// std::env::set_var("REQUEST_TIMEOUT", "soon");
+    /// assert_eq!(FloatDuration::from_env("REQUEST_TIMEOUT"),
+    ///            Err(EnvDurationError::Invalid {
+    ///                name: "REQUEST_TIMEOUT".to_string(),
+    ///                value: "soon".to_string(),
+    ///                error: ParseDurationError::InvalidNumber,
+    ///            }));
+    /// ```
+    pub fn from_env(name: &str) -> Result<GenericFloatDuration<F>, EnvDurationError> {
+        match env::var(name) {
+            Ok(value) => {
+                value.parse().map_err(|error| {
+                    EnvDurationError::Invalid {
+                        name: name.to_string(),
+                        value,
+                        error,
+                    }
+                })
+            }
+            Err(VarError::NotPresent) => Err(EnvDurationError::NotPresent(name.to_string())),
+            Err(VarError::NotUnicode(_)) => Err(EnvDurationError::NotUnicode(name.to_string())),
+        }
+    }
+    /// Read a duration from the environment variable `name`, or return `default` if
+    /// it is not set.
+    ///
+    /// # Errors
+    /// Unlike a missing variable, a variable that is set to an invalid duration is
+    /// an error, so that a typo in the configuration is not silently ignored.
+    pub fn from_env_or(name: &str,
+                       default: GenericFloatDuration<F>)
+                       -> Result<GenericFloatDuration<F>, EnvDurationError> {
+        match GenericFloatDuration::from_env(name) {
+            Err(EnvDurationError::NotPresent(_)) => Ok(default),
+            result => result,
+        }
+    }
+}
+
+impl<F: Float + FromStr> GenericFloatDuration<F> {
+    /// Parse a duration from `s`, or return `default` if it is not valid.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let default = FloatDuration::seconds(30.0);
+    /// assert_eq!(FloatDuration::parse_or("2m", default), FloatDuration::minutes(2.0));
+    /// assert_eq!(FloatDuration::parse_or("", default), default);
+    /// ```
+    pub fn parse_or(s: &str, default: GenericFloatDuration<F>) -> GenericFloatDuration<F> {
+        s.parse().unwrap_or(default)
+    }
+}
+
+/// Parse `s` as a sum of terms, each made of a number followed by a unit.
+///
+/// `number` parses the number of each term, without its sign, and `unit` looks up
+/// the name of its unit. A sign in front of the first term applies to the whole
+/// sum. The last term may leave out its unit if there is a `default_unit`.
+fn parse_terms<F, N, U>(s: &str,
+                        separator: char,
+                        default_unit: Option<TimeUnit>,
+                        number: N,
+                        unit: U)
+                        -> Result<GenericFloatDuration<F>, ParseDurationError>
+    where F: Float,
+          N: Fn(&str) -> Result<F, ParseDurationError>,
+          U: Fn(&str) -> Result<TimeUnit, ParseDurationError>
+{
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    let (negative, mut rest) = match s.as_bytes()[0] {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let mut total: Option<GenericFloatDuration<F>> = None;
+    loop {
+        let (digits, tail) = split_number(rest, separator);
+        if digits.is_empty() && total.is_some() {
+            // Text after a complete term is an unknown unit, as in `5 hours ago`.
+            return Err(ParseDurationError::UnknownUnit);
+        }
+        if digits.starts_with('+') || digits.starts_with('-') {
+            return Err(ParseDurationError::InvalidNumber);
+        }
+        let value = number(digits)?;
+
+        let tail = tail.trim_start();
+        let end = tail.find(|c: char| c.is_whitespace() || c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        let (name, tail) = tail.split_at(end);
+        let tail = tail.trim_start();
+        let term_unit = if !name.is_empty() {
+            unit(name)?
+        } else if tail.is_empty() {
+            default_unit.ok_or(ParseDurationError::MissingUnit)?
+        } else {
+            return Err(ParseDurationError::MissingUnit);
+        };
+
+        let term = GenericFloatDuration::from_unit(value, term_unit);
+        let sum = total.map_or(term, |total| total + term);
+        if tail.is_empty() {
+            return Ok(if negative { -sum } else { sum });
+        }
+        total = Some(sum);
+        rest = tail;
+    }
+}
+
+/// Split `s` into a leading floating-point literal, using `separator` as the
+/// decimal separator, and the remaining text.
+fn split_number(s: &str, separator: char) -> (&str, &str) {
+    let bytes = s.as_bytes();
+    let mut end = 0;
+
+    if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+        end += 1;
+    }
+    if s[end..].starts_with("inf") || s[end..].starts_with("NaN") {
+        return s.split_at(end + 3);
+    }
+    loop {
+        if end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        } else if s[end..].starts_with(separator) {
+            end += separator.len_utf8();
+        } else {
+            break;
+        }
+    }
+    // Only treat an `e` as an exponent if digits follow it, otherwise it
+    // is the start of the unit.
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut exp_end = end + 1;
+        if exp_end < bytes.len() && (bytes[exp_end] == b'+' || bytes[exp_end] == b'-') {
+            exp_end += 1;
+        }
+        if exp_end < bytes.len() && bytes[exp_end].is_ascii_digit() {
+            end = exp_end;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+    }
+
+    s.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::{FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1.5h".parse(), Ok(FloatDuration::hours(1.5)));
+        assert_eq!("90 minutes".parse(), Ok(FloatDuration::minutes(90.0)));
+        assert_eq!("250ms".parse(), Ok(FloatDuration::milliseconds(250.0)));
+        assert_eq!("  3 days ".parse(), Ok(FloatDuration::days(3.0)));
+        assert_eq!("2w".parse(), Ok(FloatDuration::weeks(2.0)));
+        assert_eq!("3 months".parse(), Ok(FloatDuration::months(3.0)));
+        assert_eq!("-2.5s".parse(), Ok(FloatDuration::seconds(-2.5)));
+        assert_eq!("+10us".parse(), Ok(FloatDuration::microseconds(10.0)));
+        assert_eq!("15µs".parse(), Ok(FloatDuration::microseconds(15.0)));
+        assert_eq!("1e3ns".parse(), Ok(FloatDuration::nanoseconds(1000.0)));
+        assert_eq!("2.5e-1 hours".parse(), Ok(FloatDuration::hours(0.25)));
+        assert_eq!(".5y".parse(), Ok(FloatDuration::years(0.5)));
+        assert_eq!("1h30m".parse(), Ok(FloatDuration::minutes(90.0)));
+        assert_eq!("-1 hour 30 minutes".parse(), Ok(FloatDuration::minutes(-90.0)));
+        assert_eq!("-inf seconds".parse(), Ok(FloatDuration::seconds(f64::NEG_INFINITY)));
+
+        assert_eq!("1.5h".parse(), Ok(FloatDuration32::hours(1.5)));
+        assert_eq!("250 ms".parse(), Ok(FloatDuration32::milliseconds(250.0)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<FloatDuration>(), Err(ParseDurationError::Empty));
+        assert_eq!("   ".parse::<FloatDuration>(), Err(ParseDurationError::Empty));
+        assert_eq!("10".parse::<FloatDuration>(),
+                   Err(ParseDurationError::MissingUnit));
+        assert_eq!("h".parse::<FloatDuration>(),
+                   Err(ParseDurationError::InvalidNumber));
+        assert_eq!("1.2.3s".parse::<FloatDuration>(),
+                   Err(ParseDurationError::InvalidNumber));
+        assert_eq!("5 fortnights".parse::<FloatDuration>(),
+                   Err(ParseDurationError::UnknownUnit));
+        assert_eq!("5 hours ago".parse::<FloatDuration>(),
+                   Err(ParseDurationError::UnknownUnit));
+        assert_eq!("1h -5m".parse::<FloatDuration>(),
+                   Err(ParseDurationError::InvalidNumber));
+        assert_eq!("1.5 2h".parse::<FloatDuration>(),
+                   Err(ParseDurationError::MissingUnit));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parser() {
+        let parser = DurationParser::new();
+        assert_eq!(parser.parse("1.5h"), Ok(FloatDuration::hours(1.5)));
+        assert_eq!(parser.parse::<f64>("10"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(parser.parse::<f64>("10 H"), Err(ParseDurationError::UnknownUnit));
+
+        let parser = parser.alias("fortnight", TimeUnit::Week)
+            .alias("Std", TimeUnit::Hour)
+            .default_unit(TimeUnit::Millisecond)
+            .decimal_separator(',');
+        assert_eq!(parser.parse("  250 "), Ok(FloatDuration32::milliseconds(250.0)));
+        assert_eq!(parser.parse("-0,5 Std"), Ok(FloatDuration::hours(-0.5)));
+        assert_eq!(parser.parse("2fortnight"), Ok(FloatDuration::weeks(2.0)));
+        assert_eq!(parser.parse("1e3"), Ok(FloatDuration::seconds(1.0)));
+        assert_eq!(parser.parse::<f64>("1.5h"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(parser.parse::<f64>("1,2,3h"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(parser.parse::<f64>("3 std"), Err(ParseDurationError::UnknownUnit));
+
+        let parser = parser.case_sensitive(false).alias("m", TimeUnit::Month);
+        assert_eq!(parser.parse("3 STD"), Ok(FloatDuration::hours(3.0)));
+        assert_eq!(parser.parse("4 Days"), Ok(FloatDuration::days(4.0)));
+        assert_eq!(parser.parse("2 M"), Ok(FloatDuration::months(2.0)));
+        assert_eq!(parser.parse::<f64>(""), Err(ParseDurationError::Empty));
+    }
+
+    /// Yield `count` pseudo-random durations of every magnitude, from the bits of
+    /// an xorshift generator.
+    fn random_durations(count: usize) -> Vec<FloatDuration> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..count).map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                f64::from_bits(state)
+            })
+            .filter(|secs| secs.is_finite())
+            .map(FloatDuration::seconds)
+            .collect()
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for d in random_durations(20_000) {
+            let s = d.to_string();
+            assert_eq!(s.parse(), Ok(d), "{}", s);
+            let d = FloatDuration32::seconds(d.as_seconds() as f32);
+            if d.as_seconds().is_finite() {
+                assert_eq!(d.to_string().parse(), Ok(d), "{}", d);
+            }
+        }
+        for &secs in &[0.1, 1.0 / 3.0, 59.999999, 86399.99999, 1.0e-9, 1.0e-10, 7.0e12] {
+            for &d in &[FloatDuration::seconds(secs), FloatDuration::seconds(-secs)] {
+                assert_eq!(d.to_string().parse(), Ok(d), "{}", d);
+            }
+        }
+        let inf = FloatDuration::seconds(f64::INFINITY);
+        assert_eq!(inf.to_string().parse(), Ok(inf));
+        assert_eq!((-inf).to_string().parse(), Ok(-inf));
+        assert!(FloatDuration::seconds(f64::NAN).to_string().parse::<FloatDuration>().unwrap()
+            .as_seconds()
+            .is_nan());
+    }
+
+    #[test]
+    fn test_compound_round_trip() {
+        for d in random_durations(20_000) {
+            let s = d.format_compound().to_string();
+            assert_eq!(s.parse(), Ok(d), "{}", s);
+        }
+        for &secs in &[1.0e-7, 0.9999999, 61.7, 12.3456789, 1.6e24, 1.0e300, f64::MAX, 5.0e-324] {
+            for &d in &[FloatDuration::seconds(secs), FloatDuration::seconds(-secs)] {
+                let s = d.format_compound().to_string();
+                assert_eq!(s.parse(), Ok(d), "{}", s);
+            }
+        }
+        assert_eq!("2d 3h 5m 1.25s".parse(),
+                   Ok(FloatDuration::days(2.0) + FloatDuration::hours(3.0) +
+                      FloatDuration::minutes(5.0) + FloatDuration::seconds(1.25)));
+    }
+
+    #[test]
+    fn test_from_clock() {
+        assert_eq!(FloatDuration::from_clock("01:30:15.500"),
+                   Ok(FloatDuration::from_hms(1.0, 30.0, 15.5)));
+        assert_eq!(FloatDuration::from_clock("-0:00:01"), Ok(FloatDuration::seconds(-1.0)));
+        assert_eq!(FloatDuration::from_clock("90:00"), Ok(FloatDuration::minutes(90.0)));
+        assert_eq!(FloatDuration32::from_clock("1:02:00:00.000"), Ok(FloatDuration32::hours(26.0)));
+        assert_eq!(FloatDuration::from_clock_as("1:30.5", TwoFieldClock::HoursMinutes),
+                   Ok(FloatDuration::minutes(90.5)));
+
+        let d = FloatDuration::seconds(5025.25);
+        assert_eq!(FloatDuration::from_clock(&format!("{:.2}", d.format_clock().with_sign())),
+                   Ok(d));
+
+        assert_eq!(FloatDuration::from_clock(""), Err(ParseDurationError::Empty));
+        assert_eq!(FloatDuration::from_clock("15"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_clock("1:2:3:4:5"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_clock("1:60"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_clock("1:24:00:00"),
+                   Err(ParseDurationError::InvalidFormat));
+        assert_eq!(FloatDuration::from_clock("1.5:30"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(FloatDuration::from_clock("1::30"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(FloatDuration::from_clock("1:-30"), Err(ParseDurationError::InvalidNumber));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_env() {
+        use std::env;
+        use error::EnvDurationError;
+
+        env::set_var("FLOAT_DURATION_TEST_FROM_ENV", " 250ms ");
+        assert_eq!(FloatDuration::from_env("FLOAT_DURATION_TEST_FROM_ENV"),
+                   Ok(FloatDuration::milliseconds(250.0)));
+        env::set_var("FLOAT_DURATION_TEST_FROM_ENV", "5 parsecs");
+        let error = FloatDuration::from_env("FLOAT_DURATION_TEST_FROM_ENV").unwrap_err();
+        assert_eq!(error.to_string(),
+                   "The environment variable `FLOAT_DURATION_TEST_FROM_ENV` has an invalid \
+                    duration \"5 parsecs\": The duration string contained an unknown unit.");
+        assert_eq!(FloatDuration::from_env_or("FLOAT_DURATION_TEST_FROM_ENV",
+                                              FloatDuration::zero()),
+                   Err(error));
+
+        let missing = "FLOAT_DURATION_TEST_MISSING";
+        assert_eq!(FloatDuration::from_env(missing),
+                   Err(EnvDurationError::NotPresent(missing.to_string())));
+        assert_eq!(FloatDuration::from_env_or(missing, FloatDuration::seconds(3.0)),
+                   Ok(FloatDuration::seconds(3.0)));
+    }
+
+    #[test]
+    fn test_parse_or() {
+        let default = FloatDuration::seconds(1.0);
+        assert_eq!(FloatDuration::parse_or("1h 30m", default), FloatDuration::minutes(90.0));
+        assert_eq!(FloatDuration::parse_or("1 fortnight", default), default);
+    }
+}
@@ -0,0 +1,349 @@
+//! Retry policies: the delays to wait between the attempts of a failing operation.
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use backoff::Backoff;
+use duration::FloatDuration;
+
+/// A sequence of delays between retries, which may give up after some attempts.
+///
+/// Policies are built from a base sequence, such as `Fixed`, `Linear`, `Backoff` or
+/// `Fibonacci`, and combinators adding limits and jitter:
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::retry::{Fibonacci, RetryPolicy};
+///
+/// let policy = Fibonacci::new(FloatDuration::seconds(1.0))
+///     .cap_delay(FloatDuration::seconds(4.0))
+///     .limit_elapsed(FloatDuration::seconds(12.0));
+///
+/// let delays: Vec<f64> = policy.delays().map(|d| d.as_seconds()).collect();
+/// assert_eq!(delays, vec![1.0, 1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub trait RetryPolicy {
+    /// Return the delay before the retry numbered `attempt`, or `None` to stop
+    /// retrying.
+    ///
+    /// Attempts are numbered from zero and requested in order, since some policies
+    /// keep track of the previous delays. Call `reset` to start over.
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration>;
+    /// Forget the previous delays, such as after a successful attempt.
+    fn reset(&mut self) {}
+
+    /// Stop retrying after `max_attempts` delays.
+    fn limit_attempts(self, max_attempts: usize) -> MaxAttempts<Self>
+        where Self: Sized
+    {
+        MaxAttempts {
+            policy: self,
+            max_attempts,
+        }
+    }
+    /// Cap each delay at `max_delay`.
+    fn cap_delay(self, max_delay: FloatDuration) -> MaxDelay<Self>
+        where Self: Sized
+    {
+        MaxDelay {
+            policy: self,
+            max_delay,
+        }
+    }
+    /// Stop retrying once the next delay would bring the total time spent waiting
+    /// over `max_elapsed`.
+    fn limit_elapsed(self, max_elapsed: FloatDuration) -> MaxElapsed<Self>
+        where Self: Sized
+    {
+        MaxElapsed {
+            policy: self,
+            max_elapsed,
+            elapsed: FloatDuration::zero(),
+        }
+    }
+    /// Randomize each delay with `jitter`, using `rng`.
+    #[cfg(feature = "rand")]
+    fn jitter<R: Rng>(self, jitter: Jitter, rng: R) -> Jittered<Self, R>
+        where Self: Sized
+    {
+        Jittered {
+            policy: self,
+            jitter,
+            rng,
+        }
+    }
+
+    /// Return an iterator over the delays of the policy, from the first attempt.
+    fn delays(mut self) -> PolicyDelays<Self>
+        where Self: Sized
+    {
+        self.reset();
+        PolicyDelays {
+            policy: self,
+            attempt: 0,
+        }
+    }
+}
+
+impl RetryPolicy for Backoff {
+    /// Exponentially increasing delays, as described by the `Backoff`.
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        self.delay(attempt)
+    }
+}
+
+/// The same delay before every retry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fixed {
+    delay: FloatDuration,
+}
+
+impl Fixed {
+    /// Create a new `Fixed` policy waiting `delay` before every retry.
+    pub fn new(delay: FloatDuration) -> Fixed {
+        Fixed { delay }
+    }
+}
+
+impl RetryPolicy for Fixed {
+    fn next_delay(&mut self, _attempt: usize) -> Option<FloatDuration> {
+        Some(self.delay)
+    }
+}
+
+/// Delays growing by the same increment before every retry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Linear {
+    initial: FloatDuration,
+    increment: FloatDuration,
+}
+
+impl Linear {
+    /// Create a new `Linear` policy waiting `initial` before the first retry, and
+    /// `increment` longer before each following one.
+    pub fn new(initial: FloatDuration, increment: FloatDuration) -> Linear {
+        Linear { initial, increment }
+    }
+}
+
+impl RetryPolicy for Linear {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        Some(self.initial + self.increment * attempt as f64)
+    }
+}
+
+/// Delays following the Fibonacci sequence: 1, 1, 2, 3, 5, 8... times a unit.
+///
+/// These grow more slowly than doubling delays, by a factor of about 1.6.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fibonacci {
+    unit: FloatDuration,
+}
+
+impl Fibonacci {
+    /// Create a new `Fibonacci` policy waiting `unit` before the first two retries.
+    pub fn new(unit: FloatDuration) -> Fibonacci {
+        Fibonacci { unit }
+    }
+}
+
+impl RetryPolicy for Fibonacci {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        let (mut current, mut next) = (1.0f64, 1.0f64);
+        for _ in 0..attempt {
+            if current.is_infinite() {
+                break;
+            }
+            let sum = current + next;
+            current = next;
+            next = sum;
+        }
+        Some(self.unit * current)
+    }
+}
+
+/// A policy giving up after a number of retries.
+///
+/// This type is returned by `RetryPolicy::limit_attempts`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxAttempts<P> {
+    policy: P,
+    max_attempts: usize,
+}
+
+impl<P: RetryPolicy> RetryPolicy for MaxAttempts<P> {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        if attempt < self.max_attempts {
+            self.policy.next_delay(attempt)
+        } else {
+            None
+        }
+    }
+    fn reset(&mut self) {
+        self.policy.reset();
+    }
+}
+
+/// A policy with delays capped at a maximum.
+///
+/// This type is returned by `RetryPolicy::cap_delay`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxDelay<P> {
+    policy: P,
+    max_delay: FloatDuration,
+}
+
+impl<P: RetryPolicy> RetryPolicy for MaxDelay<P> {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        self.policy.next_delay(attempt).map(|delay| delay.min(self.max_delay))
+    }
+    fn reset(&mut self) {
+        self.policy.reset();
+    }
+}
+
+/// A policy giving up once the total time spent waiting would exceed a maximum.
+///
+/// This type is returned by `RetryPolicy::limit_elapsed`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxElapsed<P> {
+    policy: P,
+    max_elapsed: FloatDuration,
+    elapsed: FloatDuration,
+}
+
+impl<P: RetryPolicy> RetryPolicy for MaxElapsed<P> {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        let delay = self.policy.next_delay(attempt)?;
+        if self.elapsed + delay > self.max_elapsed {
+            return None;
+        }
+        self.elapsed += delay;
+        Some(delay)
+    }
+    fn reset(&mut self) {
+        self.elapsed = FloatDuration::zero();
+        self.policy.reset();
+    }
+}
+
+/// How `Jittered` randomizes each delay.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jitter {
+    /// A random delay between zero and the delay, as `FloatDuration::jitter_full`.
+    Full,
+    /// A random delay between half the delay and the delay, as
+    /// `FloatDuration::jitter_equal`.
+    Equal,
+}
+
+/// A policy with randomized delays.
+///
+/// This type is returned by `RetryPolicy::jitter`.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jittered<P, R> {
+    policy: P,
+    jitter: Jitter,
+    rng: R,
+}
+
+#[cfg(feature = "rand")]
+impl<P: RetryPolicy, R: Rng> RetryPolicy for Jittered<P, R> {
+    fn next_delay(&mut self, attempt: usize) -> Option<FloatDuration> {
+        let delay = self.policy.next_delay(attempt)?;
+        Some(match self.jitter {
+            Jitter::Full => delay.jitter_full(&mut self.rng),
+            Jitter::Equal => delay.jitter_equal(&mut self.rng),
+        })
+    }
+    fn reset(&mut self) {
+        self.policy.reset();
+    }
+}
+
+/// An iterator over the delays of a `RetryPolicy`.
+///
+/// This type is returned by `RetryPolicy::delays`.
+#[derive(Debug, Clone)]
+pub struct PolicyDelays<P> {
+    policy: P,
+    attempt: usize,
+}
+
+impl<P: RetryPolicy> PolicyDelays<P> {
+    /// The number of delays produced so far.
+    pub fn attempts(&self) -> usize {
+        self.attempt
+    }
+    /// Restart the sequence from the first attempt.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+        self.policy.reset();
+    }
+}
+
+impl<P: RetryPolicy> Iterator for PolicyDelays<P> {
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        let delay = self.policy.next_delay(self.attempt)?;
+        self.attempt += 1;
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seconds<P: RetryPolicy>(policy: P, count: usize) -> Vec<f64> {
+        policy.delays().take(count).map(|d| d.as_seconds()).collect()
+    }
+
+    #[test]
+    fn test_policies() {
+        assert_eq!(seconds(Fixed::new(FloatDuration::seconds(2.0)), 3), vec![2.0, 2.0, 2.0]);
+        assert_eq!(seconds(Linear::new(FloatDuration::seconds(1.0), FloatDuration::seconds(0.5)),
+                           4),
+                   vec![1.0, 1.5, 2.0, 2.5]);
+        assert_eq!(seconds(Backoff::new(FloatDuration::seconds(1.0)), 4),
+                   vec![1.0, 2.0, 4.0, 8.0]);
+        assert_eq!(seconds(Fibonacci::new(FloatDuration::seconds(1.0)), 7),
+                   vec![1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0]);
+        let mut fibonacci = Fibonacci::new(FloatDuration::seconds(1.0));
+        assert_eq!(fibonacci.next_delay(5000), Some(FloatDuration::seconds(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_limits() {
+        let policy = Backoff::new(FloatDuration::seconds(1.0));
+        assert_eq!(seconds(policy.clone().limit_attempts(2), 10), vec![1.0, 2.0]);
+        assert_eq!(seconds(policy.clone().cap_delay(FloatDuration::seconds(3.0)), 4),
+                   vec![1.0, 2.0, 3.0, 3.0]);
+
+        let mut delays = Fixed::new(FloatDuration::seconds(1.0))
+            .limit_elapsed(FloatDuration::seconds(2.5))
+            .delays();
+        assert_eq!(delays.by_ref().count(), 2);
+        assert_eq!(delays.attempts(), 2);
+        delays.reset();
+        assert_eq!(delays.count(), 2);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_jitter() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let policy = Fixed::new(FloatDuration::seconds(1.0))
+            .jitter(Jitter::Equal, SmallRng::seed_from_u64(3))
+            .limit_attempts(100);
+        let delays: Vec<FloatDuration> = policy.delays().collect();
+        assert_eq!(delays.len(), 100);
+        assert!(delays.iter().all(|&d| d >= FloatDuration::seconds(0.5)
+                                       && d <= FloatDuration::seconds(1.0)));
+        assert!(delays.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+}
@@ -0,0 +1,171 @@
+//! Time scales, the factors by which simulated time runs faster or slower.
+use core::fmt;
+use core::ops;
+
+use duration::FloatDuration;
+
+/// A dimensionless factor applied to durations, such as the speed of a game clock
+/// or of a replay.
+///
+/// `TimeScale` makes the intent of multiplying a duration by a speed explicit.
+/// Scales compose by multiplication, so a slow-motion effect can be applied on top
+/// of a global speed setting.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, TimeScale};
+///
+/// let game_speed = TimeScale::new(2.0);
+/// let slow_motion = TimeScale::new(0.25);
+/// let frame_time = FloatDuration::milliseconds(16.0);
+///
+/// assert_eq!(frame_time * (game_speed * slow_motion), FloatDuration::milliseconds(8.0));
+/// assert_eq!(frame_time * TimeScale::PAUSED, FloatDuration::zero());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TimeScale {
+    factor: f64,
+}
+
+impl TimeScale {
+    /// The scale leaving durations unchanged.
+    pub const IDENTITY: TimeScale = TimeScale { factor: 1.0 };
+    /// The scale stopping time, turning every duration into zero.
+    pub const PAUSED: TimeScale = TimeScale { factor: 0.0 };
+
+    /// Create a new `TimeScale` multiplying durations by `factor`.
+    #[inline]
+    pub const fn new(factor: f64) -> TimeScale {
+        TimeScale { factor }
+    }
+
+    /// Return the factor durations are multiplied by.
+    #[inline]
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+    /// Return whether this scale stops time.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.factor == 0.0
+    }
+    /// Return the scale undoing this one.
+    ///
+    /// The inverse of `PAUSED` is an infinite scale.
+    #[inline]
+    pub fn inverse(&self) -> TimeScale {
+        TimeScale { factor: 1.0 / self.factor }
+    }
+}
+
+impl Default for TimeScale {
+    /// The identity scale.
+    #[inline]
+    fn default() -> TimeScale {
+        TimeScale::IDENTITY
+    }
+}
+
+impl From<f64> for TimeScale {
+    #[inline]
+    fn from(factor: f64) -> TimeScale {
+        TimeScale::new(factor)
+    }
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match fmt.precision() {
+            Some(decimals) => write!(fmt, "{:.*}x", decimals, self.factor),
+            None => write!(fmt, "{}x", self.factor),
+        }
+    }
+}
+
+impl ops::Mul<TimeScale> for TimeScale {
+    type Output = TimeScale;
+
+    /// The scale applying both `self` and `rhs`.
+    #[inline]
+    fn mul(self, rhs: TimeScale) -> TimeScale {
+        TimeScale { factor: self.factor * rhs.factor }
+    }
+}
+impl ops::Mul<TimeScale> for FloatDuration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn mul(self, rhs: TimeScale) -> FloatDuration {
+        self * rhs.factor
+    }
+}
+impl ops::Mul<FloatDuration> for TimeScale {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn mul(self, rhs: FloatDuration) -> FloatDuration {
+        rhs * self.factor
+    }
+}
+impl ops::Div<TimeScale> for FloatDuration {
+    type Output = FloatDuration;
+
+    /// Undo the scale `rhs`, such as to find the real time a scaled span took.
+    #[inline]
+    fn div(self, rhs: TimeScale) -> FloatDuration {
+        self / rhs.factor
+    }
+}
+
+impl ops::MulAssign<TimeScale> for TimeScale {
+    #[inline]
+    fn mul_assign(&mut self, rhs: TimeScale) {
+        self.factor *= rhs.factor;
+    }
+}
+impl ops::MulAssign<TimeScale> for FloatDuration {
+    #[inline]
+    fn mul_assign(&mut self, rhs: TimeScale) {
+        *self = *self * rhs.factor;
+    }
+}
+impl ops::DivAssign<TimeScale> for FloatDuration {
+    #[inline]
+    fn div_assign(&mut self, rhs: TimeScale) {
+        *self = *self / rhs.factor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let d = FloatDuration::seconds(3.0);
+        assert_eq!(d * TimeScale::IDENTITY, d);
+        assert_eq!(TimeScale::new(0.5) * d, FloatDuration::seconds(1.5));
+        assert_eq!(d / TimeScale::new(0.5), FloatDuration::seconds(6.0));
+        assert_eq!(TimeScale::new(4.0) * TimeScale::new(0.5), TimeScale::new(2.0));
+        assert_eq!(TimeScale::new(4.0).inverse(), TimeScale::new(0.25));
+        assert_eq!(TimeScale::default(), TimeScale::IDENTITY);
+        assert_eq!(TimeScale::from(3.0).factor(), 3.0);
+        assert!(TimeScale::PAUSED.is_paused());
+        assert!(TimeScale::PAUSED.inverse().factor().is_infinite());
+
+        let mut scaled = d;
+        scaled *= TimeScale::new(2.0);
+        assert_eq!(scaled, FloatDuration::seconds(6.0));
+        scaled /= TimeScale::new(4.0);
+        assert_eq!(scaled, FloatDuration::seconds(1.5));
+
+        let mut scale = TimeScale::new(2.0);
+        scale *= TimeScale::PAUSED;
+        assert_eq!(scale, TimeScale::PAUSED);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", TimeScale::new(1.5)), "1.5x");
+        assert_eq!(format!("{:.2}", TimeScale::IDENTITY), "1.00x");
+    }
+}
@@ -0,0 +1,296 @@
+//! Modules for `#[serde(with = "...")]` that encode a `FloatDuration` in other
+//! formats than the default number of seconds.
+//!
+//! ```rust,ignore
+//! use float_duration::FloatDuration;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Request {
+//!     #[serde(with = "float_duration::serde::milliseconds_u64")]
+//!     timeout: FloatDuration,
+//!     #[serde(with = "float_duration::serde::iso8601")]
+//!     retention: FloatDuration,
+//! }
+//! ```
+#[cfg(feature = "humantime")]
+pub use interop::humantime::serde as humantime;
+
+/// Encode a duration as a floating-point number of seconds.
+///
+/// This is the default encoding of `FloatDuration`, except that integers are
+/// accepted as well when deserializing.
+pub mod seconds_f64 {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use num_traits::Float;
+    use serde_crate::{de, Deserializer, Serializer};
+
+    use duration::{float, GenericFloatDuration};
+
+    /// Serialize a duration as a number of seconds.
+    pub fn serialize<F, S>(duration: &GenericFloatDuration<F>,
+                           serializer: S)
+                           -> Result<S::Ok, S::Error>
+        where F: Float,
+              S: Serializer
+    {
+        serializer.serialize_f64(duration.as_seconds().to_f64().unwrap_or(f64::NAN))
+    }
+
+    /// Deserialize a duration from a number of seconds.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<GenericFloatDuration<F>, D::Error>
+        where F: Float,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_f64(SecondsVisitor { marker: PhantomData })
+    }
+
+    struct SecondsVisitor<F> {
+        marker: PhantomData<F>,
+    }
+
+    impl<'de, F: Float> de::Visitor<'de> for SecondsVisitor<F> {
+        type Value = GenericFloatDuration<F>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number of seconds")
+        }
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<GenericFloatDuration<F>, E> {
+            Ok(GenericFloatDuration::seconds(float(value)))
+        }
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<GenericFloatDuration<F>, E> {
+            self.visit_f64(value as f64)
+        }
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<GenericFloatDuration<F>, E> {
+            self.visit_f64(value as f64)
+        }
+    }
+
+    /// The JSON schema of this representation, for `#[schemars(schema_with = "...")]`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        ::schemars::json_schema!({
+            "type": "number",
+            "description": "A duration in seconds."
+        })
+    }
+}
+
+/// Encode a duration as a whole, non-negative number of milliseconds.
+///
+/// Durations are rounded to the nearest millisecond. Negative, non-finite and too
+/// large durations cannot be serialized.
+pub mod milliseconds_u64 {
+    use num_traits::Float;
+    use serde_crate::{ser, Deserialize, Deserializer, Serializer};
+
+    use duration::GenericFloatDuration;
+
+    /// Serialize a duration as a number of milliseconds.
+    ///
+    /// # Errors
+    /// Returns an error if the rounded duration is negative, not finite, or does
+    /// not fit in a `u64`.
+    pub fn serialize<F, S>(duration: &GenericFloatDuration<F>,
+                           serializer: S)
+                           -> Result<S::Ok, S::Error>
+        where F: Float,
+              S: Serializer
+    {
+        let millis = duration.as_milliseconds().to_f64().unwrap_or(f64::NAN).round();
+        if millis >= 0.0 && millis < u64::MAX as f64 {
+            serializer.serialize_u64(millis as u64)
+        } else {
+            Err(ser::Error::custom("the duration does not fit in a u64 of milliseconds"))
+        }
+    }
+
+    /// Deserialize a duration from a number of milliseconds.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<GenericFloatDuration<F>, D::Error>
+        where F: Float,
+              D: Deserializer<'de>
+    {
+        let millis = u64::deserialize(deserializer)?;
+        Ok(GenericFloatDuration::from_unit(F::from(millis).unwrap(),
+                                           ::unit::TimeUnit::Millisecond))
+    }
+
+    /// The JSON schema of this representation, for `#[schemars(schema_with = "...")]`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        ::schemars::json_schema!({
+            "type": "integer",
+            "format": "uint64",
+            "minimum": 0,
+            "description": "A duration in milliseconds."
+        })
+    }
+}
+
+/// Encode a duration as an ISO 8601 duration string, such as `"PT1H30M"`.
+///
+/// See `FloatDuration::to_iso8601` and `FloatDuration::from_iso8601`.
+#[cfg(feature = "std")]
+pub mod iso8601 {
+    use core::fmt;
+
+    use serde_crate::{de, Deserializer, Serializer};
+
+    use duration::FloatDuration;
+
+    /// Serialize a duration as an ISO 8601 string.
+    pub fn serialize<S>(duration: &FloatDuration, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(&duration.to_iso8601())
+    }
+
+    /// Deserialize a duration from an ISO 8601 string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<FloatDuration, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(Iso8601Visitor)
+    }
+
+    struct Iso8601Visitor;
+
+    impl<'de> de::Visitor<'de> for Iso8601Visitor {
+        type Value = FloatDuration;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an ISO 8601 duration such as \"PT1H30M\"")
+        }
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<FloatDuration, E> {
+            FloatDuration::from_iso8601(value).map_err(E::custom)
+        }
+    }
+
+    /// The JSON schema of this representation, for `#[schemars(schema_with = "...")]`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        ::schemars::json_schema!({
+            "type": "string",
+            "format": "duration",
+            "description": "An ISO 8601 duration."
+        })
+    }
+}
+
+/// Encode a duration as a struct of whole seconds and nanoseconds, the same way
+/// `serde` encodes a `std::time::Duration`.
+///
+/// Durations are rounded to the nearest nanosecond, and negative, non-finite and
+/// too large durations cannot be serialized.
+pub mod secs_nanos_struct {
+    use core::time::Duration;
+
+    use num_traits::Float;
+    use serde_crate::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use duration::GenericFloatDuration;
+
+    /// Serialize a duration as a struct of seconds and nanoseconds.
+    ///
+    /// # Errors
+    /// Returns an error if the duration cannot be converted to a
+    /// `std::time::Duration`.
+    pub fn serialize<F, S>(duration: &GenericFloatDuration<F>,
+                           serializer: S)
+                           -> Result<S::Ok, S::Error>
+        where F: Float,
+              S: Serializer
+    {
+        duration.to_std().map_err(ser::Error::custom)?.serialize(serializer)
+    }
+
+    /// Deserialize a duration from a struct of seconds and nanoseconds.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<GenericFloatDuration<F>, D::Error>
+        where F: Float,
+              D: Deserializer<'de>
+    {
+        Duration::deserialize(deserializer).map(GenericFloatDuration::from_std)
+    }
+
+    /// The JSON schema of this representation, for `#[schemars(schema_with = "...")]`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        generator.subschema_for::<Duration>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_crate::{Deserialize, Serialize};
+    use serde_test::{Token, assert_de_tokens, assert_ser_tokens, assert_ser_tokens_error,
+                     assert_tokens};
+
+    use duration::FloatDuration;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Seconds(#[serde(with = "super::seconds_f64")] FloatDuration);
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Millis(#[serde(with = "super::milliseconds_u64")] FloatDuration);
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Iso(#[serde(with = "super::iso8601")] FloatDuration);
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SecsNanos(#[serde(with = "super::secs_nanos_struct")] FloatDuration);
+
+    fn newtype(name: &'static str, token: Token) -> [Token; 2] {
+        [Token::NewtypeStruct { name }, token]
+    }
+
+    #[test]
+    fn test_seconds_f64() {
+        assert_tokens(&Seconds(FloatDuration::seconds(-1.5)),
+                      &newtype("Seconds", Token::F64(-1.5)));
+        assert_de_tokens(&Seconds(FloatDuration::seconds(10.0)),
+                         &newtype("Seconds", Token::U32(10)));
+    }
+
+    #[test]
+    fn test_milliseconds_u64() {
+        assert_tokens(&Millis(FloatDuration::seconds(2.5)), &newtype("Millis", Token::U64(2500)));
+        assert_ser_tokens(&Millis(FloatDuration::microseconds(1499.0)),
+                          &newtype("Millis", Token::U64(1)));
+        assert_ser_tokens_error(&Millis(FloatDuration::seconds(-1.0)),
+                                &[Token::NewtypeStruct { name: "Millis" }],
+                                "the duration does not fit in a u64 of milliseconds");
+    }
+
+    #[test]
+    fn test_iso8601() {
+        assert_tokens(&Iso(FloatDuration::minutes(90.0)),
+                      &newtype("Iso", Token::Str("PT1H30M")));
+    }
+
+    #[test]
+    fn test_secs_nanos_struct() {
+        let tokens = [Token::NewtypeStruct { name: "SecsNanos" },
+                      Token::Struct { name: "Duration", len: 2 },
+                      Token::Str("secs"),
+                      Token::U64(3),
+                      Token::Str("nanos"),
+                      Token::U32(250_000_000),
+                      Token::StructEnd];
+        assert_tokens(&SecsNanos(FloatDuration::seconds(3.25)), &tokens);
+        assert_ser_tokens_error(&SecsNanos(FloatDuration::seconds(-3.25)),
+                                &[Token::NewtypeStruct { name: "SecsNanos" }],
+                                "The converted duration value is out of range.");
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        use schemars::SchemaGenerator;
+
+        let mut generator = SchemaGenerator::default();
+        let schema = super::milliseconds_u64::json_schema(&mut generator);
+        assert_eq!(schema.get("type"), Some(&"integer".into()));
+        let schema = super::iso8601::json_schema(&mut generator);
+        assert_eq!(schema.get("format"), Some(&"duration".into()));
+        let schema = super::secs_nanos_struct::json_schema(&mut generator);
+        assert_eq!(schema.get("$ref"), Some(&"#/$defs/Duration".into()));
+        assert!(generator.definitions().contains_key("Duration"));
+    }
+}
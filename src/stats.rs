@@ -0,0 +1,327 @@
+//! Streaming summary statistics and sums over durations.
+use core::iter::FromIterator;
+use core::ops::AddAssign;
+
+use num_traits::Float;
+
+use duration::FloatDuration;
+
+/// A running sum of durations that does not drift.
+///
+/// Adding many small durations to a large running total with `+=` loses the low
+/// bits of each addition, and the error grows with the number of additions.
+/// `DurationSum` uses Neumaier's variant of Kahan summation to carry the lost bits
+/// in a separate compensation term, so the total stays accurate to within a few
+/// rounding errors regardless of how many durations are added.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::stats::DurationSum;
+///
+/// let mut naive = FloatDuration::zero();
+/// let mut sum = DurationSum::new();
+/// for _ in 0..1_000_000 {
+///     naive += FloatDuration::milliseconds(0.1);
+///     sum += FloatDuration::milliseconds(0.1);
+/// }
+/// assert!(naive != FloatDuration::seconds(100.0));
+/// assert_eq!(sum.total(), FloatDuration::seconds(100.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DurationSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl DurationSum {
+    /// Create a new `DurationSum` with a total of zero.
+    pub fn new() -> DurationSum {
+        DurationSum::default()
+    }
+
+    /// Add a duration to the sum.
+    pub fn push(&mut self, duration: FloatDuration) {
+        self.push_seconds(duration.as_seconds());
+    }
+    /// Add the total of `other` to the sum.
+    pub fn merge(&mut self, other: &DurationSum) {
+        self.push_seconds(other.sum);
+        self.push_seconds(other.compensation);
+    }
+    /// Reset the sum to zero.
+    pub fn clear(&mut self) {
+        *self = DurationSum::new();
+    }
+    /// The sum of all added durations.
+    pub fn total(&self) -> FloatDuration {
+        FloatDuration::seconds(self.sum + self.compensation)
+    }
+
+    fn push_seconds(&mut self, x: f64) {
+        let total = self.sum + x;
+        if Float::abs(self.sum) >= Float::abs(x) {
+            self.compensation += (self.sum - total) + x;
+        } else {
+            self.compensation += (x - total) + self.sum;
+        }
+        self.sum = total;
+    }
+}
+
+impl AddAssign<FloatDuration> for DurationSum {
+    fn add_assign(&mut self, rhs: FloatDuration) {
+        self.push(rhs);
+    }
+}
+
+impl Extend<FloatDuration> for DurationSum {
+    fn extend<I: IntoIterator<Item = FloatDuration>>(&mut self, iter: I) {
+        for duration in iter {
+            self.push(duration);
+        }
+    }
+}
+
+impl FromIterator<FloatDuration> for DurationSum {
+    fn from_iter<I: IntoIterator<Item = FloatDuration>>(iter: I) -> DurationSum {
+        let mut sum = DurationSum::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+/// An accumulator of summary statistics over a stream of durations.
+///
+/// `DurationStats` tracks the count, sum, mean, variance, minimum and maximum of
+/// the durations pushed into it in constant memory, using Welford's algorithm
+/// to keep the variance numerically stable.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::stats::DurationStats;
+///
+/// let stats: DurationStats = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].iter()
+///     .map(|&ms| FloatDuration::milliseconds(ms))
+///     .collect();
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(FloatDuration::milliseconds(5.0)));
+/// assert_eq!(stats.min(), Some(FloatDuration::milliseconds(2.0)));
+/// assert_eq!(stats.max(), Some(FloatDuration::milliseconds(9.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DurationStats {
+    count: u64,
+    sum: DurationSum,
+    mean: f64,
+    m2: f64,
+    min: Option<FloatDuration>,
+    max: Option<FloatDuration>,
+}
+
+impl DurationStats {
+    /// Create a new, empty `DurationStats`.
+    pub fn new() -> DurationStats {
+        DurationStats::default()
+    }
+
+    /// Add a single duration to the statistics.
+    pub fn push(&mut self, duration: FloatDuration) {
+        let x = duration.as_seconds();
+        self.count += 1;
+        self.sum.push(duration);
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+    /// Combine the statistics of `other` into `self`, as if every duration pushed
+    /// into `other` had been pushed into `self`.
+    pub fn merge(&mut self, other: &DurationStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * other.count as f64 /
+                   count as f64;
+        self.count = count;
+        self.sum.merge(&other.sum);
+        self.min = self.min.map(|min| min.min(other.min.unwrap()));
+        self.max = self.max.map(|max| max.max(other.max.unwrap()));
+    }
+    /// Discard all recorded durations.
+    pub fn clear(&mut self) {
+        *self = DurationStats::new();
+    }
+
+    /// The number of durations recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+    /// Returns true if no durations have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// The sum of all recorded durations.
+    ///
+    /// The sum is compensated in the same way as `DurationSum`.
+    pub fn sum(&self) -> FloatDuration {
+        self.sum.total()
+    }
+    /// The arithmetic mean of the recorded durations, or `None` if there are none.
+    pub fn mean(&self) -> Option<FloatDuration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(FloatDuration::seconds(self.mean))
+        }
+    }
+    /// The sample variance of the recorded durations, in seconds squared.
+    ///
+    /// Returns `None` if fewer than two durations have been recorded.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+    /// The population variance of the recorded durations, in seconds squared.
+    ///
+    /// Returns `None` if no durations have been recorded.
+    pub fn population_variance(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.m2 / self.count as f64)
+        }
+    }
+    /// The sample standard deviation of the recorded durations.
+    ///
+    /// Returns `None` if fewer than two durations have been recorded.
+    pub fn stddev(&self) -> Option<FloatDuration> {
+        self.variance().map(|var| FloatDuration::seconds(Float::sqrt(var)))
+    }
+    /// The population standard deviation of the recorded durations.
+    ///
+    /// Returns `None` if no durations have been recorded.
+    pub fn population_stddev(&self) -> Option<FloatDuration> {
+        self.population_variance().map(|var| FloatDuration::seconds(Float::sqrt(var)))
+    }
+    /// The smallest recorded duration, or `None` if there are none.
+    pub fn min(&self) -> Option<FloatDuration> {
+        self.min
+    }
+    /// The largest recorded duration, or `None` if there are none.
+    pub fn max(&self) -> Option<FloatDuration> {
+        self.max
+    }
+}
+
+impl Extend<FloatDuration> for DurationStats {
+    fn extend<I: IntoIterator<Item = FloatDuration>>(&mut self, iter: I) {
+        for duration in iter {
+            self.push(duration);
+        }
+    }
+}
+
+impl FromIterator<FloatDuration> for DurationStats {
+    fn from_iter<I: IntoIterator<Item = FloatDuration>>(iter: I) -> DurationStats {
+        let mut stats = DurationStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<FloatDuration> {
+        [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|&s| FloatDuration::seconds(s))
+            .collect()
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [1.0, 1.0e100, 1.0, -1.0e100];
+        let sum: DurationSum = values.iter().map(|&s| FloatDuration::seconds(s)).collect();
+        assert_eq!(sum.total(), FloatDuration::seconds(2.0));
+        assert_eq!(values.iter().map(|&s| FloatDuration::seconds(s)).sum::<FloatDuration>(),
+                   FloatDuration::zero());
+
+        let mut sum = DurationSum::new();
+        for _ in 0..100_000 {
+            sum += FloatDuration::microseconds(10.0);
+        }
+        assert_eq!(sum.total(), FloatDuration::seconds(1.0));
+
+        let mut merged = DurationSum::new();
+        merged.push(FloatDuration::seconds(1.0));
+        merged.merge(&sum);
+        assert_eq!(merged.total(), FloatDuration::seconds(2.0));
+        merged.clear();
+        assert_eq!(merged.total(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats: DurationStats = sample().into_iter().collect();
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.sum(), FloatDuration::seconds(40.0));
+        assert_eq!(stats.mean(), Some(FloatDuration::seconds(5.0)));
+        assert_eq!(stats.population_variance(), Some(4.0));
+        assert_eq!(stats.population_stddev(), Some(FloatDuration::seconds(2.0)));
+        assert!((stats.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!(stats.min(), Some(FloatDuration::seconds(2.0)));
+        assert_eq!(stats.max(), Some(FloatDuration::seconds(9.0)));
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut stats = DurationStats::new();
+        assert!(stats.is_empty());
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.population_variance(), None);
+        assert_eq!(stats.min(), None);
+
+        stats.push(FloatDuration::seconds(1.0));
+        assert_eq!(stats.mean(), Some(FloatDuration::seconds(1.0)));
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.population_variance(), Some(0.0));
+
+        stats.clear();
+        assert_eq!(stats, DurationStats::new());
+    }
+
+    #[test]
+    fn test_merge() {
+        let all: DurationStats = sample().into_iter().collect();
+        let sample = sample();
+        let (left, right) = sample.split_at(3);
+        let mut merged: DurationStats = left.iter().cloned().collect();
+        merged.merge(&right.iter().cloned().collect());
+
+        assert_eq!(merged.count(), all.count());
+        assert_eq!(merged.sum(), all.sum());
+        assert_eq!(merged.min(), all.min());
+        assert_eq!(merged.max(), all.max());
+        assert!((merged.mean().unwrap() - all.mean().unwrap()).abs() <
+                FloatDuration::nanoseconds(1.0));
+        assert!((merged.variance().unwrap() - all.variance().unwrap()).abs() < 1e-12);
+
+        let mut empty = DurationStats::new();
+        empty.merge(&all);
+        assert_eq!(empty, all);
+    }
+}
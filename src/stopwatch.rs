@@ -0,0 +1,150 @@
+//! A stopwatch for measuring elapsed wall-clock time.
+use std::time::Instant;
+
+use duration::{instant_elapsed, FloatDuration};
+
+/// A stopwatch measuring elapsed time as a `FloatDuration`.
+///
+/// A `Stopwatch` can be started, stopped and resumed any number of times, and
+/// `elapsed` reports the total time it has been running. Laps can be recorded
+/// while the stopwatch runs, each holding the time since the previous lap.
+///
+/// ```rust
+/// use float_duration::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::start_new();
+/// // Do some work...
+/// let first = stopwatch.lap();
+/// // Do some more work...
+/// let second = stopwatch.lap();
+///
+/// assert_eq!(stopwatch.laps(), &[first, second]);
+/// assert!(stopwatch.elapsed() >= second);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    started: Option<Instant>,
+    accumulated: FloatDuration,
+    last_lap: FloatDuration,
+    laps: Vec<FloatDuration>,
+}
+
+impl Stopwatch {
+    /// Create a new, stopped `Stopwatch`.
+    pub fn new() -> Stopwatch {
+        Stopwatch::default()
+    }
+    /// Create a new `Stopwatch` and immediately start it.
+    pub fn start_new() -> Stopwatch {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.start();
+        stopwatch
+    }
+
+    /// Start or resume the stopwatch.
+    ///
+    /// Time spent while stopped is not counted. Calling `start` on a running
+    /// stopwatch has no effect.
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+    /// Stop the stopwatch, keeping the elapsed time and laps.
+    ///
+    /// Calling `stop` on a stopped stopwatch has no effect.
+    pub fn stop(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.accumulated += instant_elapsed(started);
+        }
+    }
+    /// Stop the stopwatch and clear the elapsed time and laps.
+    pub fn reset(&mut self) {
+        *self = Stopwatch::new();
+    }
+    /// Clear the elapsed time and laps, and start the stopwatch again.
+    pub fn restart(&mut self) {
+        self.reset();
+        self.start();
+    }
+
+    /// Returns true if the stopwatch is currently running.
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+    /// Return the total amount of time the stopwatch has been running.
+    pub fn elapsed(&self) -> FloatDuration {
+        match self.started {
+            Some(started) => self.accumulated + instant_elapsed(started),
+            None => self.accumulated,
+        }
+    }
+
+    /// Record a lap and return its duration.
+    ///
+    /// The duration of a lap is the running time since the previous lap, or since the
+    /// stopwatch was first started for the first lap.
+    pub fn lap(&mut self) -> FloatDuration {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.last_lap;
+        self.last_lap = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+    /// Return the laps recorded so far, oldest first.
+    pub fn laps(&self) -> &[FloatDuration] {
+        &self.laps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stopwatch() {
+        let mut stopwatch = Stopwatch::new();
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), FloatDuration::zero());
+
+        stopwatch.start();
+        assert!(stopwatch.is_running());
+        thread::sleep(Duration::from_millis(5));
+        stopwatch.stop();
+        assert!(!stopwatch.is_running());
+
+        let elapsed = stopwatch.elapsed();
+        assert!(elapsed >= FloatDuration::milliseconds(5.0));
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(stopwatch.elapsed(), elapsed);
+
+        stopwatch.start();
+        thread::sleep(Duration::from_millis(5));
+        assert!(stopwatch.elapsed() >= elapsed + FloatDuration::milliseconds(5.0));
+
+        stopwatch.reset();
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_laps() {
+        let mut stopwatch = Stopwatch::start_new();
+        thread::sleep(Duration::from_millis(2));
+        let first = stopwatch.lap();
+        thread::sleep(Duration::from_millis(2));
+        stopwatch.stop();
+        let second = stopwatch.lap();
+
+        assert!(first >= FloatDuration::milliseconds(2.0));
+        assert!(second >= FloatDuration::milliseconds(2.0));
+        assert_eq!(stopwatch.laps(), &[first, second]);
+        assert!((first + second - stopwatch.elapsed()).abs() < FloatDuration::nanoseconds(1.0));
+
+        stopwatch.restart();
+        assert!(stopwatch.is_running());
+        assert!(stopwatch.laps().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+//! Timeouts for `std::sync` primitives given as `FloatDuration`s.
+//!
+//! The extension traits in this module accept timeouts as a `FloatDuration`,
+//! converting them with `FloatDuration::to_std_saturating`: negative and NaN
+//! timeouts expire immediately, and timeouts too large to represent wait for as
+//! long as possible.
+use std::sync::{Condvar, LockResult, MutexGuard, WaitTimeoutResult};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+
+use duration::FloatDuration;
+
+/// Extension methods for `Condvar` taking a `FloatDuration` timeout.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::sync::CondvarExt;
+/// use std::sync::{Condvar, Mutex};
+///
+/// let lock = Mutex::new(false);
+/// let condvar = Condvar::new();
+///
+/// let guard = lock.lock().unwrap();
+/// let (_guard, result) = condvar.wait_timeout_float(guard, FloatDuration::milliseconds(1.0))
+///     .unwrap();
+/// assert!(result.timed_out());
+/// ```
+pub trait CondvarExt {
+    /// Wait on this condition variable for a notification, timing out after
+    /// `timeout`.
+    ///
+    /// This is equivalent to `Condvar::wait_timeout`.
+    fn wait_timeout_float<'a, T>(&self,
+                                 guard: MutexGuard<'a, T>,
+                                 timeout: FloatDuration)
+                                 -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)>;
+    /// Wait on this condition variable while `condition` returns true, timing out
+    /// after `timeout`.
+    ///
+    /// This is equivalent to `Condvar::wait_timeout_while`.
+    fn wait_timeout_while_float<'a, T, F>(&self,
+                                          guard: MutexGuard<'a, T>,
+                                          timeout: FloatDuration,
+                                          condition: F)
+                                          -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)>
+        where F: FnMut(&mut T) -> bool;
+}
+
+impl CondvarExt for Condvar {
+    #[inline]
+    fn wait_timeout_float<'a, T>(&self,
+                                 guard: MutexGuard<'a, T>,
+                                 timeout: FloatDuration)
+                                 -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)> {
+        self.wait_timeout(guard, timeout.to_std_saturating())
+    }
+    #[inline]
+    fn wait_timeout_while_float<'a, T, F>(&self,
+                                          guard: MutexGuard<'a, T>,
+                                          timeout: FloatDuration,
+                                          condition: F)
+                                          -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)>
+        where F: FnMut(&mut T) -> bool
+    {
+        self.wait_timeout_while(guard, timeout.to_std_saturating(), condition)
+    }
+}
+
+/// Extension methods for `mpsc::Receiver` taking a `FloatDuration` timeout.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::sync::ReceiverExt;
+/// use std::sync::mpsc::{channel, RecvTimeoutError};
+///
+/// let (sender, receiver) = channel();
+/// sender.send(1).unwrap();
+/// assert_eq!(receiver.recv_timeout_float(FloatDuration::milliseconds(10.0)), Ok(1));
+/// assert_eq!(receiver.recv_timeout_float(FloatDuration::seconds(-1.0)),
+///            Err(RecvTimeoutError::Timeout));
+/// ```
+pub trait ReceiverExt<T> {
+    /// Wait for a value on this receiver, timing out after `timeout`.
+    ///
+    /// This is equivalent to `Receiver::recv_timeout`.
+    fn recv_timeout_float(&self, timeout: FloatDuration) -> Result<T, RecvTimeoutError>;
+}
+
+impl<T> ReceiverExt<T> for Receiver<T> {
+    #[inline]
+    fn recv_timeout_float(&self, timeout: FloatDuration) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout(timeout.to_std_saturating())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    #[test]
+    fn test_condvar() {
+        let pair = Arc::new((Mutex::new(false), Condvar::new()));
+        let (ref lock, ref condvar) = *pair;
+
+        let guard = lock.lock().unwrap();
+        let (guard, result) = condvar.wait_timeout_float(guard, FloatDuration::seconds(-1.0))
+            .unwrap();
+        assert!(result.timed_out());
+        let (guard, result) = condvar.wait_timeout_while_float(guard,
+                                      FloatDuration::seconds(f64::NAN),
+                                      |ready| !*ready)
+            .unwrap();
+        assert!(result.timed_out());
+        drop(guard);
+
+        let notifier = Arc::clone(&pair);
+        let handle = thread::spawn(move || {
+            let (ref lock, ref condvar) = *notifier;
+            *lock.lock().unwrap() = true;
+            condvar.notify_one();
+        });
+        let guard = lock.lock().unwrap();
+        let (guard, result) = condvar.wait_timeout_while_float(guard,
+                                      FloatDuration::minutes(1.0),
+                                      |ready| !*ready)
+            .unwrap();
+        assert!(!result.timed_out());
+        assert!(*guard);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_receiver() {
+        let (sender, receiver) = channel();
+        assert_eq!(receiver.recv_timeout_float(FloatDuration::milliseconds(1.0)),
+                   Err(RecvTimeoutError::Timeout));
+        assert_eq!(receiver.recv_timeout_float(FloatDuration::seconds(f64::NAN)),
+                   Err(RecvTimeoutError::Timeout));
+
+        thread::spawn(move || sender.send("hello").unwrap());
+        assert_eq!(receiver.recv_timeout_float(FloatDuration::seconds(f64::INFINITY)),
+                   Ok("hello"));
+        assert_eq!(receiver.recv_timeout_float(FloatDuration::seconds(1.0)),
+                   Err(RecvTimeoutError::Disconnected));
+    }
+}
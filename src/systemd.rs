@@ -0,0 +1,186 @@
+//! systemd-style time spans, as described in `systemd.time(7)` and used in unit
+//! files.
+use duration::FloatDuration;
+use error::ParseDurationError;
+use go::decimal;
+use unit::CalendarConvention;
+
+/// The length of the units of a systemd time span in microseconds, from the
+/// longest to the shortest, as used for formatting.
+const SPAN_UNITS: [(&str, u128); 6] = [("y", 31_557_600_000_000),
+                                       ("month", 2_629_800_000_000),
+                                       ("w", 604_800_000_000),
+                                       ("d", 86_400_000_000),
+                                       ("h", 3_600_000_000),
+                                       ("min", 60_000_000)];
+
+impl FloatDuration {
+    /// Format the duration as a systemd time span, such as `"2h 30min"` or
+    /// `"1w 3d"`.
+    ///
+    /// The duration is rounded to the nearest microsecond, the resolution of
+    /// systemd. It is split into years, months, weeks, days, hours, and minutes,
+    /// leaving out zero components, with the rest written as seconds,
+    /// milliseconds, or microseconds. As in systemd, years are 365.25 days and
+    /// months are a twelfth of that. A zero duration is written as `"0"` and an
+    /// infinite one as `"infinity"`.
+    ///
+    /// Negative durations and `NAN` have no systemd representation; their output is
+    /// unspecified.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::minutes(150.0).to_systemd_timespan(), "2h 30min");
+    /// assert_eq!(FloatDuration::days(10.0).to_systemd_timespan(), "1w 3d");
+    /// assert_eq!(FloatDuration::seconds(61.5).to_systemd_timespan(), "1min 1.5s");
+    /// ```
+    pub fn to_systemd_timespan(&self) -> String {
+        let secs = self.as_seconds();
+        if secs == f64::INFINITY {
+            return String::from("infinity");
+        }
+        let micros = (secs * 1.0e6).round();
+        if !(micros >= 0.0 && micros < u128::MAX as f64) {
+            return format!("{}s", secs);
+        }
+        let mut micros = micros as u128;
+        if micros == 0 {
+            return String::from("0");
+        }
+
+        let mut parts = Vec::new();
+        for &(symbol, length) in &SPAN_UNITS {
+            if micros >= length {
+                parts.push(format!("{}{}", micros / length, symbol));
+                micros %= length;
+            }
+        }
+        if micros >= 1_000_000 {
+            parts.push(format!("{}s", decimal(micros, 6)));
+        } else if micros >= 1_000 {
+            parts.push(format!("{}ms", decimal(micros, 3)));
+        } else if micros > 0 {
+            parts.push(format!("{}us", micros));
+        }
+        parts.join(" ")
+    }
+
+    /// Parse a systemd time span, such as `"2h 30min"`, `"1w 3d"`, or `"100us"`.
+    ///
+    /// A time span is a sequence of non-negative numbers, each with an optional
+    /// fraction and unit, optionally separated by whitespace. Numbers without a
+    /// unit are seconds, and `"infinity"` is an infinite duration. The units are
+    /// those of `systemd.time(7)`, and are case sensitive:
+    ///
+    /// - `nsec`, `ns`
+    /// - `usec`, `us`, `µs`, `μs`
+    /// - `msec`, `ms`
+    /// - `seconds`, `second`, `sec`, `s`
+    /// - `minutes`, `minute`, `min`, `m`
+    /// - `hours`, `hour`, `hr`, `h`
+    /// - `days`, `day`, `d`
+    /// - `weeks`, `week`, `w`
+    /// - `months`, `month`, `M` (30.44 days)
+    /// - `years`, `year`, `y` (365.25 days)
+    ///
+    /// # Errors
+    /// Any deviation from the format results in a `ParseDurationError`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_systemd_timespan("2h 30min"),
+    ///            Ok(FloatDuration::minutes(150.0)));
+    /// assert_eq!(FloatDuration::from_systemd_timespan("1w3d"), Ok(FloatDuration::days(10.0)));
+    /// assert_eq!(FloatDuration::from_systemd_timespan("90"), Ok(FloatDuration::seconds(90.0)));
+    /// ```
+    pub fn from_systemd_timespan(s: &str) -> Result<FloatDuration, ParseDurationError> {
+        let mut s = s.trim();
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+        if s == "infinity" {
+            return Ok(FloatDuration::seconds(f64::INFINITY));
+        }
+
+        let mut total = FloatDuration::zero();
+        while !s.is_empty() {
+            let end = s.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(s.len());
+            let (number, rest) = s.split_at(end);
+            if !number.bytes().any(|b| b.is_ascii_digit()) {
+                return Err(ParseDurationError::InvalidNumber);
+            }
+            let value = number.parse::<f64>().map_err(|_| ParseDurationError::InvalidNumber)?;
+
+            let rest = rest.trim_start();
+            let end = rest.find(|c: char| c.is_whitespace() || c.is_ascii_digit() || c == '.')
+                .unwrap_or(rest.len());
+            let (unit, rest) = rest.split_at(end);
+            let julian = CalendarConvention::Julian;
+            total += match unit {
+                "nsec" | "ns" => FloatDuration::nanoseconds(value),
+                "usec" | "us" | "µs" | "μs" => FloatDuration::microseconds(value),
+                "msec" | "ms" => FloatDuration::milliseconds(value),
+                "" | "seconds" | "second" | "sec" | "s" => FloatDuration::seconds(value),
+                "minutes" | "minute" | "min" | "m" => FloatDuration::minutes(value),
+                "hours" | "hour" | "hr" | "h" => FloatDuration::hours(value),
+                "days" | "day" | "d" => FloatDuration::days(value),
+                "weeks" | "week" | "w" => FloatDuration::weeks(value),
+                "months" | "month" | "M" => FloatDuration::months_in(value, julian),
+                "years" | "year" | "y" => FloatDuration::years_in(value, julian),
+                _ => return Err(ParseDurationError::UnknownUnit),
+            };
+            s = rest.trim_start();
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_systemd_timespan() {
+        assert_eq!(FloatDuration::zero().to_systemd_timespan(), "0");
+        assert_eq!(FloatDuration::microseconds(100.0).to_systemd_timespan(), "100us");
+        assert_eq!(FloatDuration::microseconds(1500.0).to_systemd_timespan(), "1.5ms");
+        assert_eq!(FloatDuration::hours(1.0).to_systemd_timespan(), "1h");
+        assert_eq!(FloatDuration::years_in(1.0, CalendarConvention::Julian).to_systemd_timespan(),
+                   "1y");
+        assert_eq!((FloatDuration::days(40.0) + FloatDuration::milliseconds(2.0))
+                       .to_systemd_timespan(),
+                   "1month 1w 2d 13h 30min 2ms");
+        assert_eq!(FloatDuration::nanoseconds(400.0).to_systemd_timespan(), "0");
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).to_systemd_timespan(), "infinity");
+    }
+
+    #[test]
+    fn test_from_systemd_timespan() {
+        let parse = FloatDuration::from_systemd_timespan;
+        assert_eq!(parse("100us"), Ok(FloatDuration::microseconds(100.0)));
+        assert_eq!(parse("  5 min 30 s "), Ok(FloatDuration::seconds(330.0)));
+        assert_eq!(parse("1.5h"), Ok(FloatDuration::minutes(90.0)));
+        assert_eq!(parse("2M"), Ok(FloatDuration::days(60.875)));
+        assert_eq!(parse("1y"), Ok(FloatDuration::days(365.25)));
+        assert_eq!(parse("3m"), Ok(FloatDuration::minutes(3.0)));
+        assert_eq!(parse("1 2"), Ok(FloatDuration::seconds(3.0)));
+        assert_eq!(parse("infinity"), Ok(FloatDuration::seconds(f64::INFINITY)));
+
+        assert_eq!(parse(""), Err(ParseDurationError::Empty));
+        assert_eq!(parse("-5s"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(parse("min"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(parse("5 Min"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(parse("1.2.3s"), Err(ParseDurationError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_systemd_round_trip() {
+        for &secs in &[0.0, 1e-6, 0.000123, 0.5, 59.999, 3723.5, 86400.000001, 1.0e9] {
+            let d = FloatDuration::seconds(secs);
+            let parsed = FloatDuration::from_systemd_timespan(&d.to_systemd_timespan()).unwrap();
+            assert!(parsed.approx_eq(d, FloatDuration::microseconds(0.5)), "{}", secs);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! A blocking ticker for running code at a fixed rate.
+use std::time::Instant;
+
+use num_traits::Float;
+
+use duration::{instant_elapsed, FloatDuration};
+use timing::precise_sleep;
+
+/// What a `Ticker` does with ticks that are already overdue, such as after the
+/// loop body took longer than the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissedTicks {
+    /// Yield the overdue ticks immediately, one after the other, to catch up.
+    Burst,
+    /// Drop the overdue ticks and wait for the next tick on the timeline.
+    Skip,
+}
+
+/// An iterator blocking the current thread until each tick of a fixed interval.
+///
+/// Ticks are scheduled on an ideal timeline starting when the ticker is created,
+/// so the time spent between ticks and the latency of waking up do not accumulate
+/// as drift. Each item is the time of the tick on that timeline, since the start.
+///
+/// The waits use `precise_sleep`, which busy-waits for the last couple of
+/// milliseconds of each tick for accuracy. An interval so large that the next
+/// tick cannot be represented as an `Instant` blocks forever.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, Ticker};
+///
+/// let mut ticker = Ticker::new(FloatDuration::milliseconds(2.5));
+/// for (i, time) in ticker.by_ref().take(4).enumerate() {
+///     assert_eq!(time, FloatDuration::milliseconds(2.5) * (i + 1) as f64);
+/// }
+/// assert!(ticker.elapsed() >= FloatDuration::milliseconds(10.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    interval: FloatDuration,
+    missed_ticks: MissedTicks,
+    start: Instant,
+    ticks: u64,
+}
+
+impl Ticker {
+    /// Create a new `Ticker` ticking every `interval`, starting now.
+    ///
+    /// The first tick is one interval from now.
+    ///
+    /// # Panics
+    /// This function panics if `interval` is not a positive, finite duration.
+    pub fn new(interval: FloatDuration) -> Ticker {
+        assert!(interval > FloatDuration::zero() && interval.as_seconds().is_finite(),
+                "the tick interval must be positive and finite");
+        Ticker {
+            interval,
+            missed_ticks: MissedTicks::Burst,
+            start: Instant::now(),
+            ticks: 0,
+        }
+    }
+    /// Set what to do with overdue ticks. The default is `MissedTicks::Burst`.
+    pub fn with_missed_ticks(mut self, missed_ticks: MissedTicks) -> Ticker {
+        self.missed_ticks = missed_ticks;
+        self
+    }
+
+    /// The time between ticks.
+    pub fn interval(&self) -> FloatDuration {
+        self.interval
+    }
+    /// The number of ticks yielded or skipped since the start.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+    /// The time elapsed since the start.
+    pub fn elapsed(&self) -> FloatDuration {
+        instant_elapsed(self.start)
+    }
+    /// Start the timeline over from now.
+    pub fn reset(&mut self) {
+        self.start = Instant::now();
+        self.ticks = 0;
+    }
+}
+
+impl Iterator for Ticker {
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        let elapsed = self.elapsed();
+        self.ticks += 1;
+        if self.missed_ticks == MissedTicks::Skip {
+            let next_due = Float::floor(elapsed / self.interval) as u64 + 1;
+            self.ticks = self.ticks.max(next_due);
+        }
+        let due = self.interval * self.ticks as f64;
+        precise_sleep(due - elapsed);
+        Some(due)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_no_drift() {
+        let interval = FloatDuration::milliseconds(10.0);
+        let mut ticker = Ticker::new(interval);
+        for (i, time) in ticker.by_ref().take(5).enumerate() {
+            assert_eq!(time, interval * (i + 1) as f64);
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(ticker.ticks(), 5);
+        // The sleeps between ticks do not delay the following ticks, which would
+        // take 75ms in total otherwise.
+        assert!(ticker.elapsed() < FloatDuration::milliseconds(70.0));
+    }
+
+    #[test]
+    fn test_missed_ticks() {
+        let interval = FloatDuration::milliseconds(2.0);
+        let mut burst = Ticker::new(interval);
+        let mut skip = Ticker::new(interval).with_missed_ticks(MissedTicks::Skip);
+        thread::sleep(Duration::from_millis(7));
+
+        assert_eq!(burst.next(), Some(interval));
+        assert_eq!(burst.next(), Some(interval * 2.0));
+        let stalled = skip.elapsed();
+        let tick = skip.next().unwrap();
+        assert!(tick >= interval * 4.0);
+        assert!(tick > stalled);
+        assert!(skip.elapsed() >= tick);
+
+        skip.reset();
+        assert_eq!(skip.ticks(), 0);
+        assert_eq!(skip.next(), Some(interval));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_interval_panic() {
+        Ticker::new(FloatDuration::zero());
+    }
+}
@@ -0,0 +1,317 @@
+//! Timers and cooldowns driven by the frame time of a game or simulation loop.
+use num_traits::Float;
+
+use duration::FloatDuration;
+
+/// Whether a `Timer` stops or starts over when it finishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimerMode {
+    /// The timer finishes once and then stays finished until reset.
+    Once,
+    /// The timer starts over each time it finishes.
+    Repeating,
+}
+
+/// A timer counting up to a duration, advanced by the time passed to `tick`.
+///
+/// Rather than reading a clock, the timer only advances when it is ticked, usually
+/// with the frame time of a game loop, so it follows pauses and time scaling of the
+/// simulation.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::timer::Timer;
+///
+/// let mut spawn = Timer::repeating(FloatDuration::seconds(2.0));
+/// let mut spawned = 0;
+/// for _ in 0..10 {
+///     // Each frame takes half a second.
+///     spawn.tick(FloatDuration::seconds(0.5));
+///     spawned += spawn.times_finished_this_tick();
+/// }
+/// assert_eq!(spawned, 2);
+/// assert_eq!(spawn.percent_complete(), 50.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timer {
+    duration: FloatDuration,
+    mode: TimerMode,
+    elapsed: FloatDuration,
+    paused: bool,
+    finished: bool,
+    times_finished: u32,
+}
+
+impl Timer {
+    /// Create a new `Timer` lasting `duration`, in the given `mode`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is not a positive, finite duration.
+    pub fn new(duration: FloatDuration, mode: TimerMode) -> Timer {
+        assert!(duration > FloatDuration::zero() && duration.as_seconds().is_finite(),
+                "the timer duration must be positive and finite");
+        Timer {
+            duration,
+            mode,
+            elapsed: FloatDuration::zero(),
+            paused: false,
+            finished: false,
+            times_finished: 0,
+        }
+    }
+    /// Create a new `Timer` finishing once after `duration`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is not a positive, finite duration.
+    pub fn once(duration: FloatDuration) -> Timer {
+        Timer::new(duration, TimerMode::Once)
+    }
+    /// Create a new `Timer` finishing every `duration`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is not a positive, finite duration.
+    pub fn repeating(duration: FloatDuration) -> Timer {
+        Timer::new(duration, TimerMode::Repeating)
+    }
+
+    /// The time the timer takes to finish.
+    pub fn duration(&self) -> FloatDuration {
+        self.duration
+    }
+    /// Whether the timer stops or starts over when it finishes.
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+    /// The time elapsed since the timer was started, or last started over.
+    pub fn elapsed(&self) -> FloatDuration {
+        self.elapsed
+    }
+    /// The time left until the timer finishes.
+    pub fn remaining(&self) -> FloatDuration {
+        self.duration - self.elapsed
+    }
+    /// The fraction of the duration elapsed, in `[0, 1]`.
+    pub fn fraction(&self) -> f64 {
+        self.elapsed / self.duration
+    }
+    /// The percentage of the duration elapsed, in `[0, 100]`.
+    pub fn percent_complete(&self) -> f64 {
+        self.fraction() * 100.0
+    }
+
+    /// Whether the timer has finished.
+    ///
+    /// A `Once` timer stays finished until it is reset, while a `Repeating` timer is
+    /// only finished during the tick in which it started over.
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+    /// Whether the timer finished during the last tick.
+    pub fn just_finished(&self) -> bool {
+        self.times_finished > 0
+    }
+    /// The number of times the timer finished during the last tick.
+    ///
+    /// This can be more than one for a `Repeating` timer ticked by more than its
+    /// duration.
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished
+    }
+
+    /// Advance the timer by `delta`.
+    ///
+    /// Negative deltas are ignored, as is any time passed while paused.
+    pub fn tick(&mut self, delta: FloatDuration) -> &Timer {
+        self.times_finished = 0;
+        if self.paused || (self.finished && self.mode == TimerMode::Once) {
+            return self;
+        }
+        self.elapsed += delta.max(FloatDuration::zero());
+        if self.elapsed >= self.duration {
+            match self.mode {
+                TimerMode::Once => {
+                    self.elapsed = self.duration;
+                    self.times_finished = 1;
+                }
+                TimerMode::Repeating => {
+                    let times = Float::floor(self.elapsed / self.duration);
+                    self.elapsed -= self.duration * times;
+                    self.times_finished = times as u32;
+                }
+            }
+            self.finished = true;
+        } else if self.mode == TimerMode::Repeating {
+            self.finished = false;
+        }
+        self
+    }
+
+    /// Stop advancing the timer until `unpause` is called.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+    /// Resume advancing the timer.
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+    /// Whether the timer is paused.
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+    /// Start the timer over, as if it was just created.
+    ///
+    /// The paused state is kept.
+    pub fn reset(&mut self) {
+        self.elapsed = FloatDuration::zero();
+        self.finished = false;
+        self.times_finished = 0;
+    }
+}
+
+/// A cooldown limiting how often an action can be triggered, advanced by the time
+/// passed to `tick`.
+///
+/// A new cooldown is ready. Triggering it makes it unavailable for its duration.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::timer::Cooldown;
+///
+/// let mut dash = Cooldown::new(FloatDuration::seconds(1.5));
+/// assert!(dash.trigger());
+/// dash.tick(FloatDuration::seconds(1.0));
+/// assert!(!dash.trigger());
+/// assert_eq!(dash.remaining(), FloatDuration::seconds(0.5));
+/// dash.tick(FloatDuration::seconds(0.5));
+/// assert!(dash.trigger());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cooldown {
+    timer: Timer,
+}
+
+impl Cooldown {
+    /// Create a new, ready `Cooldown` lasting `duration`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is not a positive, finite duration.
+    pub fn new(duration: FloatDuration) -> Cooldown {
+        let mut timer = Timer::once(duration);
+        timer.tick(duration);
+        Cooldown { timer }
+    }
+
+    /// The time the cooldown lasts once triggered.
+    pub fn duration(&self) -> FloatDuration {
+        self.timer.duration()
+    }
+    /// The time left until the cooldown is ready, or zero if it is ready.
+    pub fn remaining(&self) -> FloatDuration {
+        self.timer.remaining()
+    }
+    /// The percentage of the cooldown elapsed, in `[0, 100]`.
+    ///
+    /// This is 100 when the cooldown is ready.
+    pub fn percent_complete(&self) -> f64 {
+        self.timer.percent_complete()
+    }
+    /// Whether the cooldown can be triggered.
+    pub fn is_ready(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// Advance the cooldown by `delta`.
+    ///
+    /// Negative deltas are ignored.
+    pub fn tick(&mut self, delta: FloatDuration) {
+        self.timer.tick(delta);
+    }
+    /// Trigger the cooldown if it is ready, returning whether it was.
+    pub fn trigger(&mut self) -> bool {
+        let ready = self.is_ready();
+        if ready {
+            self.timer.reset();
+        }
+        ready
+    }
+    /// Make the cooldown ready immediately.
+    pub fn reset(&mut self) {
+        let duration = self.timer.duration();
+        self.timer.tick(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_once() {
+        let mut timer = Timer::once(FloatDuration::seconds(1.0));
+        timer.tick(FloatDuration::seconds(0.25));
+        assert!(!timer.finished());
+        assert_eq!(timer.remaining(), FloatDuration::seconds(0.75));
+        assert_eq!(timer.percent_complete(), 25.0);
+
+        assert!(timer.tick(FloatDuration::seconds(1.0)).just_finished());
+        assert!(timer.finished());
+        assert_eq!(timer.elapsed(), FloatDuration::seconds(1.0));
+        assert_eq!(timer.remaining(), FloatDuration::zero());
+
+        timer.tick(FloatDuration::seconds(1.0));
+        assert!(timer.finished() && !timer.just_finished());
+
+        timer.reset();
+        assert!(!timer.finished());
+        assert_eq!(timer.elapsed(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_repeating() {
+        let mut timer = Timer::repeating(FloatDuration::seconds(0.5));
+        timer.tick(FloatDuration::seconds(0.25));
+        assert!(!timer.finished());
+        timer.tick(FloatDuration::seconds(0.5));
+        assert!(timer.finished() && timer.just_finished());
+        assert_eq!(timer.elapsed(), FloatDuration::seconds(0.25));
+
+        timer.tick(FloatDuration::seconds(0.125));
+        assert!(!timer.finished());
+        timer.tick(FloatDuration::seconds(1.125));
+        assert_eq!(timer.times_finished_this_tick(), 3);
+        assert_eq!(timer.elapsed(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut timer = Timer::once(FloatDuration::seconds(1.0));
+        timer.pause();
+        timer.tick(FloatDuration::seconds(2.0));
+        assert!(timer.paused() && !timer.finished());
+        timer.unpause();
+        timer.tick(FloatDuration::seconds(-1.0));
+        assert_eq!(timer.elapsed(), FloatDuration::zero());
+        timer.tick(FloatDuration::seconds(2.0));
+        assert!(timer.finished());
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let mut cooldown = Cooldown::new(FloatDuration::seconds(2.0));
+        assert!(cooldown.is_ready());
+        assert_eq!(cooldown.percent_complete(), 100.0);
+        assert!(cooldown.trigger());
+        assert!(!cooldown.trigger());
+        cooldown.tick(FloatDuration::seconds(1.5));
+        assert_eq!(cooldown.remaining(), FloatDuration::seconds(0.5));
+        assert!(!cooldown.is_ready());
+        cooldown.reset();
+        assert!(cooldown.trigger());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_duration_panic() {
+        Timer::repeating(FloatDuration::zero());
+    }
+}
@@ -0,0 +1,160 @@
+//! Fixed-timestep accumulation for game and simulation loops.
+use duration::FloatDuration;
+
+/// An accumulator that turns variable frame times into fixed simulation steps.
+///
+/// Each frame, the measured frame time is added to an internal accumulator with
+/// [`advance`](#method.advance), which yields the fixed step once for every
+/// whole step that fits in the accumulated time. The leftover time is kept for the
+/// next frame and exposed as an interpolation factor by [`alpha`](#method.alpha).
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::timestep::FixedTimestep;
+///
+/// let mut timestep = FixedTimestep::new(FloatDuration::milliseconds(10.0));
+/// let mut steps = 0;
+///
+/// // A 25ms frame runs two 10ms simulation steps...
+/// for dt in timestep.advance(FloatDuration::milliseconds(25.0)) {
+///     assert_eq!(dt, FloatDuration::milliseconds(10.0));
+///     steps += 1;
+/// }
+/// assert_eq!(steps, 2);
+/// // ...and leaves half a step to interpolate the rendered state with.
+/// assert!((timestep.alpha() - 0.5).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    step: FloatDuration,
+    accumulator: FloatDuration,
+    max_frame_time: Option<FloatDuration>,
+}
+
+impl FixedTimestep {
+    /// Create a new `FixedTimestep` running steps of length `step`.
+    ///
+    /// # Panics
+    /// This function panics if `step` is not a positive, finite duration.
+    pub fn new(step: FloatDuration) -> FixedTimestep {
+        assert!(step > FloatDuration::zero() && step.as_seconds().is_finite(),
+                "the fixed timestep must be positive and finite");
+        FixedTimestep {
+            step,
+            accumulator: FloatDuration::zero(),
+            max_frame_time: None,
+        }
+    }
+    /// Clamp the frame time passed to `advance` to at most `max_frame_time`.
+    ///
+    /// After a long hitch, such as a breakpoint or the window being dragged, an
+    /// unclamped accumulator would run a large number of steps at once and might
+    /// never catch up. Clamping drops the excess time instead.
+    pub fn with_max_frame_time(mut self, max_frame_time: FloatDuration) -> FixedTimestep {
+        self.max_frame_time = Some(max_frame_time);
+        self
+    }
+
+    /// The length of a single simulation step.
+    pub fn step(&self) -> FloatDuration {
+        self.step
+    }
+    /// The time accumulated that has not yet been consumed by a step.
+    pub fn accumulated(&self) -> FloatDuration {
+        self.accumulator
+    }
+    /// The fraction of a step left in the accumulator, in `[0, 1)`.
+    ///
+    /// This is meant to be used to interpolate between the previous and current
+    /// simulation states when rendering.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / self.step
+    }
+
+    /// Add `frame_time` to the accumulator and return an iterator over the
+    /// simulation steps that are now due.
+    ///
+    /// Each item is the fixed step length. Steps are only consumed from the
+    /// accumulator as the iterator is advanced, so dropping it early leaves the
+    /// remaining steps due for the next frame. Negative frame times are ignored.
+    pub fn advance(&mut self, frame_time: FloatDuration) -> Steps<'_> {
+        let mut frame_time = frame_time.max(FloatDuration::zero());
+        if let Some(max_frame_time) = self.max_frame_time {
+            frame_time = frame_time.min(max_frame_time);
+        }
+        self.accumulator += frame_time;
+        Steps { timestep: self }
+    }
+    /// Discard any accumulated time.
+    pub fn reset(&mut self) {
+        self.accumulator = FloatDuration::zero();
+    }
+}
+
+/// An iterator over the due steps of a `FixedTimestep`.
+///
+/// This type is returned by `FixedTimestep::advance`.
+#[derive(Debug)]
+pub struct Steps<'a> {
+    timestep: &'a mut FixedTimestep,
+}
+
+impl<'a> Iterator for Steps<'a> {
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        if self.timestep.accumulator >= self.timestep.step {
+            self.timestep.accumulator -= self.timestep.step;
+            Some(self.timestep.step)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance() {
+        let mut timestep = FixedTimestep::new(FloatDuration::seconds(0.25));
+
+        assert_eq!(timestep.advance(FloatDuration::seconds(0.1)).count(), 0);
+        assert_eq!(timestep.accumulated(), FloatDuration::seconds(0.1));
+        assert_eq!(timestep.advance(FloatDuration::seconds(0.9)).count(), 4);
+        assert_eq!(timestep.accumulated(), FloatDuration::zero());
+        assert_eq!(timestep.alpha(), 0.0);
+
+        assert_eq!(timestep.advance(FloatDuration::seconds(0.625)).count(), 2);
+        assert_eq!(timestep.alpha(), 0.5);
+        assert_eq!(timestep.advance(FloatDuration::seconds(-1.0)).count(), 0);
+        assert_eq!(timestep.alpha(), 0.5);
+
+        timestep.reset();
+        assert_eq!(timestep.accumulated(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_max_frame_time() {
+        let mut timestep = FixedTimestep::new(FloatDuration::seconds(0.125))
+            .with_max_frame_time(FloatDuration::seconds(0.5));
+
+        assert_eq!(timestep.advance(FloatDuration::seconds(10.0)).count(), 4);
+        assert_eq!(timestep.accumulated(), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_partial_iteration() {
+        let mut timestep = FixedTimestep::new(FloatDuration::seconds(1.0));
+        assert_eq!(timestep.advance(FloatDuration::seconds(3.0)).next(),
+                   Some(FloatDuration::seconds(1.0)));
+        assert_eq!(timestep.accumulated(), FloatDuration::seconds(2.0));
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_zero_step_panic() {
+        FixedTimestep::new(FloatDuration::zero());
+    }
+}
@@ -0,0 +1,455 @@
+//! Helpers for timing code with the system clock.
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+use std::fmt;
+use std::hint;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use duration::{instant_elapsed, FloatDuration, TimeOffset};
+use error::OutOfRangeError;
+
+/// The part of a `precise_sleep` which is spent spinning instead of sleeping.
+///
+/// This is chosen to cover the usual scheduler latency of desktop systems.
+const SPIN_THRESHOLD: Duration = Duration::from_millis(2);
+
+/// The most untimed runs done by `FloatDuration::bench` before measuring.
+const BENCH_MAX_WARM_UP: usize = 100;
+
+/// Block the current thread for `duration`, more precisely than `thread::sleep`.
+///
+/// `thread::sleep` may overshoot by a millisecond or more, depending on the
+/// operating system's scheduler. This function sleeps for most of `duration` and
+/// then busy-waits for the last couple of milliseconds, which makes it suitable for
+/// frame pacing or audio scheduling at the cost of some CPU time.
+///
+/// Negative, zero and NaN durations return immediately, and durations too large
+/// to represent as a deadline, such as infinity, block forever.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, precise_sleep};
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// precise_sleep(FloatDuration::milliseconds(5.0));
+/// assert!(start.elapsed().as_secs_f64() >= 0.005);
+/// ```
+pub fn precise_sleep(duration: FloatDuration) {
+    let start = Instant::now();
+    let deadline = match start.checked_add_float(duration) {
+        Some(deadline) => deadline,
+        // The deadline is too far in the future to be represented.
+        None if duration.is_positive() && !duration.as_seconds().is_nan() => loop {
+            thread::sleep(Duration::from_secs(u64::from(u32::MAX)));
+        },
+        None => return,
+    };
+
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            return;
+        }
+        let remaining = deadline - now;
+        if remaining > SPIN_THRESHOLD {
+            thread::sleep(remaining - SPIN_THRESHOLD);
+        } else {
+            hint::spin_loop();
+        }
+    }
+}
+
+impl FloatDuration {
+    /// Run `f` and return its result along with the wall-clock time it took.
+    ///
+    /// The time is measured with `std::time::Instant`, which is monotonic.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let (sum, elapsed) = FloatDuration::measure(|| (0..1000u64).sum::<u64>());
+    /// assert_eq!(sum, 499500);
+    /// println!("Summing took {}.", elapsed);
+    /// ```
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, FloatDuration) {
+        let start = Instant::now();
+        let result = f();
+        (result, instant_elapsed(start))
+    }
+
+    /// Block the current thread for this duration.
+    ///
+    /// Negative, zero and NaN durations do not sleep at all, and durations too
+    /// large to be represented by `std::time::Duration` sleep for as long as
+    /// possible. Use `try_sleep` to treat those as errors instead.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// FloatDuration::milliseconds(1.5).sleep();
+    /// // Computed delays may end up negative, which is not an error.
+    /// FloatDuration::milliseconds(-3.0).sleep();
+    /// ```
+    pub fn sleep(self) {
+        let duration = self.to_std_saturating();
+        if duration > Duration::from_secs(0) {
+            thread::sleep(duration);
+        }
+    }
+    /// Block the current thread for this duration, failing for durations that
+    /// cannot be slept.
+    ///
+    /// # Errors
+    /// Returns an `OutOfRangeError` without sleeping if the duration is negative,
+    /// NaN, or too large to be represented by `std::time::Duration`.
+    pub fn try_sleep(self) -> Result<(), OutOfRangeError> {
+        thread::sleep(self.to_std()?);
+        Ok(())
+    }
+
+    /// Run `f` `iterations` times, after a few untimed warm-up runs, and report
+    /// statistics of the time taken by each run.
+    ///
+    /// This is meant for quick measurements within a program. The results of `f` are
+    /// passed to `std::hint::black_box` so the calls are not optimized away, but
+    /// unlike a benchmarking framework no outliers are removed.
+    ///
+    /// # Panics
+    /// This function panics if `iterations` is zero.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let report = FloatDuration::bench(100, || (0..1000u64).sum::<u64>());
+    /// assert_eq!(report.iterations, 100);
+    /// assert!(report.min <= report.median && report.median <= report.max);
+    /// println!("Summing: {}", report);
+    /// ```
+    pub fn bench<T>(iterations: usize, mut f: impl FnMut() -> T) -> BenchReport {
+        assert!(iterations > 0, "cannot benchmark zero iterations");
+        for _ in 0..(iterations / 10).clamp(1, BENCH_MAX_WARM_UP) {
+            hint::black_box(f());
+        }
+
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            hint::black_box(f());
+            samples.push(instant_elapsed(start));
+        }
+        samples.sort_by(|a, b| a.total_cmp(b));
+
+        // Both indices are the middle sample when `iterations` is odd.
+        let median = (samples[(iterations - 1) / 2] + samples[iterations / 2]) / 2.0;
+        BenchReport {
+            iterations,
+            min: samples[0],
+            mean: samples.iter().sum::<FloatDuration>() / iterations as f64,
+            median,
+            max: samples[iterations - 1],
+        }
+    }
+
+    /// Wrap `future` so that it resolves to its output along with the wall-clock
+    /// time it took.
+    ///
+    /// Like an `async fn`, the returned future does nothing until it is first
+    /// polled, so the measured time starts at the first poll rather than when
+    /// `measure_async` is called. The future is boxed so that it can be polled
+    /// without requiring it to be `Unpin`.
+    ///
+    /// ```rust
+    /// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    /// #     let mut future = Box::pin(future);
+    /// #     let waker = std::task::Waker::noop();
+    /// #     let mut context = std::task::Context::from_waker(&waker);
+    /// #     loop {
+    /// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+    /// #             return output;
+    /// #         }
+    /// #     }
+    /// # }
+    /// use float_duration::FloatDuration;
+    ///
+    /// let (value, elapsed) = block_on(FloatDuration::measure_async(std::future::ready(42)));
+    /// assert_eq!(value, 42);
+    /// assert!(elapsed >= FloatDuration::zero());
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn measure_async<Fut: Future>(future: Fut) -> Measure<Fut> {
+        Measure {
+            future: Box::pin(future),
+            start: None,
+        }
+    }
+}
+
+/// A future measuring the time taken by another future.
+///
+/// This type is returned by `FloatDuration::measure_async`.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Measure<Fut> {
+    future: Pin<Box<Fut>>,
+    start: Option<Instant>,
+}
+
+#[cfg(feature = "async")]
+impl<Fut: Future> Future for Measure<Fut> {
+    type Output = (Fut::Output, FloatDuration);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<(Fut::Output, FloatDuration)> {
+        let this = self.get_mut();
+        let start = *this.start.get_or_insert_with(Instant::now);
+        match this.future.as_mut().poll(cx) {
+            Poll::Ready(output) => Poll::Ready((output, instant_elapsed(start))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Statistics of the time taken by each run of a closure.
+///
+/// This type is returned by `FloatDuration::bench`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchReport {
+    /// The number of timed runs.
+    pub iterations: usize,
+    /// The fastest run.
+    pub min: FloatDuration,
+    /// The mean time of a run.
+    pub mean: FloatDuration,
+    /// The median time of a run.
+    pub median: FloatDuration,
+    /// The slowest run.
+    pub max: FloatDuration,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "min {}, mean {}, median {}, max {} over {} iterations",
+               self.min, self.mean, self.median, self.max, self.iterations)
+    }
+}
+
+/// A guard passing the time elapsed since its creation to a callback when it is
+/// dropped, for lightweight profiling of a scope.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, ScopeTimer};
+/// use std::cell::Cell;
+///
+/// let total = Cell::new(FloatDuration::zero());
+/// for _ in 0..3 {
+///     let _timer = ScopeTimer::new(|elapsed| total.set(total.get() + elapsed));
+///     // Do some work...
+/// }
+/// println!("The work took {} in total.", total.get());
+/// ```
+///
+/// The callback can also log the time under a label of its own:
+///
+/// ```rust
+/// use float_duration::ScopeTimer;
+///
+/// fn parse_config() {
+///     let _timer = ScopeTimer::new(|elapsed| println!("parse_config: {}", elapsed));
+///     // Parse the configuration...
+/// }
+/// # parse_config();
+/// ```
+pub struct ScopeTimer<F: FnOnce(FloatDuration)> {
+    start: Instant,
+    callback: Option<F>,
+}
+
+impl<F: FnOnce(FloatDuration)> ScopeTimer<F> {
+    /// Start timing, to pass the elapsed time to `callback` when dropped.
+    pub fn new(callback: F) -> ScopeTimer<F> {
+        ScopeTimer {
+            start: Instant::now(),
+            callback: Some(callback),
+        }
+    }
+
+    /// Return the time elapsed since the timer was created.
+    pub fn elapsed(&self) -> FloatDuration {
+        instant_elapsed(self.start)
+    }
+    /// Stop the timer before the end of the scope, passing the elapsed time to the
+    /// callback and returning it.
+    pub fn stop(mut self) -> FloatDuration {
+        let elapsed = self.elapsed();
+        if let Some(callback) = self.callback.take() {
+            callback(elapsed);
+        }
+        elapsed
+    }
+}
+
+impl<F: FnOnce(FloatDuration)> fmt::Debug for ScopeTimer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScopeTimer")
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
+impl<F: FnOnce(FloatDuration)> Drop for ScopeTimer<F> {
+    fn drop(&mut self) {
+        if let Some(callback) = self.callback.take() {
+            callback(self.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (value, elapsed) = FloatDuration::measure(|| {
+            thread::sleep(Duration::from_millis(5));
+            42
+        });
+        assert_eq!(value, 42);
+        assert!(elapsed >= FloatDuration::milliseconds(5.0));
+
+        let ((), elapsed) = FloatDuration::measure(|| ());
+        assert!(elapsed >= FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_precise_sleep() {
+        for &ms in &[0.5, 3.0, 10.0] {
+            let start = Instant::now();
+            precise_sleep(FloatDuration::milliseconds(ms));
+            let elapsed = instant_elapsed(start);
+            assert!(elapsed >= FloatDuration::milliseconds(ms));
+        }
+
+        let start = Instant::now();
+        precise_sleep(FloatDuration::seconds(-1.0));
+        precise_sleep(FloatDuration::seconds(f64::NAN));
+        precise_sleep(FloatDuration::zero());
+        assert!(instant_elapsed(start) < FloatDuration::seconds(1.0));
+    }
+
+    #[test]
+    fn test_sleep() {
+        let start = Instant::now();
+        FloatDuration::milliseconds(5.0).sleep();
+        assert!(instant_elapsed(start) >= FloatDuration::milliseconds(5.0));
+
+        let start = Instant::now();
+        FloatDuration::seconds(-10.0).sleep();
+        FloatDuration::seconds(f64::NAN).sleep();
+        assert!(instant_elapsed(start) < FloatDuration::seconds(10.0));
+
+        assert_eq!(FloatDuration::milliseconds(1.0).try_sleep(), Ok(()));
+        assert_eq!(FloatDuration::zero().try_sleep(), Ok(()));
+        assert_eq!(FloatDuration::seconds(-10.0).try_sleep(), Err(OutOfRangeError::new()));
+        assert_eq!(FloatDuration::seconds(f64::NAN).try_sleep(), Err(OutOfRangeError::new()));
+        assert_eq!(FloatDuration::max_value().try_sleep(), Err(OutOfRangeError::new()));
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut calls = 0;
+        let report = FloatDuration::bench(20, || {
+            calls += 1;
+            thread::sleep(Duration::from_millis(1));
+        });
+        assert_eq!(calls, 22);
+        assert_eq!(report.iterations, 20);
+        assert!(report.min >= FloatDuration::milliseconds(1.0));
+        assert!(report.min <= report.median && report.median <= report.max);
+        assert!(report.min <= report.mean && report.mean <= report.max);
+
+        let report = FloatDuration::bench(1, || ());
+        assert_eq!(report.min, report.max);
+        assert_eq!(report.median, report.mean);
+        assert!(report.to_string().ends_with("over 1 iterations"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bench_zero_iterations_panic() {
+        FloatDuration::bench(0, || ());
+    }
+
+    #[test]
+    fn test_scope_timer() {
+        use std::cell::Cell;
+
+        let recorded = Cell::new(None);
+        {
+            let _timer = ScopeTimer::new(|elapsed| recorded.set(Some(elapsed)));
+            thread::sleep(Duration::from_millis(5));
+            assert_eq!(recorded.get(), None);
+        }
+        assert!(recorded.get().unwrap() >= FloatDuration::milliseconds(5.0));
+
+        let calls = Cell::new(0);
+        let timer = ScopeTimer::new(|_| calls.set(calls.get() + 1));
+        let elapsed = timer.stop();
+        assert_eq!(calls.get(), 1);
+        assert!(elapsed >= FloatDuration::zero());
+    }
+
+    #[cfg(feature = "async")]
+    fn block_on<Fut: Future>(future: Fut) -> Fut::Output {
+        use std::task::Waker;
+
+        let mut future = Box::pin(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// A future that sleeps in a separate thread until its deadline passes.
+    #[cfg(feature = "async")]
+    struct Delay {
+        deadline: Instant,
+    }
+
+    #[cfg(feature = "async")]
+    impl Future for Delay {
+        type Output = &'static str;
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<&'static str> {
+            if Instant::now() >= self.deadline {
+                Poll::Ready("done")
+            } else {
+                thread::sleep(Duration::from_millis(1));
+                Poll::Pending
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_measure_async() {
+        let delay = Delay { deadline: Instant::now() + Duration::from_millis(5) };
+        let (value, elapsed) = block_on(FloatDuration::measure_async(delay));
+        assert_eq!(value, "done");
+        assert!(elapsed >= FloatDuration::zero());
+        assert!(elapsed < FloatDuration::seconds(5.0));
+
+        // The timer starts on the first poll, not on creation.
+        let measure = FloatDuration::measure_async(::std::future::ready(1));
+        thread::sleep(Duration::from_millis(5));
+        let (_, elapsed) = block_on(measure);
+        assert!(elapsed < FloatDuration::milliseconds(5.0));
+    }
+}
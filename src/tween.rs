@@ -0,0 +1,188 @@
+//! Animation timing: progress through a duration, advanced by the frame time.
+use num_traits::Float;
+
+use duration::FloatDuration;
+
+/// What a `Tween` does when it reaches the end of its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TweenMode {
+    /// Stop at the end, with a progress of 1.
+    Once,
+    /// Start over from a progress of 0.
+    Loop,
+    /// Run backward to a progress of 0, then forward again, and so on.
+    PingPong,
+}
+
+/// The progress of an animation through its duration, as a fraction in `[0, 1]`.
+///
+/// The tween only advances when it is ticked, usually with the frame time, and its
+/// progress is meant to drive an interpolation, possibly through an easing curve.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::tween::{Tween, TweenMode};
+///
+/// let mut fade = Tween::new(FloatDuration::seconds(2.0), TweenMode::PingPong);
+/// assert_eq!(fade.tick(FloatDuration::seconds(0.5)), 0.25);
+/// assert_eq!(fade.tick(FloatDuration::seconds(2.0)), 0.75);
+/// assert_eq!(fade.cycles(), 1);
+/// assert_eq!(fade.interpolate(10.0, 20.0), 17.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    duration: FloatDuration,
+    mode: TweenMode,
+    elapsed: FloatDuration,
+    reversed: bool,
+    cycles: u64,
+}
+
+impl Tween {
+    /// Create a new `Tween` lasting `duration`, in the given `mode`.
+    ///
+    /// # Panics
+    /// This function panics if `duration` is not a positive, finite duration.
+    pub fn new(duration: FloatDuration, mode: TweenMode) -> Tween {
+        assert!(duration > FloatDuration::zero() && duration.as_seconds().is_finite(),
+                "the tween duration must be positive and finite");
+        Tween {
+            duration,
+            mode,
+            elapsed: FloatDuration::zero(),
+            reversed: false,
+            cycles: 0,
+        }
+    }
+
+    /// The time the tween takes to go from a progress of 0 to 1.
+    pub fn duration(&self) -> FloatDuration {
+        self.duration
+    }
+    /// What the tween does at the end of its duration.
+    pub fn mode(&self) -> TweenMode {
+        self.mode
+    }
+    /// The time elapsed in the current cycle.
+    pub fn elapsed(&self) -> FloatDuration {
+        self.elapsed
+    }
+    /// The number of times the tween reached an end of its duration.
+    ///
+    /// A `PingPong` tween counts both ends.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+    /// Whether a `PingPong` tween is currently running backward.
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+    /// Whether a `Once` tween has reached its end. Other tweens never complete.
+    pub fn is_complete(&self) -> bool {
+        self.mode == TweenMode::Once && self.elapsed >= self.duration
+    }
+
+    /// The progress of the tween, in `[0, 1]`.
+    pub fn progress(&self) -> f64 {
+        let fraction = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        if self.reversed {
+            1.0 - fraction
+        } else {
+            fraction
+        }
+    }
+    /// Interpolate linearly from `start` to `end` according to the progress.
+    pub fn interpolate(&self, start: f64, end: f64) -> f64 {
+        start + (end - start) * self.progress()
+    }
+
+    /// Advance the tween by `delta` and return the new progress.
+    ///
+    /// Negative deltas are ignored.
+    pub fn tick(&mut self, delta: FloatDuration) -> f64 {
+        self.elapsed += delta.max(FloatDuration::zero());
+        if self.elapsed >= self.duration {
+            match self.mode {
+                TweenMode::Once => {
+                    if self.cycles == 0 {
+                        self.cycles = 1;
+                    }
+                    self.elapsed = self.duration;
+                }
+                TweenMode::Loop | TweenMode::PingPong => {
+                    let cycles = Float::floor(self.elapsed / self.duration);
+                    self.elapsed -= self.duration * cycles;
+                    self.cycles += cycles as u64;
+                    if self.mode == TweenMode::PingPong && cycles % 2.0 == 1.0 {
+                        self.reversed = !self.reversed;
+                    }
+                }
+            }
+        }
+        self.progress()
+    }
+    /// Jump to `progress`, clamped to `[0, 1]`, in the current direction.
+    pub fn seek(&mut self, progress: f64) {
+        let progress = progress.clamp(0.0, 1.0);
+        let fraction = if self.reversed { 1.0 - progress } else { progress };
+        self.elapsed = self.duration * fraction;
+    }
+    /// Start the tween over from a progress of 0.
+    pub fn reset(&mut self) {
+        self.elapsed = FloatDuration::zero();
+        self.reversed = false;
+        self.cycles = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_once() {
+        let mut tween = Tween::new(FloatDuration::seconds(4.0), TweenMode::Once);
+        assert_eq!(tween.tick(FloatDuration::seconds(1.0)), 0.25);
+        assert_eq!(tween.tick(FloatDuration::seconds(-1.0)), 0.25);
+        assert!(!tween.is_complete());
+        assert_eq!(tween.tick(FloatDuration::seconds(10.0)), 1.0);
+        assert!(tween.is_complete());
+        assert_eq!(tween.cycles(), 1);
+        assert_eq!(tween.interpolate(-1.0, 1.0), 1.0);
+
+        tween.seek(0.5);
+        assert_eq!(tween.elapsed(), FloatDuration::seconds(2.0));
+        tween.reset();
+        assert_eq!(tween.progress(), 0.0);
+    }
+
+    #[test]
+    fn test_loop() {
+        let mut tween = Tween::new(FloatDuration::seconds(1.0), TweenMode::Loop);
+        assert_eq!(tween.tick(FloatDuration::seconds(0.75)), 0.75);
+        assert_eq!(tween.tick(FloatDuration::seconds(2.5)), 0.25);
+        assert_eq!(tween.cycles(), 3);
+        assert!(!tween.is_complete());
+    }
+
+    #[test]
+    fn test_ping_pong() {
+        let mut tween = Tween::new(FloatDuration::seconds(1.0), TweenMode::PingPong);
+        assert_eq!(tween.tick(FloatDuration::seconds(1.25)), 0.75);
+        assert!(tween.is_reversed());
+        assert_eq!(tween.tick(FloatDuration::seconds(2.0)), 0.75);
+        assert!(tween.is_reversed());
+        assert_eq!(tween.tick(FloatDuration::seconds(1.0)), 0.25);
+        assert!(!tween.is_reversed());
+        assert_eq!(tween.cycles(), 4);
+
+        tween.seek(0.5);
+        assert_eq!(tween.progress(), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_duration_panic() {
+        Tween::new(FloatDuration::zero(), TweenMode::Loop);
+    }
+}
@@ -0,0 +1,509 @@
+//! Named units of time and calendar conventions.
+use core::str::FromStr;
+
+use num_traits::Float;
+
+use duration::{self, GenericFloatDuration, float};
+use error::ParseDurationError;
+
+/// A unit of time, from nanoseconds up to years.
+///
+/// Months and years follow the `CalendarConvention::Common` convention, matching
+/// `FloatDuration::months` and `FloatDuration::years`.
+///
+/// A `TimeUnit` can be parsed from the same unit names that are accepted when
+/// parsing a `FloatDuration`:
+///
+/// ```rust
+/// use float_duration::{FloatDuration, TimeUnit};
+///
+/// let unit: TimeUnit = "ms".parse().unwrap();
+/// assert_eq!(unit, TimeUnit::Millisecond);
+/// assert_eq!(FloatDuration::seconds(1.5).in_unit(unit), 1500.0);
+/// assert_eq!(FloatDuration::from_unit(2.0, TimeUnit::Week), FloatDuration::days(14.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    /// A billionth of a second.
+    Nanosecond,
+    /// A millionth of a second.
+    Microsecond,
+    /// A thousandth of a second.
+    Millisecond,
+    /// A second.
+    Second,
+    /// Sixty seconds.
+    Minute,
+    /// Sixty minutes.
+    Hour,
+    /// Twenty-four hours.
+    Day,
+    /// Seven days.
+    Week,
+    /// A twelfth of a 365 day year.
+    Month,
+    /// A 365 day year.
+    Year,
+}
+
+impl TimeUnit {
+    /// Return the number of seconds in one of this unit.
+    ///
+    /// ```rust
+    /// use float_duration::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hour.as_seconds(), 3600.0);
+    /// assert_eq!(TimeUnit::Millisecond.as_seconds(), 0.001);
+    /// ```
+    pub fn as_seconds(&self) -> f64 {
+        match self.units_per_sec() {
+            Some(units_per_sec) => 1.0 / units_per_sec,
+            None => self.secs_per_unit(),
+        }
+    }
+
+    /// Return the plural English name of the unit, as used when displaying a
+    /// `FloatDuration`.
+    ///
+    /// ```rust
+    /// use float_duration::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microsecond.name(), "microseconds");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TimeUnit::Nanosecond => "nanoseconds",
+            TimeUnit::Microsecond => "microseconds",
+            TimeUnit::Millisecond => "milliseconds",
+            TimeUnit::Second => "seconds",
+            TimeUnit::Minute => "minutes",
+            TimeUnit::Hour => "hours",
+            TimeUnit::Day => "days",
+            TimeUnit::Week => "weeks",
+            TimeUnit::Month => "months",
+            TimeUnit::Year => "years",
+        }
+    }
+
+    /// The number of this unit in a second, for units shorter than a second.
+    ///
+    /// Sub-second units are scaled by their exact per-second count, since their
+    /// length in seconds is not exactly representable.
+    pub(crate) const fn units_per_sec(&self) -> Option<f64> {
+        match *self {
+            TimeUnit::Nanosecond => Some(duration::NANOS_PER_SEC),
+            TimeUnit::Microsecond => Some(duration::MICROS_PER_SEC),
+            TimeUnit::Millisecond => Some(duration::MILLIS_PER_SEC),
+            _ => None,
+        }
+    }
+    /// The number of seconds in this unit, for units of a second or longer.
+    pub(crate) const fn secs_per_unit(&self) -> f64 {
+        match *self {
+            TimeUnit::Minute => duration::SECS_PER_MINUTE,
+            TimeUnit::Hour => duration::SECS_PER_HOUR,
+            TimeUnit::Day => duration::SECS_PER_DAY,
+            TimeUnit::Week => duration::SECS_PER_WEEK,
+            TimeUnit::Month => duration::SECS_PER_MONTH,
+            TimeUnit::Year => duration::SECS_PER_YEAR,
+            _ => 1.0,
+        }
+    }
+
+    /// Apply `op` to `secs` measured in this unit, and convert the result back to
+    /// seconds.
+    fn snap<F: Float>(&self, secs: F, op: fn(F) -> F) -> F {
+        match self.units_per_sec() {
+            Some(units_per_sec) => {
+                let units_per_sec = float::<F>(units_per_sec);
+                op(secs * units_per_sec) / units_per_sec
+            }
+            None => {
+                let unit = float::<F>(self.secs_per_unit());
+                op(secs / unit) * unit
+            }
+        }
+    }
+}
+
+/// Parse a unit name, such as `ms`, `hour`, or `weeks`.
+///
+/// The accepted names are listed in the documentation of the `FromStr`
+/// implementation of `FloatDuration`.
+impl FromStr for TimeUnit {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<TimeUnit, ParseDurationError> {
+        TimeUnit::from_name(s.as_bytes()).ok_or(ParseDurationError::UnknownUnit)
+    }
+}
+
+/// Every name of every unit accepted when parsing.
+const UNIT_NAMES: [(&str, TimeUnit); 45] = [("ns", TimeUnit::Nanosecond),
+                                            ("nanos", TimeUnit::Nanosecond),
+                                            ("nanosecond", TimeUnit::Nanosecond),
+                                            ("nanoseconds", TimeUnit::Nanosecond),
+                                            ("us", TimeUnit::Microsecond),
+                                            ("µs", TimeUnit::Microsecond),
+                                            ("μs", TimeUnit::Microsecond),
+                                            ("micros", TimeUnit::Microsecond),
+                                            ("microsecond", TimeUnit::Microsecond),
+                                            ("microseconds", TimeUnit::Microsecond),
+                                            ("ms", TimeUnit::Millisecond),
+                                            ("millis", TimeUnit::Millisecond),
+                                            ("millisecond", TimeUnit::Millisecond),
+                                            ("milliseconds", TimeUnit::Millisecond),
+                                            ("s", TimeUnit::Second),
+                                            ("sec", TimeUnit::Second),
+                                            ("secs", TimeUnit::Second),
+                                            ("second", TimeUnit::Second),
+                                            ("seconds", TimeUnit::Second),
+                                            ("m", TimeUnit::Minute),
+                                            ("min", TimeUnit::Minute),
+                                            ("mins", TimeUnit::Minute),
+                                            ("minute", TimeUnit::Minute),
+                                            ("minutes", TimeUnit::Minute),
+                                            ("h", TimeUnit::Hour),
+                                            ("hr", TimeUnit::Hour),
+                                            ("hrs", TimeUnit::Hour),
+                                            ("hour", TimeUnit::Hour),
+                                            ("hours", TimeUnit::Hour),
+                                            ("d", TimeUnit::Day),
+                                            ("day", TimeUnit::Day),
+                                            ("days", TimeUnit::Day),
+                                            ("w", TimeUnit::Week),
+                                            ("wk", TimeUnit::Week),
+                                            ("wks", TimeUnit::Week),
+                                            ("week", TimeUnit::Week),
+                                            ("weeks", TimeUnit::Week),
+                                            ("mo", TimeUnit::Month),
+                                            ("month", TimeUnit::Month),
+                                            ("months", TimeUnit::Month),
+                                            ("y", TimeUnit::Year),
+                                            ("yr", TimeUnit::Year),
+                                            ("yrs", TimeUnit::Year),
+                                            ("year", TimeUnit::Year),
+                                            ("years", TimeUnit::Year)];
+
+impl TimeUnit {
+    /// Look up a unit by any of the names accepted by `FromStr`.
+    ///
+    /// This is a `const fn` so that the `dur!` macro can parse at compile time.
+    pub(crate) const fn from_name(name: &[u8]) -> Option<TimeUnit> {
+        let mut index = 0;
+        while index < UNIT_NAMES.len() {
+            let (candidate, unit) = UNIT_NAMES[index];
+            if bytes_eq(candidate.as_bytes(), name) {
+                return Some(unit);
+            }
+            index += 1;
+        }
+        None
+    }
+}
+
+/// Compare two byte strings in a `const fn`.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// The units `Display` chooses from, from the longest to the shortest.
+const DISPLAY_UNITS: [TimeUnit; 8] = [TimeUnit::Year,
+                                      TimeUnit::Day,
+                                      TimeUnit::Hour,
+                                      TimeUnit::Minute,
+                                      TimeUnit::Second,
+                                      TimeUnit::Millisecond,
+                                      TimeUnit::Microsecond,
+                                      TimeUnit::Nanosecond];
+
+/// A convention for the length of years and months.
+///
+/// Years and months do not have a fixed length, so converting them to and from a
+/// duration requires picking an average. The default used by
+/// `FloatDuration::years` and `FloatDuration::months` is `Common`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CalendarConvention {
+    /// A 365 day year, ignoring leap years, with twelve equal months.
+    #[default]
+    Common,
+    /// The 365.25 day Julian year used in astronomy, with twelve equal months.
+    Julian,
+    /// The 365.2425 day mean Gregorian year, with twelve equal months.
+    Gregorian,
+    /// The 30/360 convention used in finance, with 30 day months and 360 day
+    /// years.
+    Financial,
+}
+
+impl CalendarConvention {
+    /// Return the number of seconds in one year under this convention.
+    pub fn secs_per_year(&self) -> f64 {
+        match *self {
+            CalendarConvention::Common => duration::SECS_PER_YEAR,
+            CalendarConvention::Julian => duration::SECS_PER_DAY * 365.25,
+            CalendarConvention::Gregorian => duration::SECS_PER_DAY * 365.2425,
+            CalendarConvention::Financial => duration::SECS_PER_DAY * 360.0,
+        }
+    }
+    /// Return the number of seconds in one month under this convention.
+    pub fn secs_per_month(&self) -> f64 {
+        self.secs_per_year() / 12.0
+    }
+}
+
+impl<F: Float> GenericFloatDuration<F> {
+    /// Create a new `FloatDuration` representing `value` of `unit`.
+    ///
+    /// The result is identical to calling the constructor for that unit, such as
+    /// `FloatDuration::milliseconds` for `TimeUnit::Millisecond`.
+    pub fn from_unit(value: F, unit: TimeUnit) -> GenericFloatDuration<F> {
+        match unit.units_per_sec() {
+            Some(units_per_sec) => GenericFloatDuration::from_fraction(value, units_per_sec),
+            None => GenericFloatDuration::from_multiple(value, unit.secs_per_unit()),
+        }
+    }
+    /// Return the total number of fractional `unit` represented by the
+    /// `FloatDuration`.
+    ///
+    /// The result is identical to calling the `as_*` method for that unit, such as
+    /// `as_milliseconds` for `TimeUnit::Millisecond`.
+    pub fn in_unit(&self, unit: TimeUnit) -> F {
+        match unit.units_per_sec() {
+            Some(units_per_sec) => self.as_seconds() * float(units_per_sec),
+            None => self.as_seconds() / float(unit.secs_per_unit()),
+        }
+    }
+    /// Return a number of `unit` close to `in_unit(unit)` that `from_unit` turns
+    /// back into exactly this duration.
+    ///
+    /// Converting to a unit and back may be off by a rounding error, so the few
+    /// floats around `in_unit(unit)` are tried in turn. Returns `None` if none of
+    /// them converts back exactly.
+    fn in_unit_exact(&self, unit: TimeUnit) -> Option<F> {
+        let value = self.in_unit(unit);
+        if GenericFloatDuration::from_unit(value, unit) == *self {
+            return Some(value);
+        }
+        if !value.is_finite() {
+            return None;
+        }
+
+        let (mantissa, exponent, sign) = value.integer_decode();
+        let ulp = Float::powi(F::from(2.0)?, i32::from(exponent)) * F::from(sign)?;
+        for &step in &[1i64, -1, 2, -2, 3, -3] {
+            let candidate = F::from(mantissa as i64 + step)? * ulp;
+            if GenericFloatDuration::from_unit(candidate, unit) == *self {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
+    /// Return the number and unit that `Display` shows for this duration when no
+    /// precision is given.
+    ///
+    /// This is the duration in `appropriate_unit`, or if no number of that unit
+    /// converts back into exactly this duration, in the first shorter unit for
+    /// which one does, falling back to seconds.
+    pub(crate) fn display_value(&self) -> (F, TimeUnit) {
+        let unit = self.appropriate_unit();
+        DISPLAY_UNITS.iter()
+            .cloned()
+            .skip_while(|&shown| shown != unit)
+            .filter_map(|shown| self.in_unit_exact(shown).map(|value| (value, shown)))
+            .next()
+            .unwrap_or((self.as_seconds(), TimeUnit::Second))
+    }
+
+    /// Return the unit that `Display` uses to show this duration.
+    ///
+    /// This is the largest of years, days, hours, minutes, seconds, milliseconds,
+    /// microseconds, and nanoseconds that the magnitude of the duration exceeds. It
+    /// is `TimeUnit::Second` for zero, for durations of a nanosecond or less, and
+    /// for non-finite durations.
+    ///
+    /// ```rust
+    /// use float_duration::{FloatDuration, TimeUnit};
+    ///
+    /// let d = FloatDuration::milliseconds(1500.0);
+    /// let unit = d.appropriate_unit();
+    /// assert_eq!(unit, TimeUnit::Second);
+    /// assert_eq!(format!("{:.3} {}", d.in_unit(unit), unit.name()), "1.500 seconds");
+    /// ```
+    pub fn appropriate_unit(&self) -> TimeUnit {
+        let secs = self.as_seconds().abs();
+        if !secs.is_finite() {
+            return TimeUnit::Second;
+        }
+        for &unit in &DISPLAY_UNITS {
+            if secs > float(unit.as_seconds()) {
+                return unit;
+            }
+        }
+        TimeUnit::Second
+    }
+
+    /// Create a new `FloatDuration` representing a number of years of the length
+    /// given by `convention`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use float_duration::unit::CalendarConvention;
+    ///
+    /// assert_eq!(FloatDuration::years_in(4.0, CalendarConvention::Julian),
+    ///            FloatDuration::days(1461.0));
+    /// ```
+    pub fn years_in(years: F, convention: CalendarConvention) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(years * float(convention.secs_per_year()))
+    }
+    /// Create a new `FloatDuration` representing a number of months of the length
+    /// given by `convention`.
+    pub fn months_in(months: F, convention: CalendarConvention) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(months * float(convention.secs_per_month()))
+    }
+    /// Return the total number of fractional years, of the length given by
+    /// `convention`, represented by the `FloatDuration`.
+    pub fn as_years_in(&self, convention: CalendarConvention) -> F {
+        self.as_seconds() / float(convention.secs_per_year())
+    }
+    /// Return the total number of fractional months, of the length given by
+    /// `convention`, represented by the `FloatDuration`.
+    pub fn as_months_in(&self, convention: CalendarConvention) -> F {
+        self.as_seconds() / float(convention.secs_per_month())
+    }
+
+    /// Round the duration to the nearest whole number of `unit`.
+    ///
+    /// Halfway cases are rounded away from zero.
+    ///
+    /// ```rust
+    /// use float_duration::{FloatDuration, TimeUnit};
+    ///
+    /// let elapsed = FloatDuration::seconds(1.23456);
+    /// assert_eq!(elapsed.round_to(TimeUnit::Millisecond), FloatDuration::milliseconds(1235.0));
+    /// assert_eq!(elapsed.round_to(TimeUnit::Second), FloatDuration::seconds(1.0));
+    /// ```
+    pub fn round_to(self, unit: TimeUnit) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(unit.snap(self.as_seconds(), Float::round))
+    }
+    /// Round the duration down to a whole number of `unit`.
+    pub fn floor_to(self, unit: TimeUnit) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(unit.snap(self.as_seconds(), Float::floor))
+    }
+    /// Round the duration up to a whole number of `unit`.
+    pub fn ceil_to(self, unit: TimeUnit) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(unit.snap(self.as_seconds(), Float::ceil))
+    }
+    /// Round the duration towards zero to a whole number of `unit`.
+    pub fn trunc_to(self, unit: TimeUnit) -> GenericFloatDuration<F> {
+        GenericFloatDuration::seconds(unit.snap(self.as_seconds(), Float::trunc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duration::{FloatDuration, FloatDuration32};
+
+    #[test]
+    fn test_as_seconds() {
+        assert_eq!(TimeUnit::Nanosecond.as_seconds(), 1e-9);
+        assert_eq!(TimeUnit::Minute.as_seconds(), 60.0);
+        assert_eq!(TimeUnit::Day.as_seconds(), 86400.0);
+        assert_eq!(TimeUnit::Week.as_seconds(), 604800.0);
+        assert_eq!(TimeUnit::Year.as_seconds(), 365.0 * 86400.0);
+        assert!(TimeUnit::Microsecond < TimeUnit::Hour);
+    }
+
+    #[test]
+    fn test_in_unit() {
+        let d = FloatDuration::seconds(1234.5678);
+        assert_eq!(d.in_unit(TimeUnit::Nanosecond), d.as_nanoseconds());
+        assert_eq!(d.in_unit(TimeUnit::Microsecond), d.as_microseconds());
+        assert_eq!(d.in_unit(TimeUnit::Millisecond), d.as_milliseconds());
+        assert_eq!(d.in_unit(TimeUnit::Second), d.as_seconds());
+        assert_eq!(d.in_unit(TimeUnit::Minute), d.as_minutes());
+        assert_eq!(d.in_unit(TimeUnit::Hour), d.as_hours());
+        assert_eq!(d.in_unit(TimeUnit::Day), d.as_days());
+        assert_eq!(d.in_unit(TimeUnit::Week), d.as_weeks());
+        assert_eq!(d.in_unit(TimeUnit::Month), d.as_months());
+        assert_eq!(d.in_unit(TimeUnit::Year), d.as_years());
+
+        assert_eq!(FloatDuration::from_unit(0.3, TimeUnit::Nanosecond),
+                   FloatDuration::nanoseconds(0.3));
+        assert_eq!(FloatDuration::from_unit(0.3, TimeUnit::Millisecond),
+                   FloatDuration::milliseconds(0.3));
+        assert_eq!(FloatDuration::from_unit(0.3, TimeUnit::Second), FloatDuration::seconds(0.3));
+        assert_eq!(FloatDuration::from_unit(0.3, TimeUnit::Hour), FloatDuration::hours(0.3));
+        assert_eq!(FloatDuration32::from_unit(0.3, TimeUnit::Year), FloatDuration32::years(0.3));
+    }
+
+    #[test]
+    fn test_appropriate_unit() {
+        assert_eq!(FloatDuration::years(2.0).appropriate_unit(), TimeUnit::Year);
+        assert_eq!(FloatDuration::weeks(2.0).appropriate_unit(), TimeUnit::Day);
+        assert_eq!(FloatDuration::minutes(-90.0).appropriate_unit(), TimeUnit::Hour);
+        assert_eq!(FloatDuration::minutes(1.0).appropriate_unit(), TimeUnit::Second);
+        assert_eq!(FloatDuration::seconds(1.0).appropriate_unit(), TimeUnit::Millisecond);
+        assert_eq!(FloatDuration::microseconds(12.0).appropriate_unit(),
+                   TimeUnit::Microsecond);
+        assert_eq!(FloatDuration::nanoseconds(2.0).appropriate_unit(), TimeUnit::Nanosecond);
+        assert_eq!(FloatDuration::nanoseconds(0.5).appropriate_unit(), TimeUnit::Second);
+        assert_eq!(FloatDuration::zero().appropriate_unit(), TimeUnit::Second);
+        assert_eq!((FloatDuration::zero() / 0.0).appropriate_unit(), TimeUnit::Second);
+    }
+
+    #[test]
+    fn test_parse_unit() {
+        assert_eq!("µs".parse(), Ok(TimeUnit::Microsecond));
+        assert_eq!("minutes".parse(), Ok(TimeUnit::Minute));
+        assert_eq!("mo".parse(), Ok(TimeUnit::Month));
+        assert_eq!("fortnight".parse::<TimeUnit>(), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(" s".parse::<TimeUnit>(), Err(ParseDurationError::UnknownUnit));
+    }
+
+    #[test]
+    fn test_calendar_conventions() {
+        assert_eq!(FloatDuration::years_in(1.0, CalendarConvention::Common),
+                   FloatDuration::years(1.0));
+        assert_eq!(FloatDuration::months_in(2.0, CalendarConvention::Common),
+                   FloatDuration::months(2.0));
+        assert_eq!(FloatDuration::years_in(400.0, CalendarConvention::Gregorian),
+                   FloatDuration::days(146097.0));
+        assert_eq!(FloatDuration::months_in(1.0, CalendarConvention::Financial),
+                   FloatDuration::days(30.0));
+        assert_eq!(FloatDuration::days(90.0).as_months_in(CalendarConvention::Financial), 3.0);
+        assert_eq!(FloatDuration::days(365.25).as_years_in(CalendarConvention::Julian), 1.0);
+        assert_eq!(CalendarConvention::default(), CalendarConvention::Common);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let d = FloatDuration::minutes(-1.5);
+        assert_eq!(d.round_to(TimeUnit::Minute), FloatDuration::minutes(-2.0));
+        assert_eq!(d.floor_to(TimeUnit::Minute), FloatDuration::minutes(-2.0));
+        assert_eq!(d.ceil_to(TimeUnit::Minute), FloatDuration::minutes(-1.0));
+        assert_eq!(d.trunc_to(TimeUnit::Minute), FloatDuration::minutes(-1.0));
+        assert_eq!(d.trunc_to(TimeUnit::Hour), FloatDuration::zero());
+
+        let d = FloatDuration::seconds(0.0123456789);
+        assert_eq!(d.round_to(TimeUnit::Nanosecond), FloatDuration::nanoseconds(12345679.0));
+        assert_eq!(d.floor_to(TimeUnit::Microsecond), FloatDuration::microseconds(12345.0));
+        assert_eq!(d.ceil_to(TimeUnit::Millisecond), FloatDuration::milliseconds(13.0));
+        assert_eq!(FloatDuration::hours(30.0).floor_to(TimeUnit::Day), FloatDuration::days(1.0));
+
+        assert_eq!(FloatDuration32::seconds(2.5).round_to(TimeUnit::Second),
+                   FloatDuration32::seconds(3.0));
+    }
+}